@@ -3,8 +3,9 @@ use std::collections::HashSet;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, ensure, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Reply,
-    ReplyOn, Response, StdError, StdResult, SubMsg, SubMsgResponse, SubMsgResult, WasmMsg,
+    attr, coins, ensure, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut,
+    Env, MessageInfo, Order, Reply, ReplyOn, Response, StdError, StdResult, SubMsg, SubMsgResponse,
+    SubMsgResult, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw_utils::parse_instantiate_response_data;
@@ -13,17 +14,20 @@ use itertools::Itertools;
 use oroswap::asset::{addr_opt_validate, AssetInfo, PairInfo};
 use oroswap::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
 use oroswap::factory::{
-    Config, ConfigResponse, ExecuteMsg, FeeInfoResponse, InstantiateMsg, MigrateMsg, PairConfig,
-    PairType, PairsResponse, QueryMsg, StartAfter, TrackerConfig,
+    valid_fee_tier, Config, ConfigResponse, ExecuteMsg, FeeInfoResponse, InstantiateMsg,
+    MigrateMsg, PairConfig, PairMakerFee, PairMakerFeeResponse, PairType, PairsResponse, QueryMsg,
+    StartAfter, TrackerConfig,
 };
 use oroswap::incentives::ExecuteMsg::DeactivatePool;
 use oroswap::pair::InstantiateMsg as PairInstantiateMsg;
+use oroswap::pair_xyk_sale_tax::SALE_TAX_PAIR_TYPE;
 
 use crate::error::ContractError;
 use crate::querier::query_pair_info;
 use crate::state::{
-    check_asset_infos, pair_key, read_pairs, TmpPairInfo, CONFIG, OWNERSHIP_PROPOSAL, PAIRS,
-    PAIR_CONFIGS, TMP_PAIR_INFO, TRACKER_CONFIG, PAUSED_PAIRS, PAUSE_AUTHORITIES,
+    check_asset_infos, pair_key, read_pair_maker_fees, read_pairs, TmpPairInfo, CONFIG, FEE_TIERS,
+    OWNERSHIP_PROPOSAL, PAIRS, PAIR_CONFIGS, PAIR_FEE_TIERS, PAIR_MAKER_FEES, PAUSED_PAIRS,
+    PAUSE_AUTHORITIES, TMP_PAIR_INFO, TRACKER_CONFIG,
 };
 
 /// Contract name that is used for migration.
@@ -167,7 +171,16 @@ pub fn execute(
             pair_type,
             asset_infos,
             init_params,
-        } => execute_create_pair(deps, info, env, pair_type, asset_infos, init_params),
+            fee_tier,
+        } => execute_create_pair(
+            deps,
+            info,
+            env,
+            pair_type,
+            asset_infos,
+            init_params,
+            fee_tier,
+        ),
         ExecuteMsg::Deregister { asset_infos, pair_type } => deregister(deps, info, asset_infos, pair_type),
         ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
             let config = CONFIG.load(deps.storage)?;
@@ -210,6 +223,30 @@ pub fn execute(
         ExecuteMsg::UnpausePairsBatch { batch_size } => unpause_pairs_batch(deps, info, batch_size),
         ExecuteMsg::AddPauseAuthorities { authorities } => add_pause_authorities(deps, info, authorities),
         ExecuteMsg::RemovePauseAuthorities { authorities } => remove_pause_authorities(deps, info, authorities),
+        ExecuteMsg::SetPairMakerFee {
+            asset_infos,
+            pair_type,
+            fee_address,
+            maker_fee_bps,
+        } => set_pair_maker_fee(
+            deps,
+            info,
+            asset_infos,
+            pair_type,
+            fee_address,
+            maker_fee_bps,
+        ),
+        ExecuteMsg::RemovePairMakerFee {
+            asset_infos,
+            pair_type,
+        } => remove_pair_maker_fee(deps, info, asset_infos, pair_type),
+        ExecuteMsg::AddFeeTiers { fee_tiers } => add_fee_tiers(deps, info, fee_tiers),
+        ExecuteMsg::RemoveFeeTiers { fee_tiers } => remove_fee_tiers(deps, info, fee_tiers),
+        ExecuteMsg::UpdatePairFeeTier {
+            asset_infos,
+            pair_type,
+            fee_tier,
+        } => update_pair_fee_tier(deps, info, asset_infos, pair_type, fee_tier),
     }
 }
 
@@ -297,6 +334,8 @@ pub fn execute_update_pair_config(
 /// * **asset_infos** is a vector with assets for which we create a pair.
 ///
 /// * **init_params** These are packed params used for custom pair types that need extra data to be instantiated.
+///
+/// * **fee_tier** is an optional total fee (in bps) for the pair, it must be one of the allowed fee tiers.
 pub fn execute_create_pair(
     deps: DepsMut,
     info: MessageInfo,
@@ -304,6 +343,7 @@ pub fn execute_create_pair(
     pair_type: PairType,
     asset_infos: Vec<AssetInfo>,
     init_params: Option<Binary>,
+    fee_tier: Option<u16>,
 ) -> Result<Response, ContractError> {
     check_asset_infos(deps.api, &asset_infos)?;
 
@@ -327,26 +367,56 @@ pub fn execute_create_pair(
         return Err(ContractError::PairConfigDisabled {});
     }
 
-    // Check if pool creation fee is included
+    // Take the pool creation fee out of the attached funds and refund the rest to the sender
     let pool_creation_fee = pair_config.pool_creation_fee;
     let funds = info.funds.clone();
-    
-    // Find the fee coin in the funds
-    let fee_coin = funds.iter().find(|coin| coin.denom == "uzig");
-    match fee_coin {
-        Some(coin) => {
-            if coin.amount < pool_creation_fee {
-                return Err(ContractError::InvalidPoolCreationFee { 
-                    required: pool_creation_fee 
-                });
-            }
-        }
-        None => {
-            return Err(ContractError::MissingPoolCreationFee {});
+    let mut refund = funds.clone();
+    let mut messages: Vec<CosmosMsg> = vec![];
+
+    if !pool_creation_fee.is_zero() {
+        let fee_coin = refund
+            .iter_mut()
+            .find(|coin| coin.denom == pair_config.pool_creation_fee_denom)
+            .ok_or(ContractError::MissingPoolCreationFee {})?;
+        if fee_coin.amount < pool_creation_fee {
+            return Err(ContractError::InvalidPoolCreationFee {
+                required: pool_creation_fee,
+            });
         }
+        fee_coin.amount -= pool_creation_fee;
+
+        let fee_address = config
+            .fee_address
+            .as_ref()
+            .ok_or(ContractError::FeeAddressNotSet {})?;
+        messages.push(
+            BankMsg::Send {
+                to_address: fee_address.to_string(),
+                amount: coins(
+                    pool_creation_fee.u128(),
+                    &pair_config.pool_creation_fee_denom,
+                ),
+            }
+            .into(),
+        );
+    }
+
+    refund.retain(|coin| !coin.amount.is_zero());
+    if !refund.is_empty() {
+        messages.push(
+            BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: refund.clone(),
+            }
+            .into(),
+        );
     }
 
     let pair_key = pair_key(&asset_infos, &pair_type);
+    if let Some(fee_tier) = fee_tier {
+        check_fee_tier(deps.storage, &pair_type, fee_tier)?;
+        PAIR_FEE_TIERS.save(deps.storage, &pair_key, &fee_tier)?;
+    }
     TMP_PAIR_INFO.save(deps.storage, &TmpPairInfo { pair_key })?;
 
     let sub_msg: Vec<SubMsg> = vec![SubMsg {
@@ -361,7 +431,7 @@ pub fn execute_create_pair(
                 factory_addr: env.contract.address.to_string(),
                 init_params,
             })?,
-            funds: funds.clone(),
+            funds: vec![],
             label: format!("Oroswap pair {}", pair_type),
         }
         .into(),
@@ -369,15 +439,27 @@ pub fn execute_create_pair(
         reply_on: ReplyOn::Success,
     }];
 
+    let mut attrs = vec![
+        attr("action", "create_pair"),
+        attr("pair", asset_infos.iter().join("-")),
+        attr("pair_type", pair_type.to_string()),
+        attr("pool_creation_fee", pool_creation_fee.to_string()),
+        attr(
+            "total_funds",
+            funds.iter().map(|c| c.amount.to_string()).join(","),
+        ),
+    ];
+    if !refund.is_empty() {
+        attrs.push(attr("refund", refund.iter().join(",")));
+    }
+    if let Some(fee_tier) = fee_tier {
+        attrs.push(attr("fee_tier", fee_tier.to_string()));
+    }
+
     Ok(Response::new()
         .add_submessages(sub_msg)
-        .add_attributes(vec![
-            attr("action", "create_pair"),
-            attr("pair", asset_infos.iter().join("-")),
-            attr("pair_type", pair_type.to_string()),
-            attr("pool_creation_fee", pool_creation_fee.to_string()),
-            attr("total_funds", funds.iter().map(|c| c.amount.to_string()).join(",")),
-        ]))
+        .add_messages(messages)
+        .add_attributes(attrs))
 }
 
 /// The entry point to the contract for processing replies from submessages.
@@ -434,19 +516,24 @@ pub fn deregister(
 
     let pair_addr = PAIRS.load(deps.storage, &pair_key(&asset_infos, &pair_type))?;
     PAIRS.remove(deps.storage, &pair_key(&asset_infos, &pair_type));
+    PAIR_MAKER_FEES.remove(deps.storage, &pair_key(&asset_infos, &pair_type));
+    PAIR_FEE_TIERS.remove(deps.storage, &pair_key(&asset_infos, &pair_type));
 
     let mut messages: Vec<CosmosMsg> = vec![];
     if let Some(generator) = config.generator_address {
         let pair_info = query_pair_info(&deps.querier, &pair_addr)?;
 
-        // sets the allocation point to zero for the lp_token
-        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
-            contract_addr: generator.to_string(),
-            msg: to_json_binary(&DeactivatePool {
-                lp_token: pair_info.liquidity_token.to_string(),
-            })?,
-            funds: vec![],
-        }));
+        // Pairs without an LP token, such as the ORO/xORO pair, have nothing to deactivate
+        if !pair_info.liquidity_token.is_empty() {
+            // sets the allocation point to zero for the lp_token
+            messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: generator.to_string(),
+                msg: to_json_binary(&DeactivatePool {
+                    lp_token: pair_info.liquidity_token.to_string(),
+                })?,
+                funds: vec![],
+            }));
+        }
     }
 
     Ok(Response::new().add_messages(messages).add_attributes(vec![
@@ -763,6 +850,209 @@ pub fn remove_pause_authorities(
         ]))
 }
 
+/// Overrides the maker fee recipient and split for a specific pair.
+/// The pair then pays its maker fee in its own tokens to `fee_address` instead of the Maker.
+///
+/// * **asset_infos** is a vector with the assets of the pair.
+///
+/// * **fee_address** is the address that receives the pair's maker fee.
+///
+/// * **maker_fee_bps** is the amount of fees (in bps) sent to `fee_address`.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn set_pair_maker_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    asset_infos: Vec<AssetInfo>,
+    pair_type: PairType,
+    fee_address: String,
+    maker_fee_bps: u16,
+) -> Result<Response, ContractError> {
+    check_asset_infos(deps.api, &asset_infos)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pair_key = pair_key(&asset_infos, &pair_type);
+    let pair_addr = PAIRS.load(deps.storage, &pair_key)?;
+
+    let maker_fee = PairMakerFee {
+        fee_address: deps.api.addr_validate(&fee_address)?,
+        maker_fee_bps,
+    };
+    if !maker_fee.valid_fee_bps() {
+        return Err(ContractError::PairMakerFeeInvalidBps {});
+    }
+
+    PAIR_MAKER_FEES.save(deps.storage, &pair_key, &maker_fee)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "set_pair_maker_fee"),
+        attr("pair_contract_addr", pair_addr),
+        attr("fee_address", maker_fee.fee_address),
+        attr("maker_fee_bps", maker_fee_bps.to_string()),
+    ]))
+}
+
+/// Removes a pair's maker fee override so it falls back to its pair type config.
+///
+/// * **asset_infos** is a vector with the assets of the pair.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn remove_pair_maker_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    asset_infos: Vec<AssetInfo>,
+    pair_type: PairType,
+) -> Result<Response, ContractError> {
+    check_asset_infos(deps.api, &asset_infos)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pair_key = pair_key(&asset_infos, &pair_type);
+    let pair_addr = PAIRS.load(deps.storage, &pair_key)?;
+
+    if !PAIR_MAKER_FEES.has(deps.storage, &pair_key) {
+        return Err(ContractError::PairMakerFeeNotFound {});
+    }
+    PAIR_MAKER_FEES.remove(deps.storage, &pair_key);
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "remove_pair_maker_fee"),
+        attr("pair_contract_addr", pair_addr),
+    ]))
+}
+
+/// Adds fee tiers (total fees in bps) that pairs can be created with.
+///
+/// * **fee_tiers** is a vector with the fee tiers to allow.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn add_fee_tiers(
+    deps: DepsMut,
+    info: MessageInfo,
+    fee_tiers: Vec<u16>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for fee_tier in &fee_tiers {
+        if !valid_fee_tier(*fee_tier) {
+            return Err(ContractError::InvalidFeeTier {
+                fee_tier: *fee_tier,
+            });
+        }
+        FEE_TIERS.save(deps.storage, *fee_tier, &())?;
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "add_fee_tiers"),
+        attr("fee_tiers", fee_tiers.iter().join(",")),
+    ]))
+}
+
+/// Removes allowed fee tiers. Pairs that already use a removed tier keep it.
+///
+/// * **fee_tiers** is a vector with the fee tiers to disallow.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn remove_fee_tiers(
+    deps: DepsMut,
+    info: MessageInfo,
+    fee_tiers: Vec<u16>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    for fee_tier in &fee_tiers {
+        FEE_TIERS.remove(deps.storage, *fee_tier);
+    }
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "remove_fee_tiers"),
+        attr("fee_tiers", fee_tiers.iter().join(",")),
+    ]))
+}
+
+/// Sets or removes the fee tier of a specific pair.
+///
+/// * **asset_infos** is a vector with the assets of the pair.
+///
+/// * **fee_tier** is the new fee tier, or `None` to fall back to the pair type's total fee.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn update_pair_fee_tier(
+    deps: DepsMut,
+    info: MessageInfo,
+    asset_infos: Vec<AssetInfo>,
+    pair_type: PairType,
+    fee_tier: Option<u16>,
+) -> Result<Response, ContractError> {
+    check_asset_infos(deps.api, &asset_infos)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let pair_key = pair_key(&asset_infos, &pair_type);
+    let pair_addr = PAIRS.load(deps.storage, &pair_key)?;
+
+    let mut attrs = vec![
+        attr("action", "update_pair_fee_tier"),
+        attr("pair_contract_addr", pair_addr),
+    ];
+    match fee_tier {
+        Some(fee_tier) => {
+            check_fee_tier(deps.storage, &pair_type, fee_tier)?;
+            PAIR_FEE_TIERS.save(deps.storage, &pair_key, &fee_tier)?;
+            attrs.push(attr("fee_tier", fee_tier.to_string()));
+        }
+        None => PAIR_FEE_TIERS.remove(deps.storage, &pair_key),
+    }
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Checks that a fee tier is in the list of allowed fee tiers and can be assigned to the pair type.
+/// Only XYK (including sale tax XYK) and stable pairs charge the factory's total fee,
+/// so a fee tier would have no effect on other pair types.
+fn check_fee_tier(
+    storage: &dyn cosmwasm_std::Storage,
+    pair_type: &PairType,
+    fee_tier: u16,
+) -> Result<(), ContractError> {
+    let supported = match pair_type {
+        PairType::Xyk {} | PairType::Stable {} => true,
+        PairType::Custom(pair_type) => pair_type == SALE_TAX_PAIR_TYPE,
+    };
+    if !supported {
+        return Err(ContractError::FeeTierNotSupported {
+            pair_type: pair_type.to_string(),
+        });
+    }
+
+    if !FEE_TIERS.has(storage, fee_tier) {
+        return Err(ContractError::FeeTierNotAllowed { fee_tier });
+    }
+
+    Ok(())
+}
+
 /// Check if the sender has pause authority (owner or pause authority).
 fn check_pause_authority(
     storage: &dyn cosmwasm_std::Storage,
@@ -810,7 +1100,17 @@ fn check_unpause_authority(
 ///
 /// * **QueryMsg::FeeInfo { pair_type }** Returns the fee structure (total and maker fees) for a specific pair type.
 ///
+/// * **QueryMsg::PoolCreationFee { pair_type }** Returns the coins that must be attached to create a pair of a specific type.
+///
 /// * **QueryMsg::BlacklistedPairTypes {}** Returns a vector that contains blacklisted pair types (pair types that cannot get ORO emissions).
+///
+/// * **QueryMsg::PairFeeInfo { asset_infos, pair_type }** Returns the fee structure for a specific pair with its fee tier and maker fee override applied.
+///
+/// * **QueryMsg::FeeInfoByPair { pair_addr }** Returns the fee structure for a specific pair by its address.
+///
+/// * **QueryMsg::FeeTiers {}** Returns the allowed fee tiers.
+///
+/// * **QueryMsg::PairMakerFees { start_after, limit }** Returns the pairs with a maker fee override.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -820,6 +1120,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
             to_json_binary(&query_pairs(deps, start_after, limit)?)
         }
         QueryMsg::FeeInfo { pair_type } => to_json_binary(&query_fee_info(deps, pair_type)?),
+        QueryMsg::PoolCreationFee { pair_type } => {
+            to_json_binary(&query_pool_creation_fee(deps, pair_type)?)
+        }
         QueryMsg::BlacklistedPairTypes {} => to_json_binary(&query_blacklisted_pair_types(deps)?),
         QueryMsg::TrackerConfig {} => to_json_binary(&query_tracker_config(deps)?),
         QueryMsg::PairsByAssets { asset_infos } => {
@@ -828,6 +1131,17 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::IsPairPaused { asset_infos, pair_type } => to_json_binary(&query_is_pair_paused(deps, asset_infos, pair_type)?),
         QueryMsg::PauseAuthorities {} => to_json_binary(&query_pause_authorities(deps)?),
         QueryMsg::PausedPairsCount {} => to_json_binary(&query_paused_pairs_count(deps)?),
+        QueryMsg::PairFeeInfo {
+            asset_infos,
+            pair_type,
+        } => to_json_binary(&query_pair_fee_info(deps, asset_infos, pair_type)?),
+        QueryMsg::FeeInfoByPair { pair_addr } => {
+            to_json_binary(&query_fee_info_by_pair(deps, pair_addr)?)
+        }
+        QueryMsg::FeeTiers {} => to_json_binary(&query_fee_tiers(deps)?),
+        QueryMsg::PairMakerFees { start_after, limit } => {
+            to_json_binary(&query_pair_maker_fees(deps, start_after, limit)?)
+        }
     }
 }
 
@@ -906,6 +1220,92 @@ pub fn query_fee_info(deps: Deps, pair_type: PairType) -> StdResult<FeeInfoRespo
     })
 }
 
+/// Returns the fee setup for a specific pair using a [`FeeInfoResponse`] struct.
+/// The pair's fee tier, if any, replaces the total fee of its pair type.
+/// The pair's maker fee override, if any, replaces the fee address and maker fee of its pair type.
+/// * **asset_infos** is a vector with the assets of the pair.
+pub fn query_pair_fee_info(
+    deps: Deps,
+    asset_infos: Vec<AssetInfo>,
+    pair_type: PairType,
+) -> StdResult<FeeInfoResponse> {
+    let pair_key = pair_key(&asset_infos, &pair_type);
+    let mut fee_info = query_fee_info(deps, pair_type)?;
+
+    if let Some(fee_tier) = PAIR_FEE_TIERS.may_load(deps.storage, &pair_key)? {
+        fee_info.total_fee_bps = fee_tier;
+    }
+
+    if let Some(maker_fee) = PAIR_MAKER_FEES.may_load(deps.storage, &pair_key)? {
+        fee_info.fee_address = Some(maker_fee.fee_address);
+        fee_info.maker_fee_bps = maker_fee.maker_fee_bps;
+    }
+
+    Ok(fee_info)
+}
+
+/// Returns the fee setup for a registered pair by its address using a [`FeeInfoResponse`] struct.
+/// * **pair_addr** is the address of the pair.
+pub fn query_fee_info_by_pair(deps: Deps, pair_addr: String) -> StdResult<FeeInfoResponse> {
+    let pair_addr = deps.api.addr_validate(&pair_addr)?;
+    let pair_info = query_pair_info(&deps.querier, &pair_addr)?;
+
+    let registered = PAIRS.may_load(
+        deps.storage,
+        &pair_key(&pair_info.asset_infos, &pair_info.pair_type),
+    )?;
+    if registered.as_ref() != Some(&pair_addr) {
+        return Err(StdError::generic_err(format!(
+            "Pair {pair_addr} is not registered in the factory"
+        )));
+    }
+
+    query_pair_fee_info(deps, pair_info.asset_infos, pair_info.pair_type)
+}
+
+/// Returns the allowed fee tiers.
+pub fn query_fee_tiers(deps: Deps) -> StdResult<Vec<u16>> {
+    FEE_TIERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect()
+}
+
+/// Returns the pairs with a maker fee override. Querying starts at `start_after` and returns `limit` items.
+/// * **start_after** is the pair from which we start a query.
+///
+/// * **limit** sets the number of items to be retrieved.
+pub fn query_pair_maker_fees(
+    deps: Deps,
+    start_after: Option<StartAfter>,
+    limit: Option<u32>,
+) -> StdResult<Vec<PairMakerFeeResponse>> {
+    read_pair_maker_fees(deps, start_after, limit)?
+        .into_iter()
+        .map(|(pair_addr, maker_fee)| {
+            Ok(PairMakerFeeResponse {
+                pair_info: query_pair_info(&deps.querier, pair_addr)?,
+                fee_address: maker_fee.fee_address,
+                maker_fee_bps: maker_fee.maker_fee_bps,
+            })
+        })
+        .collect()
+}
+
+/// Returns the coins that must be attached to a CreatePair message for a specific pair type.
+/// * **pair_type** is the pair type for which we return the pool creation fee.
+pub fn query_pool_creation_fee(deps: Deps, pair_type: PairType) -> StdResult<Vec<Coin>> {
+    let pair_config = PAIR_CONFIGS.load(deps.storage, pair_type.to_string())?;
+
+    if pair_config.pool_creation_fee.is_zero() {
+        Ok(vec![])
+    } else {
+        Ok(coins(
+            pair_config.pool_creation_fee.u128(),
+            pair_config.pool_creation_fee_denom,
+        ))
+    }
+}
+
 pub fn query_tracker_config(deps: Deps) -> StdResult<TrackerConfig> {
     let tracker_config = TRACKER_CONFIG.load(deps.storage).map_err(|_| {
         StdError::generic_err("Tracker config is not set in the factory. It can't be provided")
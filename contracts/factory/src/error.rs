@@ -43,6 +43,9 @@ pub enum ContractError {
     #[error("Pool creation fee is missing")]
     MissingPoolCreationFee {},
 
+    #[error("Fee address must be set to collect the pool creation fee")]
+    FeeAddressNotSet {},
+
     #[error("Pair is already paused")]
     PairAlreadyPaused {},
 
@@ -57,4 +60,19 @@ pub enum ContractError {
 
     #[error("No unpause authority - only factory admin can unpause pairs")]
     NoUnpauseAuthority {},
+
+    #[error("Maker fee bps must be smaller than or equal to 10,000")]
+    PairMakerFeeInvalidBps {},
+
+    #[error("Pair has no maker fee override")]
+    PairMakerFeeNotFound {},
+
+    #[error("Fee tier {fee_tier} must be smaller than or equal to 10,000 bps")]
+    InvalidFeeTier { fee_tier: u16 },
+
+    #[error("Fee tier {fee_tier} is not allowed")]
+    FeeTierNotAllowed { fee_tier: u16 },
+
+    #[error("Fee tiers are not supported for {pair_type} pairs")]
+    FeeTierNotSupported { pair_type: String },
 }
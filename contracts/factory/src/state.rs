@@ -2,7 +2,7 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Api, Deps, Order, StdResult};
 use cw_storage_plus::{Bound, Item, Map};
 use oroswap::asset::AssetInfo;
-use oroswap::factory::{Config, PairConfig, TrackerConfig, PairType, StartAfter};
+use oroswap::factory::{Config, PairConfig, PairMakerFee, TrackerConfig, PairType, StartAfter};
 use oroswap::common::OwnershipProposal;
 
 #[cfg(test)]
@@ -25,6 +25,15 @@ pub const CONFIG: Item<Config> = Item::new("config");
 /// Saves created pairs (from olders to latest)
 pub const PAIRS: Map<&[u8], Addr> = Map::new("pair_info");
 
+/// Saves per-pair overrides of the maker fee recipient and split, keyed like [`PAIRS`]
+pub const PAIR_MAKER_FEES: Map<&[u8], PairMakerFee> = Map::new("pair_maker_fees");
+
+/// Saves per-pair fee tiers (total fee in bps), keyed like [`PAIRS`]
+pub const PAIR_FEE_TIERS: Map<&[u8], u16> = Map::new("pair_fee_tiers");
+
+/// Saves the fee tiers pairs are allowed to use
+pub const FEE_TIERS: Map<u16, ()> = Map::new("fee_tiers");
+
 /// Track config for tracking contract
 pub const TRACKER_CONFIG: Item<TrackerConfig> = Item::new("tracker_config");
 
@@ -110,6 +119,34 @@ pub fn read_pairs(
     }
 }
 
+/// Reads pair maker fee overrides from [`PAIR_MAKER_FEES`] according to the `start_after` and `limit` variables.
+///
+/// `start_after` is the pair from which the function starts to fetch results.
+///
+/// `limit` is the number of items to retrieve.
+pub fn read_pair_maker_fees(
+    deps: Deps,
+    start_after: Option<StartAfter>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(Addr, PairMakerFee)>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = calc_range_start(start_after);
+
+    PAIR_MAKER_FEES
+        .range(
+            deps.storage,
+            start.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            let (key, maker_fee) = item?;
+            Ok((PAIRS.load(deps.storage, &key)?, maker_fee))
+        })
+        .collect()
+}
+
 /// Calculates the key of a pair from which to start reading data.
 ///
 /// `start_after` is an [`Option`] type that contains both the asset infos and pair type
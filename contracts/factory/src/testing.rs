@@ -1,11 +1,11 @@
 use cosmwasm_std::{
-    attr, from_json, to_json_binary, Addr, Coin, Reply, ReplyOn, SubMsg, SubMsgResponse, SubMsgResult,
-    WasmMsg,
+    attr, coins, from_json, to_json_binary, Addr, BankMsg, Coin, Reply, ReplyOn, SubMsg,
+    SubMsgResponse, SubMsgResult, WasmMsg,
 };
 use cosmwasm_std::Uint128;
 
 use crate::mock_querier::mock_dependencies;
-use crate::state::CONFIG;
+use crate::state::{pair_key, CONFIG, PAIRS};
 use crate::{
     contract::{execute, instantiate, query},
     error::ContractError,
@@ -16,6 +16,7 @@ use oroswap::factory::{
     ConfigResponse, ExecuteMsg, InstantiateMsg, PairConfig, PairType, PairsResponse, QueryMsg,
     StartAfter,
 };
+use oroswap::pair_xoro::XORO_PAIR_TYPE;
 
 use crate::contract::reply;
 use oroswap::pair::InstantiateMsg as PairInstantiateMsg;
@@ -54,6 +55,7 @@ fn proper_initialization() {
                 is_generator_disabled: false,
                 permissioned: false,
                 pool_creation_fee: Uint128::new(1000),
+                pool_creation_fee_denom: "uzig".to_string(),
             },
             PairConfig {
                 code_id: 325u64,
@@ -64,6 +66,7 @@ fn proper_initialization() {
                 is_generator_disabled: false,
                 permissioned: false,
                 pool_creation_fee: Uint128::new(1000),
+                pool_creation_fee_denom: "uzig".to_string(),
             },
         ],
         token_code_id: 123u64,
@@ -91,6 +94,7 @@ fn proper_initialization() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: 123u64,
         fee_address: None,
@@ -120,6 +124,7 @@ fn proper_initialization() {
                 is_generator_disabled: false,
                 permissioned: false,
                 pool_creation_fee: Uint128::new(1000),
+                pool_creation_fee_denom: "uzig".to_string(),
             },
             PairConfig {
                 code_id: 123u64,
@@ -130,6 +135,7 @@ fn proper_initialization() {
                 is_generator_disabled: false,
                 permissioned: false,
                 pool_creation_fee: Uint128::new(1000),
+                pool_creation_fee_denom: "uzig".to_string(),
             },
         ],
         token_code_id: 123u64,
@@ -167,6 +173,7 @@ fn update_config() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
@@ -322,6 +329,7 @@ fn update_pair_config() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
@@ -356,6 +364,7 @@ fn update_pair_config() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     };
 
     // Unauthorized err
@@ -381,6 +390,7 @@ fn update_pair_config() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         },
     };
 
@@ -410,6 +420,7 @@ fn update_pair_config() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     };
 
     let info = mock_info(owner, &[]);
@@ -442,12 +453,13 @@ fn create_pair() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
         pair_configs: pair_configs.clone(),
         token_code_id: 123u64,
-        fee_address: None,
+        fee_address: Some(String::from("fee_address")),
         owner: owner.to_string(),
         generator_address: Some(String::from("generator")),
         whitelist_code_id: 234u64,
@@ -482,6 +494,7 @@ fn create_pair() {
         ]
         .to_vec(),
         init_params: None,
+        fee_tier: None,
     };
 
     let res = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
@@ -496,6 +509,45 @@ fn create_pair() {
         ]
     );
 
+    // The fee goes to the fee address and the pair is instantiated without funds
+    let config = CONFIG.load(&deps.storage).unwrap();
+    assert_eq!(
+        res.messages,
+        vec![
+            SubMsg {
+                msg: WasmMsg::Instantiate {
+                    msg: to_json_binary(&PairInstantiateMsg {
+                        pair_type: PairType::Xyk {},
+                        asset_infos: vec![
+                            AssetInfo::NativeToken {
+                                denom: "uluna".to_string(),
+                            },
+                            AssetInfo::Token {
+                                contract_addr: Addr::unchecked("asset0001"),
+                            },
+                        ],
+                        token_code_id: 123u64,
+                        factory_addr: String::from(MOCK_CONTRACT_ADDR),
+                        init_params: None,
+                    })
+                    .unwrap(),
+                    code_id: pair_configs[0].code_id,
+                    funds: vec![],
+                    admin: Some(config.owner.to_string()),
+                    label: String::from("Oroswap pair xyk"),
+                }
+                .into(),
+                id: 1,
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
+            },
+            SubMsg::new(BankMsg::Send {
+                to_address: String::from("fee_address"),
+                amount: coins(1000, "uzig"),
+            }),
+        ]
+    );
+
     // Handle the reply from pair creation
     let instantiate_reply = MsgInstantiateContractResponse {
         contract_address: String::from("pair0000"),
@@ -560,6 +612,183 @@ fn create_pair() {
     assert_eq!(pair_res.contract_addr, Addr::unchecked("pair0000"));
 }
 
+#[test]
+fn create_pair_pool_creation_fee() {
+    let mut deps = mock_dependencies(&[]);
+    let owner = "owner0000";
+
+    let msg = InstantiateMsg {
+        pair_configs: vec![
+            PairConfig {
+                code_id: 123u64,
+                pair_type: PairType::Xyk {},
+                total_fee_bps: 30,
+                maker_fee_bps: 3333,
+                is_disabled: false,
+                is_generator_disabled: false,
+                permissioned: false,
+                pool_creation_fee: Uint128::new(500),
+                pool_creation_fee_denom: "uoro".to_string(),
+            },
+            PairConfig {
+                code_id: 325u64,
+                pair_type: PairType::Stable {},
+                total_fee_bps: 5,
+                maker_fee_bps: 5000,
+                is_disabled: false,
+                is_generator_disabled: false,
+                permissioned: false,
+                pool_creation_fee: Uint128::zero(),
+                pool_creation_fee_denom: "uzig".to_string(),
+            },
+        ],
+        token_code_id: 123u64,
+        fee_address: None,
+        owner: owner.to_string(),
+        generator_address: None,
+        whitelist_code_id: 234u64,
+        coin_registry_address: "coin_registry".to_string(),
+        tracker_config: None,
+    };
+
+    let env = mock_env();
+    instantiate(deps.as_mut(), env.clone(), mock_info(owner, &[]), msg).unwrap();
+
+    // UIs can query exactly what to attach
+    let fee: Vec<Coin> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::PoolCreationFee {
+                pair_type: PairType::Xyk {},
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(fee, coins(500, "uoro"));
+
+    let fee: Vec<Coin> = from_json(
+        query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::PoolCreationFee {
+                pair_type: PairType::Stable {},
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(fee, vec![]);
+
+    let create_pair_msg = |pair_type: PairType| ExecuteMsg::CreatePair {
+        pair_type,
+        asset_infos: vec![
+            AssetInfo::NativeToken {
+                denom: "uluna".to_string(),
+            },
+            AssetInfo::Token {
+                contract_addr: Addr::unchecked("asset0001"),
+            },
+        ],
+        init_params: None,
+        fee_tier: None,
+    };
+
+    // The fee must be paid in the configured denom
+    let info = mock_info("addr0000", &coins(1000, "uzig"));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        create_pair_msg(PairType::Xyk {}),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::MissingPoolCreationFee {});
+
+    let info = mock_info("addr0000", &coins(400, "uoro"));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        create_pair_msg(PairType::Xyk {}),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidPoolCreationFee {
+            required: Uint128::new(500)
+        }
+    );
+
+    // The fee can't be collected without a fee address
+    let info = mock_info("addr0000", &coins(500, "uoro"));
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        create_pair_msg(PairType::Xyk {}),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::FeeAddressNotSet {});
+
+    let msg = ExecuteMsg::UpdateConfig {
+        token_code_id: None,
+        fee_address: Some(String::from("fee_address")),
+        generator_address: None,
+        whitelist_code_id: None,
+        coin_registry_address: None,
+    };
+    execute(deps.as_mut(), env.clone(), mock_info(owner, &[]), msg).unwrap();
+
+    // Excess funds are refunded to the sender
+    let info = mock_info(
+        "addr0000",
+        &[Coin::new(600, "uoro"), Coin::new(100, "uzig")],
+    );
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        info,
+        create_pair_msg(PairType::Xyk {}),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[1..],
+        [
+            SubMsg::new(BankMsg::Send {
+                to_address: String::from("fee_address"),
+                amount: coins(500, "uoro"),
+            }),
+            SubMsg::new(BankMsg::Send {
+                to_address: String::from("addr0000"),
+                amount: vec![Coin::new(100, "uoro"), Coin::new(100, "uzig")],
+            }),
+        ]
+    );
+    assert_eq!(
+        res.attributes.last().unwrap(),
+        &attr("refund", "100uoro,100uzig")
+    );
+
+    // Pairs without a fee refund everything
+    let info = mock_info("addr0000", &coins(100, "uzig"));
+    let res = execute(
+        deps.as_mut(),
+        env,
+        info,
+        create_pair_msg(PairType::Stable {}),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[1..],
+        [SubMsg::new(BankMsg::Send {
+            to_address: String::from("addr0000"),
+            amount: coins(100, "uzig"),
+        })]
+    );
+}
+
 #[test]
 fn register() {
     let mut deps = mock_dependencies(&[]);
@@ -575,9 +804,10 @@ fn register() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: 123u64,
-        fee_address: None,
+        fee_address: Some(String::from("fee_address")),
         generator_address: Some(String::from("generator")),
         owner: owner.to_string(),
         whitelist_code_id: 234u64,
@@ -605,6 +835,7 @@ fn register() {
         pair_type: PairType::Xyk {},
         asset_infos: asset_infos.clone(),
         init_params: None,
+        fee_tier: None,
     };
 
     let env = mock_env();
@@ -686,6 +917,7 @@ fn register() {
         pair_type: PairType::Xyk {},
         asset_infos: asset_infos_2.clone(),
         init_params: None,
+        fee_tier: None,
     };
 
     let env = mock_env();
@@ -840,6 +1072,80 @@ fn register() {
     );
 }
 
+#[test]
+fn deregister_pair_without_lp_token() {
+    let mut deps = mock_dependencies(&[]);
+    let owner = "owner0000";
+
+    let msg = InstantiateMsg {
+        pair_configs: vec![PairConfig {
+            code_id: 123u64,
+            pair_type: PairType::Custom(XORO_PAIR_TYPE.to_string()),
+            total_fee_bps: 0,
+            maker_fee_bps: 0,
+            is_disabled: false,
+            is_generator_disabled: true,
+            permissioned: true,
+            pool_creation_fee: Uint128::zero(),
+            pool_creation_fee_denom: "uzig".to_string(),
+        }],
+        token_code_id: 123u64,
+        fee_address: None,
+        owner: owner.to_string(),
+        generator_address: Some(String::from("generator")),
+        whitelist_code_id: 234u64,
+        coin_registry_address: "coin_registry".to_string(),
+        tracker_config: None,
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info(owner, &[]), msg).unwrap();
+
+    let asset_infos = vec![AssetInfo::native("oro"), AssetInfo::native("xoro")];
+    let pair_type = PairType::Custom(XORO_PAIR_TYPE.to_string());
+    let pair_addr = "xoro_pair".to_string();
+    let pair_info = PairInfo {
+        asset_infos: asset_infos.clone(),
+        contract_addr: Addr::unchecked(&pair_addr),
+        liquidity_token: "".to_owned(),
+        pair_type: pair_type.clone(),
+    };
+    PAIRS
+        .save(
+            deps.as_mut().storage,
+            &pair_key(&asset_infos, &pair_type),
+            &Addr::unchecked(&pair_addr),
+        )
+        .unwrap();
+    deps.querier.with_oroswap_pairs(&[(&pair_addr, &pair_info)]);
+
+    // The generator isn't asked to deactivate a pool for an empty LP token
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(owner, &[]),
+        ExecuteMsg::Deregister {
+            asset_infos: asset_infos.clone(),
+            pair_type,
+        },
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    assert_eq!(res.attributes[0], attr("action", "deregister"));
+
+    let pairs_res: PairsResponse = from_json(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Pairs {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(pairs_res.pairs.is_empty());
+}
+
 #[test]
 fn test_pause_pairs_batch() {
     let mut deps = mock_dependencies(&[]);
@@ -856,12 +1162,13 @@ fn test_pause_pairs_batch() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
         pair_configs: pair_configs.clone(),
         token_code_id: 123u64,
-        fee_address: None,
+        fee_address: Some(String::from("fee_address")),
         owner: owner.to_string(),
         generator_address: Some(String::from("generator")),
         whitelist_code_id: 234u64,
@@ -914,6 +1221,7 @@ fn test_pause_pairs_batch() {
             pair_type: PairType::Xyk {},
             asset_infos: asset_infos.to_vec(),
             init_params: None,
+            fee_tier: None,
         };
         let info = mock_info("addr0000", &[Coin {
             denom: "uzig".to_string(),
@@ -1016,12 +1324,13 @@ fn test_unpause_pairs_batch() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
         pair_configs: pair_configs.clone(),
         token_code_id: 123u64,
-        fee_address: None,
+        fee_address: Some(String::from("fee_address")),
         owner: owner.to_string(),
         generator_address: Some(String::from("generator")),
         whitelist_code_id: 234u64,
@@ -1065,6 +1374,7 @@ fn test_unpause_pairs_batch() {
             pair_type: PairType::Xyk {},
             asset_infos: asset_infos.to_vec(),
             init_params: None,
+            fee_tier: None,
         };
         let info = mock_info("addr0000", &[Coin {
             denom: "uzig".to_string(),
@@ -1167,6 +1477,7 @@ fn test_pause_authority_validation() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
@@ -1231,6 +1542,7 @@ fn test_add_pause_authorities() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
@@ -1289,12 +1601,13 @@ fn test_pause_individual_pair() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
         pair_configs: pair_configs.clone(),
         token_code_id: 123u64,
-        fee_address: None,
+        fee_address: Some(String::from("fee_address")),
         owner: owner.to_string(),
         generator_address: Some(String::from("generator")),
         whitelist_code_id: 234u64,
@@ -1327,6 +1640,7 @@ fn test_pause_individual_pair() {
         pair_type: PairType::Xyk {},
         asset_infos: asset_infos.clone(),
         init_params: None,
+        fee_tier: None,
     };
     let info = mock_info("addr0000", &[Coin {
         denom: "uzig".to_string(),
@@ -1435,12 +1749,13 @@ fn test_pause_all_pairs() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
         pair_configs: pair_configs.clone(),
         token_code_id: 123u64,
-        fee_address: None,
+        fee_address: Some(String::from("fee_address")),
         owner: owner.to_string(),
         generator_address: Some(String::from("generator")),
         whitelist_code_id: 234u64,
@@ -1494,6 +1809,7 @@ fn test_pause_all_pairs() {
             pair_type: PairType::Xyk {},
             asset_infos: asset_infos.clone(),
             init_params: None,
+            fee_tier: None,
         };
         let info = mock_info("addr0000", &[Coin {
             denom: "uzig".to_string(),
@@ -1597,6 +1913,7 @@ fn test_batch_size_validation() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
@@ -1656,6 +1973,7 @@ fn test_query_functions() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
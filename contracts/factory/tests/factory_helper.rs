@@ -84,6 +84,7 @@ impl FactoryHelper {
                     is_generator_disabled: false,
                     permissioned: false,
                     pool_creation_fee: Uint128::new(1000),
+                    pool_creation_fee_denom: "uzig".to_string(),
                 },
                 PairConfig {
                     code_id: pair_code_id,
@@ -94,6 +95,7 @@ impl FactoryHelper {
                     is_generator_disabled: false,
                     permissioned: true,
                     pool_creation_fee: Uint128::new(1000),
+                    pool_creation_fee_denom: "uzig".to_string(),
                 },
                 PairConfig {
                     code_id: pair_code_id,
@@ -104,6 +106,7 @@ impl FactoryHelper {
                     is_generator_disabled: false,
                     permissioned: false,
                     pool_creation_fee: Uint128::new(1000),
+                    pool_creation_fee_denom: "uzig".to_string(),
                 },
             ],
             token_code_id: cw20_token_code_id,
@@ -176,6 +179,7 @@ impl FactoryHelper {
             pair_type,
             asset_infos,
             init_params,
+            fee_tier: None,
         };
 
         router.execute_contract(sender.clone(), self.factory.clone(), &msg, &[Coin {
@@ -184,6 +188,40 @@ impl FactoryHelper {
         }])
     }
 
+    pub fn set_pair_maker_fee(
+        &mut self,
+        router: &mut TestApp,
+        sender: &Addr,
+        pair_type: PairType,
+        tokens: [&Addr; 2],
+        fee_address: &Addr,
+        maker_fee_bps: u16,
+    ) -> AnyResult<AppResponse> {
+        let msg = oroswap::factory::ExecuteMsg::SetPairMakerFee {
+            asset_infos: token_asset_infos(tokens),
+            pair_type,
+            fee_address: fee_address.to_string(),
+            maker_fee_bps,
+        };
+
+        router.execute_contract(sender.clone(), self.factory.clone(), &msg, &[])
+    }
+
+    pub fn remove_pair_maker_fee(
+        &mut self,
+        router: &mut TestApp,
+        sender: &Addr,
+        pair_type: PairType,
+        tokens: [&Addr; 2],
+    ) -> AnyResult<AppResponse> {
+        let msg = oroswap::factory::ExecuteMsg::RemovePairMakerFee {
+            asset_infos: token_asset_infos(tokens),
+            pair_type,
+        };
+
+        router.execute_contract(sender.clone(), self.factory.clone(), &msg, &[])
+    }
+
     pub fn update_tracker_config(
         &mut self,
         router: &mut TestApp,
@@ -207,6 +245,15 @@ impl FactoryHelper {
     }
 }
 
+pub fn token_asset_infos(tokens: [&Addr; 2]) -> Vec<AssetInfo> {
+    tokens
+        .iter()
+        .map(|token| AssetInfo::Token {
+            contract_addr: (*token).clone(),
+        })
+        .collect()
+}
+
 pub fn instantiate_token(
     app: &mut TestApp,
     token_code_id: u64,
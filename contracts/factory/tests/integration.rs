@@ -2,15 +2,16 @@
 
 mod factory_helper;
 
-use cosmwasm_std::{attr, Addr, StdError, Uint128};
+use cosmwasm_std::{attr, to_json_binary, Addr, StdError, Uint128};
 
-use oroswap::asset::{AssetInfo, PairInfo};
+use oroswap::asset::{AssetInfo, AssetInfoExt, PairInfo};
 use oroswap::factory::{
-    ConfigResponse, ExecuteMsg, FeeInfoResponse, InstantiateMsg, PairConfig, PairType, QueryMsg,
-    TrackerConfig,
+    ConfigResponse, ExecuteMsg, FeeInfoResponse, InstantiateMsg, PairConfig, PairMakerFeeResponse,
+    PairType, QueryMsg, TrackerConfig,
 };
+use oroswap::pair_xyk_sale_tax::{SaleTaxInitParams, SALE_TAX_PAIR_TYPE};
 
-use crate::factory_helper::{instantiate_token, FactoryHelper};
+use crate::factory_helper::{instantiate_token, token_asset_infos, FactoryHelper};
 use oroswap_factory::error::ContractError;
 use oroswap_test::cw_multi_test::{AppBuilder, ContractWrapper, Executor, BankSudo};
 use oroswap_test::modules::stargate::{MockStargate, StargateApp as TestApp};
@@ -74,6 +75,7 @@ fn proper_initialization() {
         is_generator_disabled: false,
         permissioned: false,
         pool_creation_fee: Uint128::new(1000),
+        pool_creation_fee_denom: "uzig".to_string(),
     }];
 
     let msg = InstantiateMsg {
@@ -239,6 +241,7 @@ fn test_create_pair() {
                 is_generator_disabled: false,
                 permissioned: false,
                 pool_creation_fee: Uint128::new(1000),
+                pool_creation_fee_denom: "uzig".to_string(),
             },
         },
         &[],
@@ -484,6 +487,7 @@ fn tracker_config() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: 0,
         generator_address: None,
@@ -511,3 +515,538 @@ fn tracker_config() {
     assert_eq!(tracker_config.token_factory_addr, "token_factory_addr");
     assert_eq!(tracker_config.code_id, 64);
 }
+
+#[test]
+fn pair_maker_fee_override() {
+    let mut app = mock_app();
+    let owner = Addr::unchecked("owner");
+    let treasury = Addr::unchecked("treasury");
+    let user = Addr::unchecked("user");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let token1 = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "tokenX", None);
+    let token2 = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "tokenY", None);
+    let asset_infos = token_asset_infos([&token1, &token2]);
+
+    // Can't override the maker fee of a pair that doesn't exist
+    helper
+        .set_pair_maker_fee(
+            &mut app,
+            &owner,
+            PairType::Xyk {},
+            [&token1, &token2],
+            &treasury,
+            5000,
+        )
+        .unwrap_err();
+
+    helper
+        .create_pair(&mut app, &owner, PairType::Xyk {}, [&token1, &token2], None)
+        .unwrap();
+
+    let err = helper
+        .set_pair_maker_fee(
+            &mut app,
+            &Addr::unchecked("random_stranger"),
+            PairType::Xyk {},
+            [&token1, &token2],
+            &treasury,
+            5000,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    let err = helper
+        .set_pair_maker_fee(
+            &mut app,
+            &owner,
+            PairType::Xyk {},
+            [&token1, &token2],
+            &treasury,
+            10001,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::PairMakerFeeInvalidBps {}
+    );
+
+    helper
+        .set_pair_maker_fee(
+            &mut app,
+            &owner,
+            PairType::Xyk {},
+            [&token1, &token2],
+            &treasury,
+            5000,
+        )
+        .unwrap();
+
+    let fee_info: FeeInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairFeeInfo {
+                asset_infos: asset_infos.clone(),
+                pair_type: PairType::Xyk {},
+            },
+        )
+        .unwrap();
+    assert_eq!(fee_info.fee_address, Some(treasury.clone()));
+    assert_eq!(fee_info.total_fee_bps, 100);
+    assert_eq!(fee_info.maker_fee_bps, 5000);
+
+    let overrides: Vec<PairMakerFeeResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairMakerFees {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(overrides.len(), 1);
+    assert_eq!(overrides[0].pair_info.asset_infos, asset_infos);
+    assert_eq!(overrides[0].fee_address, treasury);
+    assert_eq!(overrides[0].maker_fee_bps, 5000);
+    let pair = overrides[0].pair_info.contract_addr.clone();
+
+    // Provide liquidity and swap to check the maker fee is paid in-kind to the treasury
+    for token in [&token1, &token2] {
+        app.execute_contract(
+            owner.clone(),
+            token.clone(),
+            &cw20::Cw20ExecuteMsg::Mint {
+                recipient: owner.to_string(),
+                amount: Uint128::new(1_001_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            token.clone(),
+            &cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                spender: pair.to_string(),
+                expires: None,
+                amount: Uint128::new(1_000_000_000),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    app.execute_contract(
+        owner.clone(),
+        pair.clone(),
+        &oroswap::pair::ExecuteMsg::ProvideLiquidity {
+            assets: asset_infos
+                .iter()
+                .map(|info| info.with_balance(1_000_000_000u128))
+                .collect(),
+            slippage_tolerance: None,
+            auto_stake: None,
+            receiver: None,
+            min_lp_to_receive: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let res = app
+        .execute_contract(
+            owner.clone(),
+            token1.clone(),
+            &cw20::Cw20ExecuteMsg::Send {
+                contract: pair.to_string(),
+                amount: Uint128::new(1_000_000),
+                msg: to_json_binary(&oroswap::pair::Cw20HookMsg::Swap {
+                    ask_asset_info: None,
+                    belief_price: None,
+                    max_spread: None,
+                    to: Some(user.to_string()),
+                })
+                .unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let swap_attr = |key: &str| -> Uint128 {
+        res.events
+            .iter()
+            .flat_map(|event| event.attributes.iter())
+            .find(|attribute| attribute.key == key)
+            .unwrap()
+            .value
+            .parse()
+            .unwrap()
+    };
+    let maker_fee_amount = swap_attr("maker_fee_amount");
+    assert_eq!(
+        maker_fee_amount,
+        swap_attr("commission_amount").multiply_ratio(1u128, 2u128)
+    );
+
+    let treasury_balance: cw20::BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &token2,
+            &cw20::Cw20QueryMsg::Balance {
+                address: treasury.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(treasury_balance.balance, maker_fee_amount);
+
+    helper
+        .remove_pair_maker_fee(&mut app, &owner, PairType::Xyk {}, [&token1, &token2])
+        .unwrap();
+
+    let err = helper
+        .remove_pair_maker_fee(&mut app, &owner, PairType::Xyk {}, [&token1, &token2])
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::PairMakerFeeNotFound {}
+    );
+
+    // Without an override the pair falls back to its pair type config
+    let fee_info: FeeInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::PairFeeInfo {
+                asset_infos,
+                pair_type: PairType::Xyk {},
+            },
+        )
+        .unwrap();
+    assert_eq!(fee_info.fee_address, Some(owner));
+    assert_eq!(fee_info.maker_fee_bps, 10);
+}
+
+#[test]
+fn pair_fee_tiers() {
+    let mut app = mock_app();
+    let owner = Addr::unchecked("owner");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let token1 = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "tokenX", None);
+    let token2 = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "tokenY", None);
+    let asset_infos = token_asset_infos([&token1, &token2]);
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("random_stranger"),
+            helper.factory.clone(),
+            &ExecuteMsg::AddFeeTiers {
+                fee_tiers: vec![5, 30],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::AddFeeTiers {
+                fee_tiers: vec![10001],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidFeeTier { fee_tier: 10001 }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::AddFeeTiers {
+            fee_tiers: vec![30, 5],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let fee_tiers: Vec<u16> = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::FeeTiers {})
+        .unwrap();
+    assert_eq!(fee_tiers, vec![5, 30]);
+
+    let create_pair_msg = |fee_tier: u16| ExecuteMsg::CreatePair {
+        pair_type: PairType::Xyk {},
+        asset_infos: asset_infos.clone(),
+        init_params: None,
+        fee_tier: Some(fee_tier),
+    };
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &create_pair_msg(50),
+            &coins(1000, "uzig"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::FeeTierNotAllowed { fee_tier: 50 }
+    );
+
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &create_pair_msg(30),
+        &coins(1000, "uzig"),
+    )
+    .unwrap();
+
+    let pair: PairInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pair_type: PairType::Xyk {},
+            },
+        )
+        .unwrap();
+
+    let query_fee_info = |app: &TestApp| -> FeeInfoResponse {
+        app.wrap()
+            .query_wasm_smart(
+                &helper.factory,
+                &QueryMsg::FeeInfoByPair {
+                    pair_addr: pair.contract_addr.to_string(),
+                },
+            )
+            .unwrap()
+    };
+
+    let fee_info = query_fee_info(&app);
+    assert_eq!(fee_info.total_fee_bps, 30);
+    assert_eq!(fee_info.maker_fee_bps, 10);
+
+    // Only registered pairs can be queried by address
+    app.wrap()
+        .query_wasm_smart::<FeeInfoResponse>(
+            &helper.factory,
+            &QueryMsg::FeeInfoByPair {
+                pair_addr: token1.to_string(),
+            },
+        )
+        .unwrap_err();
+
+    // Governance moves the pair to another tier
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdatePairFeeTier {
+            asset_infos: asset_infos.clone(),
+            pair_type: PairType::Xyk {},
+            fee_tier: Some(5),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_fee_info(&app).total_fee_bps, 5);
+
+    // Removing a tier from the whitelist keeps it on existing pairs but prevents new assignments
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::RemoveFeeTiers {
+            fee_tiers: vec![5, 30],
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_fee_info(&app).total_fee_bps, 5);
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::UpdatePairFeeTier {
+                asset_infos: asset_infos.clone(),
+                pair_type: PairType::Xyk {},
+                fee_tier: Some(30),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::FeeTierNotAllowed { fee_tier: 30 }
+    );
+
+    // Concentrated pairs don't use the factory's total fee
+    let pcl_asset_infos = token_asset_infos([&token1, &token2]);
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::CreatePair {
+                pair_type: PairType::Custom("concentrated".to_string()),
+                asset_infos: pcl_asset_infos.clone(),
+                init_params: None,
+                fee_tier: Some(30),
+            },
+            &coins(1000, "uzig"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::FeeTierNotSupported {
+            pair_type: "custom-concentrated".to_string()
+        }
+    );
+
+    helper
+        .create_pair(
+            &mut app,
+            &owner,
+            PairType::Custom("concentrated".to_string()),
+            [&token1, &token2],
+            None,
+        )
+        .unwrap();
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            helper.factory.clone(),
+            &ExecuteMsg::UpdatePairFeeTier {
+                asset_infos: pcl_asset_infos,
+                pair_type: PairType::Custom("concentrated".to_string()),
+                fee_tier: Some(5),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::FeeTierNotSupported {
+            pair_type: "custom-concentrated".to_string()
+        }
+    );
+
+    // Without a fee tier the pair falls back to its pair type config
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdatePairFeeTier {
+            asset_infos,
+            pair_type: PairType::Xyk {},
+            fee_tier: None,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_fee_info(&app).total_fee_bps, 100);
+}
+
+#[test]
+fn sale_tax_pair_fee_tier() {
+    let mut app = mock_app();
+    let owner = Addr::unchecked("owner");
+    let helper = FactoryHelper::init(&mut app, &owner);
+
+    let token1 = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "tokenX", None);
+    let token2 = instantiate_token(&mut app, helper.cw20_token_code_id, &owner, "tokenY", None);
+    let asset_infos = token_asset_infos([&token1, &token2]);
+    let pair_type = PairType::Custom(SALE_TAX_PAIR_TYPE.to_string());
+
+    // Sale tax pairs are XYK pairs and use the factory's total fee as well
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&helper.factory, &QueryMsg::Config {})
+        .unwrap();
+    let xyk_config = config
+        .pair_configs
+        .into_iter()
+        .find(|pair_config| pair_config.pair_type == PairType::Xyk {})
+        .unwrap();
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdatePairConfig {
+            config: PairConfig {
+                pair_type: pair_type.clone(),
+                ..xyk_config
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::AddFeeTiers {
+            fee_tiers: vec![5, 30],
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::CreatePair {
+            pair_type: pair_type.clone(),
+            asset_infos: asset_infos.clone(),
+            init_params: Some(to_json_binary(&SaleTaxInitParams::default()).unwrap()),
+            fee_tier: Some(30),
+        },
+        &coins(1000, "uzig"),
+    )
+    .unwrap();
+
+    let pair: PairInfo = app
+        .wrap()
+        .query_wasm_smart(
+            &helper.factory,
+            &QueryMsg::Pair {
+                asset_infos: asset_infos.clone(),
+                pair_type: pair_type.clone(),
+            },
+        )
+        .unwrap();
+    let query_fee_info = |app: &TestApp| -> FeeInfoResponse {
+        app.wrap()
+            .query_wasm_smart(
+                &helper.factory,
+                &QueryMsg::FeeInfoByPair {
+                    pair_addr: pair.contract_addr.to_string(),
+                },
+            )
+            .unwrap()
+    };
+    assert_eq!(query_fee_info(&app).total_fee_bps, 30);
+
+    app.execute_contract(
+        owner.clone(),
+        helper.factory.clone(),
+        &ExecuteMsg::UpdatePairFeeTier {
+            asset_infos,
+            pair_type,
+            fee_tier: Some(5),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(query_fee_info(&app).total_fee_bps, 5);
+}
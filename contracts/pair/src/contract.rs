@@ -7,8 +7,8 @@ use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, coin, ensure_eq, from_json, to_json_binary, wasm_execute, Addr, Binary, BankMsg, Coin, CosmosMsg,
     CustomMsg, CustomQuery, Decimal, Decimal256, Deps, DepsMut, Empty, Env, Fraction, MessageInfo,
-    QuerierWrapper, Reply, Response, StdError, StdResult, SubMsg, SubMsgResponse, SubMsgResult,
-    Uint128, Uint256, Uint64, WasmMsg,
+    QuerierWrapper, Reply, Response, StdError, StdResult, Storage, SubMsg, SubMsgResponse,
+    SubMsgResult, Uint128, Uint256, Uint64, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
@@ -21,30 +21,43 @@ use oroswap::asset::{
     MINIMUM_LIQUIDITY_AMOUNT, CoinsExt,
 };
 use oroswap::common::LP_SUBDENOM;
+use oroswap::factory::PairType;
 use oroswap::incentives::ExecuteMsg as IncentiveExecuteMsg;
+use oroswap::observation::{
+    accumulate_swap_sizes, query_observation, PrecommitObservation, OBSERVATIONS_SIZE,
+};
 use oroswap::pair::{
     ConfigResponse, FeeShareConfig, ReplyIds, XYKPoolConfig, XYKPoolParams, XYKPoolUpdateParams,
     DEFAULT_SLIPPAGE, MAX_ALLOWED_SLIPPAGE, MAX_FEE_SHARE_BPS,
 };
 use oroswap::pair::{
     CumulativePricesResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, PoolResponse, QueryMsg,
-    ReverseSimulationResponse, SimulationResponse, TWAP_PRECISION,
+    ReverseSimulationResponse, SimulateImbalancedWithdrawResponse, SimulationResponse,
+    TWAP_PRECISION,
+};
+use oroswap::pair_xyk_sale_tax::{
+    SaleTaxConfigUpdates, SaleTaxInitParams, SaleTaxPoolConfig, TaxConfigChecked,
+    SALE_TAX_PAIR_TYPE,
 };
 use oroswap::querier::{
-    query_factory_config, query_fee_info, query_native_supply, query_tracker_config,
+    query_factory_config, query_native_supply, query_pair_fee_info, query_tracker_config,
 };
 use oroswap::token_factory::{
     tf_before_send_hook_msg, tf_burn_msg, tf_create_denom_msg, tf_mint_msg, MsgCreateDenomResponse,
 };
 use oroswap::{tokenfactory_tracker, U256};
+use oroswap_circular_buffer::BufferManager;
 
 use crate::error::ContractError;
-use crate::state::{Config, BALANCES, CONFIG};
+use crate::state::{Config, SaleTaxConfig, BALANCES, CONFIG, OBSERVATIONS};
 
 /// Contract name that is used for migration.
 const CONTRACT_NAME: &str = "oroswap-pair";
 /// Contract version that is used for migration.
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Minimum amount of offer and ask assets for a swap to be recorded as a price observation.
+/// Smaller trades are skipped as rounding errors can seriously mess the oracle price.
+const MIN_OBSERVATION_TRADE_SIZE: Uint128 = Uint128::new(1000);
 
 /// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -65,18 +78,24 @@ pub fn instantiate(
         return Err(ContractError::DoublingAssets {});
     }
 
-    // Get factory config to check maker fee address
-    let factory_config = query_factory_config(&deps.querier, &msg.factory_addr)?;
-    let fee_address = factory_config.fee_address.ok_or(ContractError::MakerFeeAddressNotSet {})?;
-
-    // Get pair config to check pool creation fee
-    let pair_config = query_fee_info(&deps.querier, &msg.factory_addr, msg.pair_type.clone())?; // Clone here
-
     let mut track_asset_balances = false;
-
-    if let Some(init_params) = msg.init_params {
-        let params: XYKPoolParams = from_json(init_params)?;
-        track_asset_balances = params.track_asset_balances.unwrap_or_default();
+    let mut sale_tax = None;
+
+    match &msg.pair_type {
+        PairType::Custom(pair_type) if pair_type == SALE_TAX_PAIR_TYPE => {
+            let params = SaleTaxInitParams::from_json(msg.init_params)?;
+            track_asset_balances = params.track_asset_balances;
+            sale_tax = Some(SaleTaxConfig {
+                tax_configs: params.tax_configs.check(deps.api, &msg.asset_infos)?,
+                tax_config_admin: deps.api.addr_validate(&params.tax_config_admin)?,
+            });
+        }
+        _ => {
+            if let Some(init_params) = msg.init_params {
+                let params: XYKPoolParams = from_json(init_params)?;
+                track_asset_balances = params.track_asset_balances.unwrap_or_default();
+            }
+        }
     }
 
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
@@ -96,6 +115,7 @@ pub fn instantiate(
         fee_share: None,
         tracker_addr: None,
         paused: false,
+        sale_tax,
     };
 
     if track_asset_balances {
@@ -105,31 +125,25 @@ pub fn instantiate(
     }
 
     CONFIG.save(deps.storage, &config)?;
+    BufferManager::init(deps.storage, OBSERVATIONS, OBSERVATIONS_SIZE)?;
 
-    // Create messages to:
-    // 1. Create LP token
-    // 2. Forward pool creation fee to maker fee address
-    let mut messages = vec![
-        SubMsg::reply_on_success(
-            tf_create_denom_msg(env.contract.address.to_string(), LP_SUBDENOM),
-            ReplyIds::CreateDenom as u64,
-        ),
-    ];
-
-    // Forward pool creation fee to maker fee address
-    messages.push(SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
-        to_address: fee_address.to_string(),
-        amount: vec![coin(pair_config.pool_creation_fee.u128(), "uzig")],
-    })));
+    // Create LP token
+    let sub_msg = SubMsg::reply_on_success(
+        tf_create_denom_msg(env.contract.address.to_string(), LP_SUBDENOM),
+        ReplyIds::CreateDenom as u64,
+    );
 
-    Ok(Response::new()
-        .add_submessages(messages)
-        .add_attributes(vec![
-            attr("action", "instantiate"),
-            attr("asset_balances_tracking", if config.track_asset_balances { "enabled" } else { "disabled" }),
-            attr("maker_fee_address", fee_address.to_string()),
-            attr("pool_creation_fee", pair_config.pool_creation_fee.to_string()),
-        ]))
+    Ok(Response::new().add_submessage(sub_msg).add_attributes(vec![
+        attr("action", "instantiate"),
+        attr(
+            "asset_balances_tracking",
+            if config.track_asset_balances {
+                "enabled"
+            } else {
+                "disabled"
+            },
+        ),
+    ]))
 }
 
 /// The entry point to the contract for processing replies from submessages.
@@ -533,6 +547,12 @@ where
 }
 
 /// Withdraw liquidity from the pool.
+///
+/// * **assets** is an optional list of assets to withdraw. If it is empty, the LP tokens sent
+/// are burned for a balanced share of the pool. Otherwise, exactly the specified assets are
+/// withdrawn, only the LP tokens needed for that are burned and the rest are refunded.
+///
+/// * **min_assets_to_receive** is an optional list of minimum amounts of assets to receive.
 pub fn withdraw_liquidity(
     deps: DepsMut,
     env: Env,
@@ -554,11 +574,11 @@ pub fn withdraw_liquidity(
 
     let (pools, total_share) = pool_info(deps.querier, &config)?;
 
-    let refund_assets = if assets.is_empty() {
+    let (refund_assets, burn_amount) = if assets.is_empty() {
         // Usual withdraw (balanced)
-        get_share_in_assets(&pools, amount, total_share)
+        (get_share_in_assets(&pools, amount, total_share), amount)
     } else {
-        return Err(StdError::generic_err("Imbalanced withdraw is currently disabled").into());
+        imbalanced_withdraw(deps.as_ref(), &config, &pools, &assets, amount, total_share)?
     };
 
     ensure_min_assets_to_receive(&config, refund_assets.clone(), min_assets_to_receive)?;
@@ -586,27 +606,206 @@ pub fn withdraw_liquidity(
 
     // Update the pool info
     let mut messages = refund_assets
-        .clone()
-        .into_iter()
-        .map(|asset| asset.into_msg(&info.sender))
+        .iter()
+        .filter(|asset| !asset.amount.is_zero())
+        .map(|asset| asset.clone().into_msg(&info.sender))
         .collect::<StdResult<Vec<_>>>()?;
 
     messages.push(tf_burn_msg(
         env.contract.address,
-        coin(amount.u128(), config.pair_info.liquidity_token.to_string()),
+        coin(
+            burn_amount.u128(),
+            config.pair_info.liquidity_token.to_string(),
+        ),
     ));
 
+    // Return the LP tokens that were not needed for an imbalanced withdraw
+    let refunded_share = amount - burn_amount;
+    if !refunded_share.is_zero() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(
+                refunded_share.u128(),
+                config.pair_info.liquidity_token.to_string(),
+            )],
+        }));
+    }
+
     Ok(Response::new().add_messages(messages).add_attributes(vec![
         attr("action", "withdraw_liquidity"),
         attr("sender", &info.sender),
-        attr("withdrawn_share", amount),
+        attr("withdrawn_share", burn_amount),
         attr(
             "refund_assets",
             format!("{}, {}", refund_assets[0], refund_assets[1]),
         ),
+        attr("refunded_share", refunded_share),
     ]))
 }
 
+/// Calculates an imbalanced withdraw of exactly the specified `assets` from the pool.
+/// Returns the assets to send back ordered as in the pool together with the amount of LP tokens to burn.
+///
+/// * **assets** is the list of assets to withdraw. Assets that are not listed are not withdrawn.
+///
+/// * **lp_amount** is the maximum amount of LP tokens that can be burned.
+///
+/// * **total_share** is the total amount of LP tokens currently minted.
+fn imbalanced_withdraw(
+    deps: Deps,
+    config: &Config,
+    pools: &[Asset],
+    assets: &[Asset],
+    lp_amount: Uint128,
+    total_share: Uint128,
+) -> Result<(Vec<Asset>, Uint128), ContractError> {
+    let withdrawals = get_withdrawals_from_assets(deps, assets, pools)?;
+
+    let fee_info = query_pair_fee_info(
+        &deps.querier,
+        &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
+        config.pair_info.pair_type.clone(),
+    )?;
+
+    let burn_amount = compute_imbalanced_withdraw_share(
+        pools,
+        &withdrawals,
+        total_share,
+        fee_info.total_fee_rate,
+    )?;
+
+    if burn_amount > lp_amount {
+        return Err(ContractError::InsufficientLpTokens {
+            required: burn_amount,
+            provided: lp_amount,
+        });
+    }
+
+    let refund_assets = pools
+        .iter()
+        .zip(withdrawals)
+        .map(|(pool, amount)| Asset {
+            info: pool.info.clone(),
+            amount,
+        })
+        .collect();
+
+    Ok((refund_assets, burn_amount))
+}
+
+/// Verify assets requested for an imbalanced withdraw and returns withdraw amounts ordered as in the pool.
+///
+/// * **assets** is an array with assets to withdraw. Pool assets which are not specified are not withdrawn.
+///
+/// * **pools** is the array with assets in the pool.
+pub fn get_withdrawals_from_assets(
+    deps: Deps,
+    assets: &[Asset],
+    pools: &[Asset],
+) -> Result<[Uint128; 2], ContractError> {
+    if assets.len() > 2 {
+        return Err(ContractError::WrongAssetLength {
+            expected: 2,
+            actual: assets.len(),
+        });
+    }
+
+    let mut withdrawals = [Uint128::zero(); 2];
+    let mut specified = [false; 2];
+    for asset in assets {
+        asset.info.check(deps.api)?;
+
+        let ind = pools
+            .iter()
+            .position(|pool| pool.info.equal(&asset.info))
+            .ok_or(ContractError::AssetMismatch {})?;
+
+        if specified[ind] {
+            return Err(ContractError::DoublingAssets {});
+        }
+        specified[ind] = true;
+        withdrawals[ind] = asset.amount;
+    }
+
+    if withdrawals.iter().all(|amount| amount.is_zero()) {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    Ok(withdrawals)
+}
+
+/// Returns the amount of LP tokens that must be burned to withdraw exactly `withdrawals` from the pool.
+///
+/// An imbalanced withdraw is treated as a balanced withdraw followed by a swap. The pool fee is charged
+/// on the difference between the reserves left after the withdraw and the reserves a balanced withdraw
+/// of the same value would leave. Both legs of the implicit swap count towards that difference,
+/// so each side pays half of the fee rate. Fees stay in the pool and accrue to the remaining LPs.
+///
+/// * **pools** is the array with assets in the pool.
+///
+/// * **withdrawals** are the amounts to withdraw ordered as in the pool.
+///
+/// * **total_share** is the total amount of LP tokens currently minted.
+///
+/// * **commission_rate** is the pool fee rate.
+pub fn compute_imbalanced_withdraw_share(
+    pools: &[Asset],
+    withdrawals: &[Uint128; 2],
+    total_share: Uint128,
+    commission_rate: Decimal,
+) -> Result<Uint128, ContractError> {
+    let old_reserves = [
+        U256::from(pools[0].amount.u128()),
+        U256::from(pools[1].amount.u128()),
+    ];
+
+    let mut new_reserves = [U256::zero(); 2];
+    for (i, reserve) in new_reserves.iter_mut().enumerate() {
+        let remaining = pools[i]
+            .amount
+            .checked_sub(withdrawals[i])
+            .map_err(|_| ContractError::ExceedsPoolReserves {})?;
+        // The pool can't be drained completely
+        if remaining.is_zero() {
+            return Err(ContractError::ExceedsPoolReserves {});
+        }
+        *reserve = U256::from(remaining.u128());
+    }
+
+    let d0 = (old_reserves[0] * old_reserves[1]).integer_sqrt();
+    let d1 = (new_reserves[0] * new_reserves[1]).integer_sqrt();
+
+    // Charge half of the fee rate on the deviation from the balanced reserves on each side
+    let fee_numerator = U256::from(commission_rate.numerator().u128());
+    let fee_denominator = U256::from(commission_rate.denominator().u128()) * U256::from(2u8);
+    for (new_reserve, old_reserve) in new_reserves.iter_mut().zip(old_reserves) {
+        let ideal_reserve = old_reserve * d1 / d0;
+        let difference = if ideal_reserve > *new_reserve {
+            ideal_reserve - *new_reserve
+        } else {
+            *new_reserve - ideal_reserve
+        };
+        *new_reserve = new_reserve
+            .checked_sub(difference * fee_numerator / fee_denominator)
+            .filter(|reserve| !reserve.is_zero())
+            .ok_or(ContractError::ExceedsPoolReserves {})?;
+    }
+
+    let d2 = (new_reserves[0] * new_reserves[1]).integer_sqrt();
+
+    // Round up so that the remaining LPs never lose value
+    let total_share = U256::from(total_share.u128());
+    let burn_amount = (total_share * (d0 - d2) + d0 - U256::one()) / d0;
+    let burn_amount = Uint128::new(burn_amount.as_u128());
+
+    if burn_amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    Ok(burn_amount)
+}
+
 /// Returns the amount of pool assets that correspond to an amount of LP tokens.
 ///
 /// * **pools** is the array with assets in the pool.
@@ -687,13 +886,20 @@ pub fn swap(
     }
 
     // Get fee info from the factory
-    let fee_info = query_fee_info(
+    let fee_info = query_pair_fee_info(
         &deps.querier,
         &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
         config.pair_info.pair_type.clone(),
     )?;
 
-    let offer_amount = offer_asset.amount;
+    // Sale tax is deducted from the offer amount before the swap
+    let tax_config = get_tax_config(&config, &offer_asset.info).cloned();
+    let tax_amount = tax_config
+        .as_ref()
+        .map(|tax_config| offer_asset.amount * tax_config.tax_rate)
+        .unwrap_or_default();
+    let offer_amount = offer_asset.amount.checked_sub(tax_amount)?;
 
     let (return_amount, spread_amount, commission_amount) = compute_swap(
         offer_pool.amount,
@@ -722,6 +928,19 @@ pub fn swap(
         messages.push(return_asset.into_msg(receiver.clone())?)
     }
 
+    // Send the sale tax to the tax recipient
+    if let Some(tax_config) = tax_config {
+        if !tax_amount.is_zero() {
+            messages.push(
+                Asset {
+                    info: offer_asset.info.clone(),
+                    amount: tax_amount,
+                }
+                .into_msg(tax_config.tax_recipient)?,
+            );
+        }
+    }
+
     // If this pool is configured to share fees, calculate the amount to send
     // to the receiver and add the transfer message
     // The calculation works as follows: We take the share percentage first,
@@ -784,7 +1003,7 @@ pub fn swap(
 
     // Accumulate prices for the assets in the pool
     if let Some((price0_cumulative_new, price1_cumulative_new, block_time)) =
-        accumulate_prices(env, &config, pools[0].amount, pools[1].amount)?
+        accumulate_prices(env.clone(), &config, pools[0].amount, pools[1].amount)?
     {
         config.price0_cumulative_last = price0_cumulative_new;
         config.price1_cumulative_last = price1_cumulative_new;
@@ -792,10 +1011,26 @@ pub fn swap(
         CONFIG.save(deps.storage, &config)?;
     }
 
-    Ok(Response::new()
+    // Store observation from precommit data
+    accumulate_swap_sizes(deps.storage, &env, OBSERVATIONS)?;
+
+    // Store time series data in precommit observation.
+    // Skipping small unsafe values which can seriously mess oracle price due to rounding errors.
+    // This data will be reflected in observations on the next action.
+    if offer_amount >= MIN_OBSERVATION_TRADE_SIZE && return_amount >= MIN_OBSERVATION_TRADE_SIZE {
+        let (base_amount, quote_amount) = if offer_pool.info.equal(&pools[0].info) {
+            (offer_amount, return_amount)
+        } else {
+            (return_amount, offer_amount)
+        };
+        PrecommitObservation::save(deps.storage, &env, base_amount, quote_amount)?;
+    }
+
+    let mut response = Response::new()
         .add_messages(
             // 1. send collateral tokens from the contract to a user
-            // 2. send inactive commission fees to the Maker contract
+            // 2. send the sale tax to the tax recipient
+            // 3. send inactive commission fees to the Maker contract
             messages,
         )
         .add_attributes(vec![
@@ -817,7 +1052,27 @@ pub fn swap(
                 ask_pool.info.to_string(),
                 post_ask_reserve
             )),
-        ]))
+        ]);
+
+    if config.sale_tax.is_some() {
+        response = response.add_attribute("tax_amount", tax_amount);
+    }
+
+    Ok(response)
+}
+
+/// Returns the sale tax config for the given offer asset if the pair charges a tax on its sales.
+pub fn get_tax_config<'a>(
+    config: &'a Config,
+    offer_asset_info: &AssetInfo,
+) -> Option<&'a TaxConfigChecked> {
+    match offer_asset_info {
+        AssetInfo::NativeToken { denom } => config
+            .sale_tax
+            .as_ref()
+            .and_then(|sale_tax| sale_tax.tax_configs.get(denom)),
+        AssetInfo::Token { .. } => None,
+    }
 }
 
 /// Updates the pool configuration with the specified parameters in the `params` variable.
@@ -831,6 +1086,30 @@ pub fn update_config(
     let mut config = CONFIG.load(deps.storage)?;
     let factory_config = query_factory_config(&deps.querier, &config.factory_addr)?;
 
+    if let Some(sale_tax) = config.sale_tax.as_mut() {
+        if let Ok(updates) = from_json::<SaleTaxConfigUpdates>(&params) {
+            if info.sender != sale_tax.tax_config_admin && info.sender != factory_config.owner {
+                return Err(ContractError::Unauthorized {});
+            }
+
+            let mut response = Response::new().add_attribute("action", "update_tax_config");
+
+            if let Some(tax_configs) = updates.tax_configs {
+                sale_tax.tax_configs =
+                    tax_configs.check(deps.api, &config.pair_info.asset_infos)?;
+            }
+
+            if let Some(tax_config_admin) = updates.tax_config_admin {
+                sale_tax.tax_config_admin = deps.api.addr_validate(&tax_config_admin)?;
+                response = response.add_attribute("tax_config_admin", tax_config_admin);
+            }
+
+            CONFIG.save(deps.storage, &config)?;
+
+            return Ok(response);
+        }
+    }
+
     if info.sender != factory_config.owner {
         return Err(ContractError::Unauthorized {});
     }
@@ -971,7 +1250,12 @@ pub fn calculate_maker_fee(
 /// just preceeding the moment of the specified block height creation.
 /// * **QueryMsg::SimulateWithdraw { lp_amount }** Returns the amount of assets that could be withdrawn from the pool
 /// using a specific amount of LP tokens. The result is returned in a vector that contains objects of type [`Asset`].
+/// * **QueryMsg::SimulateImbalancedWithdraw { lp_amount, assets }** Simulates an imbalanced withdraw
+/// using a [`SimulateImbalancedWithdrawResponse`] object.
 /// * **QueryMsg::SimulateProvide { msg }** Simulates the liquidity provision in the pair contract.
+///
+/// * **QueryMsg::Observe { seconds_ago }** Returns the SMA price observation at the point that was
+/// `seconds_ago` seconds ago using an [`OracleObservation`](oroswap::observation::OracleObservation) object.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -991,10 +1275,16 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             block_height,
         } => to_json_binary(&query_asset_balances_at(deps, asset_info, block_height)?),
         QueryMsg::SimulateWithdraw { lp_amount } => to_json_binary(&query_share(deps, lp_amount)?),
+        QueryMsg::SimulateImbalancedWithdraw { lp_amount, assets } => to_json_binary(
+            &query_simulate_imbalanced_withdraw(deps, lp_amount, assets)?,
+        ),
         QueryMsg::SimulateProvide {
             assets,
             slippage_tolerance,
         } => to_json_binary(&query_simulate_provide(deps, assets, slippage_tolerance)?),
+        QueryMsg::Observe { seconds_ago } => {
+            to_json_binary(&query_observation(deps, env, OBSERVATIONS, seconds_ago)?)
+        }
         _ => Err(StdError::generic_err("Query is not supported")),
     }
 }
@@ -1025,6 +1315,29 @@ pub fn query_share(deps: Deps, amount: Uint128) -> StdResult<Vec<Asset>> {
     Ok(refund_assets)
 }
 
+/// Returns the result of an imbalanced withdraw simulation.
+///
+/// * **lp_amount** is the maximum amount of LP tokens that can be burned.
+///
+/// * **assets** is the list of assets to withdraw.
+pub fn query_simulate_imbalanced_withdraw(
+    deps: Deps,
+    lp_amount: Uint128,
+    assets: Vec<Asset>,
+) -> StdResult<SimulateImbalancedWithdrawResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (pools, total_share) = pool_info(deps.querier, &config)?;
+
+    let (assets, burn_amount) =
+        imbalanced_withdraw(deps, &config, &pools, &assets, lp_amount, total_share)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(SimulateImbalancedWithdrawResponse {
+        assets,
+        lp_amount: burn_amount,
+    })
+}
+
 /// Returns information about a swap simulation in a [`SimulationResponse`] object.
 ///
 /// * **offer_asset** is the asset to swap as well as an amount of the said asset.
@@ -1050,16 +1363,21 @@ pub fn query_simulation(deps: Deps, offer_asset: Asset) -> StdResult<SimulationR
     }
 
     // Get fee info from the factory contract
-    let fee_info = query_fee_info(
+    let fee_info = query_pair_fee_info(
         &deps.querier,
-        config.factory_addr,
-        config.pair_info.pair_type,
+        &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
+        config.pair_info.pair_type.clone(),
     )?;
 
+    let tax_amount = get_tax_config(&config, &offer_asset.info)
+        .map(|tax_config| offer_asset.amount * tax_config.tax_rate)
+        .unwrap_or_default();
+
     let (return_amount, spread_amount, commission_amount) = compute_swap(
         offer_pool.amount,
         ask_pool.amount,
-        offer_asset.amount,
+        offer_asset.amount.checked_sub(tax_amount)?,
         fee_info.total_fee_rate,
     )?;
 
@@ -1067,6 +1385,7 @@ pub fn query_simulation(deps: Deps, offer_asset: Asset) -> StdResult<SimulationR
         return_amount,
         spread_amount,
         commission_amount,
+        tax_amount,
     })
 }
 
@@ -1099,10 +1418,11 @@ pub fn query_reverse_simulation(
     }
 
     // Get fee info from factory
-    let fee_info = query_fee_info(
+    let fee_info = query_pair_fee_info(
         &deps.querier,
-        config.factory_addr,
-        config.pair_info.pair_type,
+        &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
+        config.pair_info.pair_type.clone(),
     )?;
 
     let (offer_amount, spread_amount, commission_amount) = compute_offer_amount(
@@ -1112,10 +1432,26 @@ pub fn query_reverse_simulation(
         fee_info.total_fee_rate,
     )?;
 
+    // Gross up the offer amount so that it still covers the swap after the sale tax is deducted
+    let (offer_amount, tax_amount) = match get_tax_config(&config, &offer_pool.info) {
+        Some(tax_config) => {
+            let offer_amount_with_tax: Uint128 = (Decimal256::from_ratio(offer_amount, 1u8)
+                / Decimal256::from(Decimal::one() - tax_config.tax_rate))
+            .to_uint_ceil()
+            .try_into()?;
+            (
+                offer_amount_with_tax,
+                offer_amount_with_tax * tax_config.tax_rate,
+            )
+        }
+        None => (offer_amount, Uint128::zero()),
+    };
+
     Ok(ReverseSimulationResponse {
         offer_amount,
         spread_amount,
         commission_amount,
+        tax_amount,
     })
 }
 
@@ -1162,12 +1498,22 @@ pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
 
     let factory_config = query_factory_config(&deps.querier, &config.factory_addr)?;
 
-    Ok(ConfigResponse {
-        block_time_last: config.block_time_last,
-        params: Some(to_json_binary(&XYKPoolConfig {
+    let params = match config.sale_tax {
+        Some(sale_tax) => to_json_binary(&SaleTaxPoolConfig {
             track_asset_balances: config.track_asset_balances,
             fee_share: config.fee_share,
-        })?),
+            tax_configs: sale_tax.tax_configs,
+            tax_config_admin: sale_tax.tax_config_admin,
+        })?,
+        None => to_json_binary(&XYKPoolConfig {
+            track_asset_balances: config.track_asset_balances,
+            fee_share: config.fee_share,
+        })?,
+    };
+
+    Ok(ConfigResponse {
+        block_time_last: config.block_time_last,
+        params: Some(params),
         owner: factory_config.owner,
         factory_addr: config.factory_addr,
         tracker_addr: config.tracker_addr,
@@ -1600,9 +1946,15 @@ fn check_pair_not_paused(deps: &Deps) -> Result<(), ContractError> {
 
 #[cfg(test)]
 mod tests {
-    use cosmwasm_std::{Decimal, Uint128};
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+    use cosmwasm_std::{BlockInfo, Decimal, Timestamp, Uint128};
+    use oroswap::asset::{native_asset, Asset};
+    use oroswap::observation::{accumulate_swap_sizes, PrecommitObservation};
+    use oroswap_circular_buffer::BufferManager;
 
-    use crate::contract::compute_swap;
+    use crate::contract::{compute_imbalanced_withdraw_share, compute_swap};
+    use crate::error::ContractError;
+    use crate::state::OBSERVATIONS;
 
     #[test]
     fn compute_swap_does_not_panic_on_spread_calc() {
@@ -1617,4 +1969,80 @@ mod tests {
         assert_eq!(spread_amount, Uint128::zero());
         assert_eq!(commission_amount, Uint128::zero());
     }
+
+    #[test]
+    fn compute_imbalanced_withdraw_share_charges_fee_on_swap_part() {
+        let pools: Vec<Asset> = vec![
+            native_asset("uusd".to_string(), Uint128::new(1_000_000)),
+            native_asset("uluna".to_string(), Uint128::new(1_000_000)),
+        ];
+        let total_share = Uint128::new(1_000_000);
+        let fee = Decimal::permille(3);
+
+        // A balanced request doesn't pay any fee
+        let share = compute_imbalanced_withdraw_share(
+            &pools,
+            &[Uint128::new(1000), Uint128::new(1000)],
+            total_share,
+            fee,
+        )
+        .unwrap();
+        assert_eq!(share, Uint128::new(1000));
+
+        // A single-sided withdraw pays the fee on the implicit swap
+        let no_fee_share = compute_imbalanced_withdraw_share(
+            &pools,
+            &[Uint128::new(2000), Uint128::zero()],
+            total_share,
+            Decimal::zero(),
+        )
+        .unwrap();
+        assert_eq!(no_fee_share, Uint128::new(1001));
+        let share = compute_imbalanced_withdraw_share(
+            &pools,
+            &[Uint128::new(2000), Uint128::zero()],
+            total_share,
+            fee,
+        )
+        .unwrap();
+        assert_eq!(share, Uint128::new(1002));
+
+        // The pool can't be drained
+        let err = compute_imbalanced_withdraw_share(
+            &pools,
+            &[Uint128::new(1_000_000), Uint128::zero()],
+            total_share,
+            fee,
+        )
+        .unwrap_err();
+        assert_eq!(err, ContractError::ExceedsPoolReserves {});
+    }
+
+    #[test]
+    fn test_swap_observations() {
+        let mut store = MockStorage::new();
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(1);
+
+        let next_block = |block: &mut BlockInfo| {
+            block.height += 1;
+            block.time = block.time.plus_seconds(1);
+        };
+
+        BufferManager::init(&mut store, OBSERVATIONS, 10).unwrap();
+
+        for _ in 0..=50 {
+            accumulate_swap_sizes(&mut store, &env, OBSERVATIONS).unwrap();
+            PrecommitObservation::save(&mut store, &env, 1000u128.into(), 500u128.into()).unwrap();
+            next_block(&mut env.block);
+        }
+
+        let buffer = BufferManager::new(&store, OBSERVATIONS).unwrap();
+
+        let obs = buffer.read_last(&store).unwrap().unwrap();
+        assert_eq!(obs.ts, 50);
+        assert_eq!(buffer.head(), 0);
+        assert_eq!(obs.price_sma, Decimal::from_ratio(2u8, 1u8));
+        assert_eq!(obs.price, Decimal::from_ratio(2u8, 1u8));
+    }
 }
@@ -1,6 +1,7 @@
 use oroswap::{asset::MINIMUM_LIQUIDITY_AMOUNT, pair::MAX_FEE_SHARE_BPS};
 use cosmwasm_std::{OverflowError, StdError, Uint128};
 use cw_utils::{ParseReplyError, PaymentError};
+use oroswap_circular_buffer::error::BufferError;
 use thiserror::Error;
 
 /// This enum describes pair contract errors
@@ -15,6 +16,9 @@ pub enum ContractError {
     #[error("{0}")]
     ParseReplyError(#[from] ParseReplyError),
 
+    #[error("{0}")]
+    CircularBuffer(#[from] BufferError),
+
     #[error("Unauthorized")]
     Unauthorized {},
 
@@ -76,8 +80,14 @@ pub enum ContractError {
     )]
     FeeShareOutOfBounds {},
 
-    #[error("Maker fee address is not set in factory")]
-    MakerFeeAddressNotSet {},
+    #[error("Not enough LP tokens to withdraw the requested assets: required {required}, provided {provided}")]
+    InsufficientLpTokens {
+        required: Uint128,
+        provided: Uint128,
+    },
+
+    #[error("Requested assets exceed the pool reserves")]
+    ExceedsPoolReserves {},
 
     #[error("Pair is paused")]
     PairPaused {},
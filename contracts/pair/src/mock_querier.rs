@@ -81,15 +81,17 @@ impl WasmMockQuerier {
             QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
                 if contract_addr == "factory" {
                     match from_json(&msg).unwrap() {
-                        QueryMsg::FeeInfo { .. } => SystemResult::Ok(
-                            to_json_binary(&FeeInfoResponse {
-                                fee_address: Some(Addr::unchecked("fee_address")),
-                                total_fee_bps: 30,
-                                maker_fee_bps: 1660,
-                                pool_creation_fee: Uint128::new(1000),
-                            })
-                            .into(),
-                        ),
+                        QueryMsg::FeeInfo { .. } | QueryMsg::PairFeeInfo { .. } => {
+                            SystemResult::Ok(
+                                to_json_binary(&FeeInfoResponse {
+                                    fee_address: Some(Addr::unchecked("fee_address")),
+                                    total_fee_bps: 30,
+                                    maker_fee_bps: 1660,
+                                    pool_creation_fee: Uint128::new(1000),
+                                })
+                                .into(),
+                            )
+                        }
                         QueryMsg::Config {} => SystemResult::Ok(
                             to_json_binary(&Config {
                                 owner: Addr::unchecked("owner"),
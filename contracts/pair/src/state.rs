@@ -1,7 +1,10 @@
 use oroswap::{
     asset::{AssetInfo, PairInfo},
+    observation::Observation,
     pair::FeeShareConfig,
+    pair_xyk_sale_tax::TaxConfigsChecked,
 };
+use oroswap_circular_buffer::CircularBuffer;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Uint128};
 use cw_storage_plus::{Item, SnapshotMap};
@@ -27,6 +30,18 @@ pub struct Config {
     pub tracker_addr: Option<Addr>,
     /// Whether the pair is paused
     pub paused: bool,
+    /// Sale tax settings. Only set for pairs created with the sale tax pair type
+    #[serde(default)]
+    pub sale_tax: Option<SaleTaxConfig>,
+}
+
+/// This structure stores the sale tax settings of a pair.
+#[cw_serde]
+pub struct SaleTaxConfig {
+    /// The tax configs keyed by the denom of the asset whose sales are taxed
+    pub tax_configs: TaxConfigsChecked,
+    /// The address that is allowed to update the tax configs
+    pub tax_config_admin: Addr,
 }
 
 /// Stores the config struct at the given key
@@ -39,3 +54,7 @@ pub const BALANCES: SnapshotMap<&AssetInfo, Uint128> = SnapshotMap::new(
     "balances_change",
     cw_storage_plus::Strategy::EveryBlock,
 );
+
+/// Circular buffer to store trade size observations
+pub const OBSERVATIONS: CircularBuffer<Observation> =
+    CircularBuffer::new("observations_state", "observations_buffer");
@@ -1,8 +1,8 @@
 #![cfg(not(tarpaulin_include))]
 
 use cosmwasm_std::{
-    attr, coin, to_json_binary, Addr, Coin, Decimal, DepsMut, Empty, Env, MessageInfo, Response,
-    StdResult, Uint128, Uint64,
+    attr, coin, from_json, to_json_binary, Addr, Coin, Decimal, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Uint128, Uint64,
 };
 use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
 
@@ -12,10 +12,16 @@ use oroswap::factory::{
     ExecuteMsg as FactoryExecuteMsg, InstantiateMsg as FactoryInstantiateMsg, PairConfig, PairType,
     QueryMsg as FactoryQueryMsg, TrackerConfig,
 };
+use oroswap::observation::OracleObservation;
 use oroswap::pair::{
     ConfigResponse, CumulativePricesResponse, Cw20HookMsg, ExecuteMsg, FeeShareConfig,
-    InstantiateMsg, PoolResponse, QueryMsg, XYKPoolConfig, XYKPoolParams, XYKPoolUpdateParams,
-    MAX_FEE_SHARE_BPS, TWAP_PRECISION,
+    InstantiateMsg, PoolResponse, QueryMsg, ReverseSimulationResponse,
+    SimulateImbalancedWithdrawResponse, SimulationResponse, XYKPoolConfig, XYKPoolParams,
+    XYKPoolUpdateParams, MAX_FEE_SHARE_BPS, TWAP_PRECISION,
+};
+use oroswap::pair_xyk_sale_tax::{
+    SaleTaxConfigUpdates, SaleTaxInitParams, SaleTaxPoolConfig, TaxConfigUnchecked,
+    SALE_TAX_PAIR_TYPE,
 };
 use oroswap::token::InstantiateMsg as TokenInstantiateMsg;
 use oroswap::tokenfactory_tracker::{
@@ -109,6 +115,7 @@ fn instantiate_pair(mut router: &mut TestApp, owner: &Addr) -> Addr {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: token_contract_code_id,
         generator_address: Some(String::from("generator")),
@@ -640,6 +647,7 @@ fn test_compatibility_of_tokens_with_different_precision() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: token_contract_code_id,
         generator_address: Some(String::from("generator")),
@@ -679,6 +687,7 @@ fn test_compatibility_of_tokens_with_different_precision() {
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     app.execute_contract(
@@ -1064,6 +1073,7 @@ fn asset_balances_tracking_works_correctly() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: token_code_id,
         generator_address: Some(String::from("generator")),
@@ -1104,6 +1114,7 @@ fn asset_balances_tracking_works_correctly() {
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     app.execute_contract(
@@ -1314,6 +1325,7 @@ fn update_pair_config() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: token_contract_code_id,
         generator_address: Some(String::from("generator")),
@@ -1426,6 +1438,7 @@ fn enable_disable_fee_sharing() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: token_contract_code_id,
         generator_address: Some(String::from("generator")),
@@ -1670,6 +1683,7 @@ fn provide_liquidity_with_autostaking_to_generator() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: token_contract_code_id,
         generator_address: None,
@@ -1737,6 +1751,7 @@ fn provide_liquidity_with_autostaking_to_generator() {
         ],
         pair_type: PairType::Xyk {},
         init_params: None,
+        fee_tier: None,
     };
 
     router.execute_contract(
@@ -1827,7 +1842,7 @@ fn provide_liquidity_with_autostaking_to_generator() {
 }
 
 #[test]
-fn test_imbalanced_withdraw_is_disabled() {
+fn test_imbalanced_withdraw() {
     let owner = Addr::unchecked("owner");
     let alice_address = Addr::unchecked("alice");
     let mut router = mock_app(
@@ -1911,29 +1926,294 @@ fn test_imbalanced_withdraw_is_disabled() {
         .execute_contract(alice_address.clone(), pair_instance.clone(), &msg, &coins)
         .unwrap();
 
-    // Check that imbalanced withdraw is currently disabled
-    let msg_imbalance = ExecuteMsg::WithdrawLiquidity {
-        assets: vec![Asset {
-            info: AssetInfo::NativeToken {
-                denom: "uusd".to_string(),
+    // Simulate a single-sided withdraw
+    let uusd_withdraw = Asset {
+        info: AssetInfo::NativeToken {
+            denom: "uusd".to_string(),
+        },
+        amount: Uint128::from(100u8),
+    };
+    let simulation: SimulateImbalancedWithdrawResponse = router
+        .wrap()
+        .query_wasm_smart(
+            pair_instance.clone(),
+            &QueryMsg::SimulateImbalancedWithdraw {
+                lp_amount: Uint128::new(100),
+                assets: vec![uusd_withdraw.clone()],
             },
-            amount: Uint128::from(100u8),
-        }],
+        )
+        .unwrap();
+    assert_eq!(
+        simulation,
+        SimulateImbalancedWithdrawResponse {
+            assets: vec![
+                uusd_withdraw.clone(),
+                Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "uluna".to_string(),
+                    },
+                    amount: Uint128::zero(),
+                },
+            ],
+            lp_amount: Uint128::new(51),
+        }
+    );
+
+    // Not enough LP tokens to withdraw the requested assets
+    let msg_imbalance = ExecuteMsg::WithdrawLiquidity {
+        assets: vec![uusd_withdraw.clone()],
         min_assets_to_receive: None,
     };
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            pair_instance.clone(),
+            &msg_imbalance,
+            &[coin(50u128, lp_token.clone())],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InsufficientLpTokens {
+            required: Uint128::new(51),
+            provided: Uint128::new(50),
+        }
+    );
+
+    // Asset which doesn't belong to the pool
+    let err = router
+        .execute_contract(
+            alice_address.clone(),
+            pair_instance.clone(),
+            &ExecuteMsg::WithdrawLiquidity {
+                assets: vec![Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "cny".to_string(),
+                    },
+                    amount: Uint128::from(100u8),
+                }],
+                min_assets_to_receive: None,
+            },
+            &[coin(100u128, lp_token.clone())],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AssetMismatch {}
+    );
 
+    // Minimum amounts are respected
     let err = router
+        .execute_contract(
+            alice_address.clone(),
+            pair_instance.clone(),
+            &ExecuteMsg::WithdrawLiquidity {
+                assets: vec![uusd_withdraw.clone()],
+                min_assets_to_receive: Some(vec![
+                    uusd_withdraw.clone(),
+                    Asset {
+                        info: AssetInfo::NativeToken {
+                            denom: "uluna".to_string(),
+                        },
+                        amount: Uint128::from(1u8),
+                    },
+                ]),
+            },
+            &[coin(100u128, lp_token.clone())],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::WithdrawSlippageViolation {
+            asset_name: "uluna".to_string(),
+            received: Uint128::zero(),
+            expected: Uint128::one(),
+        }
+    );
+
+    let uusd_before = router
+        .wrap()
+        .query_balance(alice_address.clone(), "uusd")
+        .unwrap()
+        .amount;
+    let lp_before = router
+        .wrap()
+        .query_balance(alice_address.clone(), lp_token.clone())
+        .unwrap()
+        .amount;
+
+    router
         .execute_contract(
             alice_address.clone(),
             pair_instance.clone(),
             &msg_imbalance,
-            &[coin(100u128, lp_token)],
+            &[coin(100u128, lp_token.clone())],
+        )
+        .unwrap();
+
+    // Alice received exactly the requested assets and unused LP tokens were refunded
+    let uusd_after = router
+        .wrap()
+        .query_balance(alice_address.clone(), "uusd")
+        .unwrap()
+        .amount;
+    let lp_after = router
+        .wrap()
+        .query_balance(alice_address.clone(), lp_token.clone())
+        .unwrap()
+        .amount;
+    assert_eq!(uusd_after - uusd_before, Uint128::new(100));
+    assert_eq!(lp_before - lp_after, Uint128::new(51));
+
+    let pool: PoolResponse = router
+        .wrap()
+        .query_wasm_smart(pair_instance.clone(), &QueryMsg::Pool {})
+        .unwrap();
+    assert_eq!(pool.assets[0].amount, Uint128::new(100_000_000));
+    assert_eq!(pool.assets[1].amount, Uint128::new(100_000_100));
+    assert_eq!(pool.total_share, Uint128::new(100_000_049));
+}
+
+#[test]
+fn test_observations() {
+    let owner = Addr::unchecked("owner");
+    let alice_address = Addr::unchecked("alice");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uzig".to_string(),
+                amount: Uint128::new(1000), // This matches the pool_creation_fee
+            },
+        ],
+    );
+
+    // Set Alice's balances
+    router
+        .send_tokens(
+            owner.clone(),
+            alice_address.clone(),
+            &[
+                Coin {
+                    denom: "uusd".to_string(),
+                    amount: Uint128::new(233_000_000u128),
+                },
+                Coin {
+                    denom: "uluna".to_string(),
+                    amount: Uint128::new(2_00_000_000u128),
+                },
+            ],
+        )
+        .unwrap();
+
+    // Init pair
+    let pair_instance = instantiate_pair(&mut router, &owner);
+
+    // Provide liquidity
+    let (msg, coins) = provide_liquidity_msg(
+        Uint128::new(100_000_000),
+        Uint128::new(100_000_000),
+        None,
+        None,
+        None,
+    );
+    router
+        .execute_contract(alice_address.clone(), pair_instance.clone(), &msg, &coins)
+        .unwrap();
+
+    router.update_block(|b| {
+        b.height += 1;
+        b.time = b.time.plus_seconds(5);
+    });
+
+    let err = router
+        .wrap()
+        .query_wasm_smart::<OracleObservation>(
+            &pair_instance,
+            &QueryMsg::Observe { seconds_ago: 0 },
         )
         .unwrap_err();
     assert_eq!(
-        err.root_cause().to_string(),
-        "Generic error: Imbalanced withdraw is currently disabled"
+        err,
+        StdError::generic_err("Querier contract error: Generic error: Buffer is empty")
+    );
+
+    let swap_msg = |amount: u128| {
+        (
+            ExecuteMsg::Swap {
+                offer_asset: Asset {
+                    info: AssetInfo::NativeToken {
+                        denom: "uusd".to_owned(),
+                    },
+                    amount: Uint128::new(amount),
+                },
+                ask_asset_info: None,
+                belief_price: None,
+                max_spread: None,
+                to: None,
+            },
+            [coin(amount, "uusd")],
+        )
+    };
+
+    // 100_000 uusd -> 99_900 uluna
+    let (msg, coins) = swap_msg(100_000);
+    router
+        .execute_contract(alice_address.clone(), pair_instance.clone(), &msg, &coins)
+        .unwrap();
+
+    router.update_block(|b| {
+        b.height += 1;
+        b.time = b.time.plus_seconds(5);
+    });
+
+    let expected_price = Decimal::from_ratio(100_000u128, 99_900u128);
+    let observation: OracleObservation = router
+        .wrap()
+        .query_wasm_smart(&pair_instance, &QueryMsg::Observe { seconds_ago: 0 })
+        .unwrap();
+    assert_eq!(
+        observation,
+        OracleObservation {
+            timestamp: router.block_info().time.seconds(),
+            price: expected_price,
+        }
     );
+
+    // Small trades are not observed but they commit the previous observation
+    let (msg, coins) = swap_msg(100);
+    router
+        .execute_contract(alice_address.clone(), pair_instance.clone(), &msg, &coins)
+        .unwrap();
+
+    router.update_block(|b| {
+        b.height += 1;
+        b.time = b.time.plus_seconds(5);
+    });
+
+    let observation: OracleObservation = router
+        .wrap()
+        .query_wasm_smart(&pair_instance, &QueryMsg::Observe { seconds_ago: 0 })
+        .unwrap();
+    assert_eq!(observation.price, expected_price);
+
+    // Observations older than the first one are not available
+    let err = router
+        .wrap()
+        .query_wasm_smart::<OracleObservation>(
+            &pair_instance,
+            &QueryMsg::Observe { seconds_ago: 15 },
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("Requested observation is too old"));
 }
 
 #[test]
@@ -2079,6 +2359,7 @@ fn test_fee_share(
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: token_contract_code_id,
         generator_address: Some(String::from("generator")),
@@ -2118,6 +2399,7 @@ fn test_fee_share(
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     app.execute_contract(
@@ -2429,6 +2711,7 @@ fn test_tracker_contract() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -2469,6 +2752,7 @@ fn test_tracker_contract() {
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     app.execute_contract(
@@ -2600,6 +2884,7 @@ fn test_create_xyk_custom_type() {
                 is_generator_disabled: false,
                 permissioned: false,
                 pool_creation_fee: Uint128::new(1000),
+                pool_creation_fee_denom: "uzig".to_string(),
             },
             PairConfig {
                 code_id: pair_code_id,
@@ -2610,6 +2895,7 @@ fn test_create_xyk_custom_type() {
                 is_generator_disabled: false,
                 permissioned: false,
                 pool_creation_fee: Uint128::new(1000),
+                pool_creation_fee_denom: "uzig".to_string(),
             },
         ],
         token_code_id,
@@ -2646,6 +2932,7 @@ fn test_create_xyk_custom_type() {
         ],
         pair_type: PairType::Custom("custom_xyk".to_string()),
         init_params: None,
+        fee_tier: None,
     };
 
     app.execute_contract(
@@ -2690,6 +2977,7 @@ fn test_create_xyk_custom_type() {
         ],
         pair_type: PairType::Xyk {},
         init_params: None,
+        fee_tier: None,
     };
 
     app.execute_contract(
@@ -2722,3 +3010,279 @@ fn test_create_xyk_custom_type() {
 
     assert_eq!(res.pair_type, PairType::Xyk {});
 }
+
+#[test]
+fn test_sale_tax() {
+    let owner = Addr::unchecked(OWNER);
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            Coin {
+                denom: "uusd".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uluna".to_string(),
+                amount: Uint128::new(100_000_000_000u128),
+            },
+            Coin {
+                denom: "uzig".to_string(),
+                amount: Uint128::new(2000),
+            },
+        ],
+    );
+
+    let token_contract_code_id = store_token_code(&mut router);
+    let pair_contract_code_id = store_pair_code(&mut router);
+    let factory_code_id = store_factory_code(&mut router);
+    let pair_type = PairType::Custom(SALE_TAX_PAIR_TYPE.to_string());
+
+    let init_msg = FactoryInstantiateMsg {
+        fee_address: Some("fee_address".to_string()),
+        pair_configs: vec![PairConfig {
+            code_id: pair_contract_code_id,
+            maker_fee_bps: 0,
+            pair_type: pair_type.clone(),
+            total_fee_bps: 0,
+            is_disabled: false,
+            is_generator_disabled: false,
+            permissioned: false,
+            pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
+        }],
+        token_code_id: token_contract_code_id,
+        generator_address: Some(String::from("generator")),
+        owner: owner.to_string(),
+        whitelist_code_id: 234u64,
+        coin_registry_address: "coin_registry".to_string(),
+        tracker_config: None,
+    };
+
+    let factory_instance = router
+        .instantiate_contract(
+            factory_code_id,
+            owner.clone(),
+            &init_msg,
+            &[],
+            "FACTORY",
+            None,
+        )
+        .unwrap();
+
+    let mut msg = InstantiateMsg {
+        pair_type: pair_type.clone(),
+        asset_infos: vec![
+            native_asset_info("uusd".to_string()),
+            native_asset_info("uluna".to_string()),
+        ],
+        token_code_id: token_contract_code_id,
+        factory_addr: factory_instance.to_string(),
+        init_params: None,
+    };
+
+    // Sale tax pairs cannot be created without tax configs
+    let err = router
+        .instantiate_contract(
+            pair_contract_code_id,
+            owner.clone(),
+            &msg,
+            &[coin(1000, "uzig")],
+            String::from("PAIR"),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Generic error: Missing Init params"
+    );
+
+    msg.init_params = Some(
+        to_json_binary(&SaleTaxInitParams {
+            tax_configs: vec![(
+                "uusd",
+                TaxConfigUnchecked {
+                    tax_rate: Decimal::percent(10),
+                    tax_recipient: "tax_recipient".to_string(),
+                },
+            )]
+            .into(),
+            tax_config_admin: "tax_admin".to_string(),
+            track_asset_balances: false,
+        })
+        .unwrap(),
+    );
+
+    let pair = router
+        .instantiate_contract(
+            pair_contract_code_id,
+            owner.clone(),
+            &msg,
+            &[coin(1000, "uzig")],
+            String::from("PAIR"),
+            None,
+        )
+        .unwrap();
+
+    let (msg, coins) = provide_liquidity_msg(
+        Uint128::new(1_000_000_000),
+        Uint128::new(1_000_000_000),
+        None,
+        None,
+        None,
+    );
+    router
+        .execute_contract(owner.clone(), pair.clone(), &msg, &coins)
+        .unwrap();
+
+    // 10% of the offered uusd is taken as tax before the swap
+    let res: SimulationResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &pair,
+            &QueryMsg::Simulation {
+                offer_asset: Asset {
+                    info: native_asset_info("uusd".to_string()),
+                    amount: Uint128::new(1_000_000),
+                },
+                ask_asset_info: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        SimulationResponse {
+            return_amount: Uint128::new(899190),
+            spread_amount: Uint128::new(810),
+            commission_amount: Uint128::zero(),
+            tax_amount: Uint128::new(100_000),
+        }
+    );
+
+    // Selling uluna is not taxed
+    let res: SimulationResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &pair,
+            &QueryMsg::Simulation {
+                offer_asset: Asset {
+                    info: native_asset_info("uluna".to_string()),
+                    amount: Uint128::new(1_000_000),
+                },
+                ask_asset_info: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(res.tax_amount, Uint128::zero());
+
+    let res: ReverseSimulationResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &pair,
+            &QueryMsg::ReverseSimulation {
+                offer_asset_info: None,
+                ask_asset: Asset {
+                    info: native_asset_info("uluna".to_string()),
+                    amount: Uint128::new(899_190),
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(res.offer_amount, Uint128::new(999_999));
+    assert_eq!(res.tax_amount, Uint128::new(99_999));
+
+    let swap_msg = ExecuteMsg::Swap {
+        offer_asset: Asset {
+            info: native_asset_info("uusd".to_string()),
+            amount: Uint128::new(1_000_000),
+        },
+        ask_asset_info: None,
+        belief_price: None,
+        max_spread: Some(Decimal::percent(50)),
+        to: None,
+    };
+    let res = router
+        .execute_contract(
+            owner.clone(),
+            pair.clone(),
+            &swap_msg,
+            &[coin(1_000_000, "uusd")],
+        )
+        .unwrap();
+    assert!(res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .any(|attribute| attribute == attr("tax_amount", "100000")));
+
+    let balance = router
+        .wrap()
+        .query_balance("tax_recipient", "uusd")
+        .unwrap();
+    assert_eq!(balance.amount, Uint128::new(100_000));
+
+    // Only the tax config admin or the factory owner can update tax configs
+    let update_msg = ExecuteMsg::UpdateConfig {
+        params: to_json_binary(&SaleTaxConfigUpdates {
+            tax_configs: Some(
+                vec![(
+                    "uluna",
+                    TaxConfigUnchecked {
+                        tax_rate: Decimal::percent(5),
+                        tax_recipient: "tax_recipient".to_string(),
+                    },
+                )]
+                .into(),
+            ),
+            tax_config_admin: None,
+        })
+        .unwrap(),
+    };
+    let err = router
+        .execute_contract(Addr::unchecked("random"), pair.clone(), &update_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    router
+        .execute_contract(Addr::unchecked("tax_admin"), pair.clone(), &update_msg, &[])
+        .unwrap();
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(&pair, &QueryMsg::Config {})
+        .unwrap();
+    let params: SaleTaxPoolConfig = from_json(res.params.unwrap()).unwrap();
+    assert_eq!(params.tax_config_admin, Addr::unchecked("tax_admin"));
+    assert!(params.tax_configs.get("uusd").is_none());
+    assert_eq!(
+        params.tax_configs.get("uluna").unwrap().tax_rate,
+        Decimal::percent(5)
+    );
+
+    // Tax rates above 50% are rejected
+    let update_msg = ExecuteMsg::UpdateConfig {
+        params: to_json_binary(&SaleTaxConfigUpdates {
+            tax_configs: Some(
+                vec![(
+                    "uluna",
+                    TaxConfigUnchecked {
+                        tax_rate: Decimal::percent(60),
+                        tax_recipient: "tax_recipient".to_string(),
+                    },
+                )]
+                .into(),
+            ),
+            tax_config_admin: None,
+        })
+        .unwrap(),
+    };
+    let err = router
+        .execute_contract(owner.clone(), pair.clone(), &update_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Generic error: Tax rate cannot be more than 50%"
+    );
+}
@@ -4,8 +4,8 @@ use std::vec;
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
     attr, coin, ensure, ensure_eq, from_json, to_json_binary, wasm_execute, Addr, Binary, Coin,
-    CosmosMsg, Decimal, Decimal256, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdError,
-    StdResult, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg, BankMsg,
+    CosmosMsg, Decimal, Decimal256, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response,
+    StdError, StdResult, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version};
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
@@ -29,7 +29,7 @@ use oroswap::pair_concentrated::{
     ConcentratedPoolParams, ConcentratedPoolUpdateParams, UpdatePoolParams,
 };
 use oroswap::querier::{
-    query_factory_config, query_fee_info, query_native_supply, query_tracker_config,
+    query_factory_config, query_native_supply, query_pair_fee_info, query_tracker_config,
 };
 use oroswap::token_factory::{
     tf_before_send_hook_msg, tf_burn_msg, tf_create_denom_msg, MsgCreateDenomResponse,
@@ -85,13 +85,6 @@ pub fn instantiate(
 
     let factory_addr = deps.api.addr_validate(&msg.factory_addr)?;
 
-    // Get factory config to check maker fee address
-    let factory_config = query_factory_config(&deps.querier, &msg.factory_addr)?;
-    let fee_address = factory_config.fee_address.ok_or(ContractError::MakerFeeAddressNotSet {})?;
-
-    // Get pair config to check pool creation fee
-    let pair_config = query_fee_info(&deps.querier, &msg.factory_addr, msg.pair_type.clone())?;
-
     Precisions::store_precisions(deps.branch(), &msg.asset_infos, &factory_addr)?;
 
     // Initializing cumulative prices
@@ -162,30 +155,23 @@ pub fn instantiate(
 
     BufferManager::init(deps.storage, OBSERVATIONS, OBSERVATIONS_SIZE)?;
 
-    // Create messages to:
-    // 1. Create LP token
-    // 2. Forward pool creation fee to maker fee address
-    let mut messages = vec![
-        SubMsg::reply_on_success(
-            tf_create_denom_msg(env.contract.address.to_string(), LP_SUBDENOM),
-            ReplyIds::CreateDenom as u64,
-        ),
-    ];
-
-    // Forward pool creation fee to maker fee address
-    messages.push(SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
-        to_address: fee_address.to_string(),
-        amount: vec![coin(pair_config.pool_creation_fee.u128(), "uzig")],
-    })));
+    // Create LP token
+    let sub_msg = SubMsg::reply_on_success(
+        tf_create_denom_msg(env.contract.address.to_string(), LP_SUBDENOM),
+        ReplyIds::CreateDenom as u64,
+    );
 
-    Ok(Response::new()
-        .add_submessages(messages)
-        .add_attributes(vec![
-            attr("action", "instantiate"),
-            attr("asset_balances_tracking", if config.track_asset_balances { "enabled" } else { "disabled" }),
-            attr("maker_fee_address", fee_address.to_string()),
-            attr("pool_creation_fee", pair_config.pool_creation_fee.to_string()),
-        ]))
+    Ok(Response::new().add_submessage(sub_msg).add_attributes(vec![
+        attr("action", "instantiate"),
+        attr(
+            "asset_balances_tracking",
+            if config.track_asset_balances {
+                "enabled"
+            } else {
+                "disabled"
+            },
+        ),
+    ]))
 }
 
 /// The entry point to the contract for processing replies from submessages.
@@ -724,9 +710,10 @@ fn swap(
     let old_real_price = calc_last_prices(&xs, &config, &env)?;
 
     // Get fee info from the factory
-    let fee_info = query_fee_info(
+    let fee_info = query_pair_fee_info(
         &deps.querier,
         &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
         config.pair_info.pair_type.clone(),
     )?;
     let mut maker_fee_share = Decimal256::zero();
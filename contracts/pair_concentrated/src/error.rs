@@ -73,9 +73,6 @@ pub enum ContractError {
     #[error("Operation is not supported")]
     NotSupported {},
 
-    #[error("Maker fee address not set")]
-    MakerFeeAddressNotSet {},
-
     #[error("Pair is paused")]
     PairPaused {},
 
@@ -13,7 +13,7 @@ use oroswap::pair::{
     SimulationResponse,
 };
 use oroswap::pair_concentrated::{ConcentratedPoolConfig, QueryMsg};
-use oroswap::querier::{query_factory_config, query_fee_info, query_native_supply};
+use oroswap::querier::{query_factory_config, query_native_supply, query_pair_fee_info};
 use oroswap_pcl_common::state::Precisions;
 use oroswap_pcl_common::utils::{
     accumulate_prices, before_swap_check, calc_last_prices, compute_offer_amount, compute_swap,
@@ -166,9 +166,10 @@ pub fn query_simulation(
     let xs = pools.iter().map(|asset| asset.amount).collect_vec();
 
     // Get fee info from the factory
-    let fee_info = query_fee_info(
+    let fee_info = query_pair_fee_info(
         &deps.querier,
         &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
         config.pair_info.pair_type.clone(),
     )?;
     let mut maker_fee_share = Decimal256::zero();
@@ -195,6 +196,7 @@ pub fn query_simulation(
         return_amount: swap_result.dy.to_uint(ask_asset_prec)?,
         spread_amount: swap_result.spread_fee.to_uint(ask_asset_prec)?,
         commission_amount: swap_result.total_fee.to_uint(ask_asset_prec)?,
+        tax_amount: Uint128::zero(),
     })
 }
 
@@ -226,6 +228,7 @@ pub fn query_reverse_simulation(
         offer_amount: offer_amount.to_uint(offer_asset_prec)?,
         spread_amount: spread_amount.to_uint(offer_asset_prec)?,
         commission_amount: commission_amount.to_uint(offer_asset_prec)?,
+        tax_amount: Uint128::zero(),
     })
 }
 
@@ -2919,6 +2919,7 @@ fn test_instantiate_missing_fee_address() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id,
         generator_address: None,
@@ -5,9 +5,9 @@ use std::vec;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, coin, ensure_eq, from_json, to_json_binary, Addr, Binary, Coin, CosmosMsg, Decimal,
-    Decimal256, Deps, DepsMut, Empty, Env, Fraction, MessageInfo, QuerierWrapper, Reply, Response,
-    StdError, StdResult, SubMsg, SubMsgResponse, SubMsgResult, Uint128, WasmMsg, BankMsg,
+    attr, coin, ensure_eq, from_json, to_json_binary, Addr, BankMsg, Binary, Coin, CosmosMsg,
+    Decimal, Decimal256, Deps, DepsMut, Empty, Env, Fraction, MessageInfo, QuerierWrapper, Reply,
+    Response, StdError, StdResult, SubMsg, SubMsgResponse, SubMsgResult, Uint128, Uint256, WasmMsg,
 };
 use cw2::set_contract_version;
 use cw20::{Cw20ExecuteMsg, Cw20ReceiveMsg};
@@ -19,17 +19,19 @@ use oroswap::asset::{
     MINIMUM_LIQUIDITY_AMOUNT,
 };
 use oroswap::common::{claim_ownership, drop_ownership_proposal, propose_new_owner, LP_SUBDENOM};
-use oroswap::observation::{query_observation, PrecommitObservation, OBSERVATIONS_SIZE};
+use oroswap::observation::{
+    accumulate_swap_sizes, query_observation, PrecommitObservation, OBSERVATIONS_SIZE,
+};
 use oroswap::pair::{
     ConfigResponse, CumulativePricesResponse, FeeShareConfig, InstantiateMsg, StablePoolParams,
     StablePoolUpdateParams, DEFAULT_SLIPPAGE, MAX_ALLOWED_SLIPPAGE, MAX_FEE_SHARE_BPS,
     MIN_TRADE_SIZE,
 };
 use oroswap::pair::{
-    Cw20HookMsg, ExecuteMsg, PoolResponse, QueryMsg, ReverseSimulationResponse, SimulationResponse,
-    StablePoolConfig,
+    Cw20HookMsg, ExecuteMsg, PoolResponse, QueryMsg, ReverseSimulationResponse,
+    SimulateImbalancedWithdrawResponse, SimulationResponse, StablePoolConfig,
 };
-use oroswap::querier::{query_factory_config, query_fee_info, query_native_supply};
+use oroswap::querier::{query_factory_config, query_native_supply, query_pair_fee_info};
 use oroswap::token_factory::{tf_burn_msg, tf_create_denom_msg, MsgCreateDenomResponse};
 use oroswap::DecimalCheckedOps;
 use oroswap_circular_buffer::BufferManager;
@@ -44,10 +46,10 @@ use crate::state::{
     get_precision, store_precisions, Config, CONFIG, OBSERVATIONS, OWNERSHIP_PROPOSAL,
 };
 use crate::utils::{
-    accumulate_prices, accumulate_swap_sizes, adjust_precision, calculate_shares,
-    check_asset_infos, check_cw20_in_pool, compute_current_amp, compute_swap,
-    determine_base_quote_amount, get_assets_collection, get_share_in_assets,
-    mint_liquidity_token_message, select_pools, SwapResult,
+    accumulate_prices, adjust_precision, calculate_shares, check_asset_infos, check_assets,
+    check_cw20_in_pool, compute_current_amp, compute_swap, determine_base_quote_amount,
+    get_assets_collection, get_share_in_assets, mint_liquidity_token_message, pool_index,
+    select_pools, SwapResult,
 };
 
 /// Contract name that is used for migration.
@@ -56,8 +58,10 @@ const CONTRACT_NAME: &str = "oroswap-pair-stable";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Reply ID for create denom reply
 const CREATE_DENOM_REPLY_ID: u64 = 1;
-/// Number of assets in the pool.
-const N_COINS: usize = 2;
+/// Minimum number of assets in the pool.
+const MIN_N_COINS: usize = 2;
+/// Maximum number of assets in the pool.
+const MAX_N_COINS: usize = 5;
 
 /// Creates a new contract with the specified parameters in [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -69,8 +73,11 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     check_asset_infos(deps.api, &msg.asset_infos)?;
 
-    if msg.asset_infos.len() != N_COINS {
-        return Err(ContractError::InvalidNumberOfAssets(N_COINS));
+    if !(MIN_N_COINS..=MAX_N_COINS).contains(&msg.asset_infos.len()) {
+        return Err(ContractError::InvalidPoolSize {
+            min: MIN_N_COINS,
+            max: MAX_N_COINS,
+        });
     }
 
     if msg.init_params.is_none() {
@@ -86,14 +93,6 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     let factory_addr = deps.api.addr_validate(&msg.factory_addr)?;
-    
-    // Get factory config to check maker fee address
-    let factory_config = query_factory_config(&deps.querier, &msg.factory_addr)?;
-    let fee_address = factory_config.fee_address.ok_or(ContractError::MakerFeeAddressNotSet {})?;
-
-    // Get pair config to check pool creation fee
-    let pair_config = query_fee_info(&deps.querier, &msg.factory_addr, msg.pair_type.clone())?;
-    
     let greatest_precision = store_precisions(deps.branch(), &msg.asset_infos, &factory_addr)?;
 
     // Initializing cumulative prices
@@ -130,29 +129,15 @@ pub fn instantiate(
     CONFIG.save(deps.storage, &config)?;
     BufferManager::init(deps.storage, OBSERVATIONS, OBSERVATIONS_SIZE)?;
 
-    // Create messages to:
-    // 1. Create LP token
-    // 2. Forward pool creation fee to maker fee address
-    let mut messages = vec![
-        SubMsg::reply_on_success(
-            tf_create_denom_msg(env.contract.address.to_string(), LP_SUBDENOM),
-            CREATE_DENOM_REPLY_ID,
-        ),
-    ];
-
-    // Forward pool creation fee to maker fee address
-    messages.push(SubMsg::new(CosmosMsg::Bank(BankMsg::Send {
-        to_address: fee_address.to_string(),
-        amount: vec![coin(pair_config.pool_creation_fee.u128(), "uzig")],
-    })));
+    // Create LP token
+    let sub_msg = SubMsg::reply_on_success(
+        tf_create_denom_msg(env.contract.address.to_string(), LP_SUBDENOM),
+        CREATE_DENOM_REPLY_ID,
+    );
 
     Ok(Response::new()
-        .add_submessages(messages)
-        .add_attributes(vec![
-            attr("action", "instantiate"),
-            attr("maker_fee_address", fee_address.to_string()),
-            attr("pool_creation_fee", pair_config.pool_creation_fee.to_string()),
-        ]))
+        .add_submessage(sub_msg)
+        .add_attribute("action", "instantiate"))
 }
 
 /// The entry point to the contract for processing replies from submessages.
@@ -511,25 +496,48 @@ pub fn withdraw_liquidity(
 
     let (pools, total_share) = pool_info(deps.querier, &config)?;
 
-    let refund_assets = if assets.is_empty() {
+    let (refund_assets, burn_amount) = if assets.is_empty() {
         // Usual withdraw (balanced)
-        get_share_in_assets(&pools, amount, total_share)
+        (get_share_in_assets(&pools, amount, total_share), amount)
     } else {
-        return Err(StdError::generic_err("Imbalanced withdraw is currently disabled").into());
+        imbalanced_withdraw(
+            deps.as_ref(),
+            &env,
+            &config,
+            &pools,
+            &assets,
+            amount,
+            total_share,
+        )?
     };
 
-    ensure_min_assets_to_receive(&config, refund_assets.clone(), min_assets_to_receive)?;
+    ensure_min_assets_to_receive(&refund_assets, min_assets_to_receive)?;
 
     let mut messages = refund_assets
-        .clone()
-        .into_iter()
-        .map(|asset| asset.into_msg(&info.sender))
+        .iter()
+        .filter(|asset| !asset.amount.is_zero())
+        .map(|asset| asset.clone().into_msg(&info.sender))
         .collect::<StdResult<Vec<_>>>()?;
     messages.push(tf_burn_msg(
         env.contract.address.to_string(),
-        coin(amount.u128(), config.pair_info.liquidity_token.to_string()),
+        coin(
+            burn_amount.u128(),
+            config.pair_info.liquidity_token.to_string(),
+        ),
     ));
 
+    // Return the LP tokens that were not needed for an imbalanced withdraw
+    let refunded_share = amount - burn_amount;
+    if !refunded_share.is_zero() {
+        messages.push(CosmosMsg::Bank(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(
+                refunded_share.u128(),
+                config.pair_info.liquidity_token.to_string(),
+            )],
+        }));
+    }
+
     let pools = pools
         .iter()
         .map(|pool| {
@@ -545,11 +553,126 @@ pub fn withdraw_liquidity(
     Ok(Response::new().add_messages(messages).add_attributes(vec![
         attr("action", "withdraw_liquidity"),
         attr("sender", info.sender),
-        attr("withdrawn_share", amount),
+        attr("withdrawn_share", burn_amount),
         attr("refund_assets", refund_assets.iter().join(", ")),
+        attr("refunded_share", refunded_share),
     ]))
 }
 
+/// Calculates an imbalanced withdraw of exactly the specified `assets` from the pool.
+/// Returns the assets to send ordered as in the pool and the amount of LP tokens to burn.
+///
+/// The pool fee is charged on the difference between the balances left after the withdraw
+/// and the balances a balanced withdraw of the same value would leave, as in Curve.
+/// Fees stay in the pool and accrue to the remaining LPs.
+///
+/// * **pools** is the array with assets in the pool.
+///
+/// * **assets** is an array with assets to withdraw. Pool assets which are not specified are not withdrawn.
+///
+/// * **lp_amount** is the amount of LP tokens provided for the withdraw.
+///
+/// * **total_share** is the total amount of LP tokens currently minted.
+fn imbalanced_withdraw(
+    deps: Deps,
+    env: &Env,
+    config: &Config,
+    pools: &[Asset],
+    assets: &[Asset],
+    lp_amount: Uint128,
+    total_share: Uint128,
+) -> Result<(Vec<Asset>, Uint128), ContractError> {
+    if assets.len() > pools.len() {
+        return Err(ContractError::WrongAssetLength {
+            expected: pools.len(),
+            actual: assets.len(),
+        });
+    }
+    check_assets(deps.api, assets)?;
+
+    let mut withdrawals = vec![Uint128::zero(); pools.len()];
+    for asset in assets {
+        let ind = pools
+            .iter()
+            .position(|pool| pool.info.eq(&asset.info))
+            .ok_or_else(|| ContractError::InvalidAsset(asset.info.to_string()))?;
+        withdrawals[ind] = asset.amount;
+    }
+
+    if withdrawals.iter().all(|amount| amount.is_zero()) {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let mut old_balances = vec![];
+    let mut new_balances = vec![];
+    for (pool, withdrawal) in pools.iter().zip(&withdrawals) {
+        let precision = get_precision(deps.storage, &pool.info)?;
+        let remaining = pool
+            .amount
+            .checked_sub(*withdrawal)
+            .map_err(|_| ContractError::ExceedsPoolReserves {})?;
+        // The pool can't be drained completely
+        if remaining.is_zero() {
+            return Err(ContractError::ExceedsPoolReserves {});
+        }
+        old_balances.push(Decimal256::with_precision(pool.amount, precision)?);
+        new_balances.push(Decimal256::with_precision(remaining, precision)?);
+    }
+
+    let fee_info = query_pair_fee_info(
+        &deps.querier,
+        &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
+        config.pair_info.pair_type.clone(),
+    )?;
+    let n_coins = pools.len() as u8;
+    let fee = Decimal256::from(fee_info.total_fee_rate)
+        .checked_mul(Decimal256::from_ratio(n_coins, 4 * (n_coins - 1)))?;
+
+    let amp = compute_current_amp(config, env)?;
+    let init_d = compute_d(amp, &old_balances)?;
+    let withdraw_d = compute_d(amp, &new_balances)?;
+
+    for (new_balance, old_balance) in new_balances.iter_mut().zip(&old_balances) {
+        let ideal_balance = withdraw_d.checked_multiply_ratio(*old_balance, init_d)?;
+        let difference = ideal_balance.abs_diff(*new_balance);
+        *new_balance = new_balance
+            .checked_sub(fee.checked_mul(difference)?)
+            .ok()
+            .filter(|balance| !balance.is_zero())
+            .ok_or(ContractError::ExceedsPoolReserves {})?;
+    }
+
+    let after_fee_d = compute_d(amp, &new_balances)?;
+
+    // Round up so that the remaining LPs never lose value
+    let burn_amount: Uint128 = Uint256::from(total_share)
+        .checked_multiply_ratio(
+            init_d.atomics().checked_sub(after_fee_d.atomics())?,
+            init_d.atomics(),
+        )?
+        .checked_add(Uint256::one())?
+        .try_into()?;
+
+    if burn_amount > lp_amount {
+        return Err(ContractError::InsufficientLpTokens {
+            required: burn_amount,
+            provided: lp_amount,
+        });
+    }
+
+    let refund_assets = pools
+        .iter()
+        .zip(withdrawals)
+        .map(|(pool, amount)| Asset {
+            info: pool.info.clone(),
+            amount,
+        })
+        .collect();
+
+    Ok((refund_assets, burn_amount))
+}
+
 /// Performs an swap operation with the specified parameters.
 ///
 /// * **sender** is the sender of the swap operation.
@@ -630,9 +753,10 @@ pub fn swap(
     )?;
 
     // Get fee info from the factory
-    let fee_info = query_fee_info(
+    let fee_info = query_pair_fee_info(
         &deps.querier,
         &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
         config.pair_info.pair_type.clone(),
     )?;
     let commission_amount = fee_info.total_fee_rate.checked_mul_uint128(return_amount)?;
@@ -702,13 +826,15 @@ pub fn swap(
     }
 
     // Store observation from precommit data
-    accumulate_swap_sizes(deps.storage, &env)?;
+    accumulate_swap_sizes(deps.storage, &env, OBSERVATIONS)?;
 
     // Store time series data in precommit observation.
     // Skipping small unsafe values which can seriously mess oracle price due to rounding errors.
+    // Observations track a single base/quote price, so they are only kept for two-asset pools.
     // This data will be reflected in observations on the next action.
     let ask_precision = get_precision(deps.storage, &ask_pool.info)?;
-    if offer_asset_dec.amount >= MIN_TRADE_SIZE
+    if pools.len() == 2
+        && offer_asset_dec.amount >= MIN_TRADE_SIZE
         && return_amount.to_decimal256(ask_precision)? >= MIN_TRADE_SIZE
     {
         // Store time series data
@@ -783,6 +909,8 @@ pub fn calculate_maker_fee(
 /// * **QueryMsg::Config {}** Returns the configuration for the pair contract using a [`ConfigResponse`] object.
 /// * **QueryMsg::SimulateWithdraw { lp_amount }** Returns the amount of assets that could be withdrawn from the pool
 /// using a specific amount of LP tokens. The result is returned in a vector that contains objects of type [`Asset`].
+/// * **QueryMsg::SimulateImbalancedWithdraw { lp_amount, assets }** Simulates an imbalanced withdraw
+/// using a [`SimulateImbalancedWithdrawResponse`] object.
 /// * **QueryMsg::SimulateProvide { msg }** Simulates the liquidity provision in the pair contract.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
@@ -809,6 +937,9 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::Config {} => to_json_binary(&query_config(deps, env)?),
         QueryMsg::SimulateWithdraw { lp_amount } => to_json_binary(&query_share(deps, lp_amount)?),
+        QueryMsg::SimulateImbalancedWithdraw { lp_amount, assets } => to_json_binary(
+            &query_simulate_imbalanced_withdraw(deps, env, lp_amount, assets)?,
+        ),
         QueryMsg::SimulateProvide { assets, .. } => to_json_binary(
             &query_simulate_provide(deps, env, assets)
                 .map_err(|e| StdError::generic_err(e.to_string()))?,
@@ -844,6 +975,30 @@ pub fn query_share(deps: Deps, amount: Uint128) -> StdResult<Vec<Asset>> {
     Ok(refund_assets)
 }
 
+/// Returns the result of an imbalanced withdraw simulation.
+///
+/// * **lp_amount** is the maximum amount of LP tokens that can be burned.
+///
+/// * **assets** is an array with assets to withdraw.
+pub fn query_simulate_imbalanced_withdraw(
+    deps: Deps,
+    env: Env,
+    lp_amount: Uint128,
+    assets: Vec<Asset>,
+) -> StdResult<SimulateImbalancedWithdrawResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let (pools, total_share) = pool_info(deps.querier, &config)?;
+
+    let (assets, burn_amount) =
+        imbalanced_withdraw(deps, &env, &config, &pools, &assets, lp_amount, total_share)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    Ok(SimulateImbalancedWithdrawResponse {
+        assets,
+        lp_amount: burn_amount,
+    })
+}
+
 /// Returns information about a swap simulation in a [`SimulationResponse`] object.
 ///
 /// * **offer_asset** is the asset to swap as well as an amount of the said asset.
@@ -882,6 +1037,7 @@ pub fn query_simulation(
             return_amount: Uint128::zero(),
             spread_amount: Uint128::zero(),
             commission_amount: Uint128::zero(),
+            tax_amount: Uint128::zero(),
         });
     }
 
@@ -900,9 +1056,10 @@ pub fn query_simulation(
     .map_err(|err| StdError::generic_err(format!("{err}")))?;
 
     // Get fee info from factory
-    let fee_info = query_fee_info(
+    let fee_info = query_pair_fee_info(
         &deps.querier,
         &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
         config.pair_info.pair_type.clone(),
     )?;
 
@@ -913,6 +1070,7 @@ pub fn query_simulation(
         return_amount,
         spread_amount,
         commission_amount,
+        tax_amount: Uint128::zero(),
     })
 }
 
@@ -959,13 +1117,15 @@ pub fn query_reverse_simulation(
             offer_amount: Uint128::zero(),
             spread_amount: Uint128::zero(),
             commission_amount: Uint128::zero(),
+            tax_amount: Uint128::zero(),
         });
     }
 
     // Get fee info from the factory
-    let fee_info = query_fee_info(
+    let fee_info = query_pair_fee_info(
         &deps.querier,
         &config.factory_addr,
+        config.pair_info.asset_infos.clone(),
         config.pair_info.pair_type.clone(),
     )?;
     let before_commission = (Decimal256::one()
@@ -974,11 +1134,17 @@ pub fn query_reverse_simulation(
     .ok_or_else(|| StdError::generic_err("The pool must have less than 100% fee!"))?
     .checked_mul(Decimal256::with_precision(ask_asset.amount, ask_precision)?)?;
 
+    let offer_ind = pool_index(&pools, &offer_pool.info)
+        .map_err(|err| StdError::generic_err(format!("{err}")))?;
+    let ask_ind = pool_index(&pools, &ask_pool.info)
+        .map_err(|err| StdError::generic_err(format!("{err}")))?;
     let xp = pools.into_iter().map(|pool| pool.amount).collect_vec();
     let new_offer_pool_amount = calc_y(
         compute_current_amp(&config, &env)?,
         ask_pool.amount - before_commission,
         &xp,
+        ask_ind,
+        offer_ind,
         config.greatest_precision,
     )?;
 
@@ -996,6 +1162,7 @@ pub fn query_reverse_simulation(
         commission_amount: fee_info
             .total_fee_rate
             .checked_mul_uint128(before_commission.to_uint128_with_precision(ask_precision)?)?,
+        tax_amount: Uint128::zero(),
     })
 }
 
@@ -1253,8 +1420,7 @@ fn query_compute_d(deps: Deps, env: Env) -> StdResult<Uint128> {
 }
 
 fn ensure_min_assets_to_receive(
-    config: &Config,
-    mut refund_assets: Vec<Asset>,
+    refund_assets: &[Asset],
     min_assets_to_receive: Option<Vec<Asset>>,
 ) -> Result<(), ContractError> {
     if let Some(min_assets_to_receive) = min_assets_to_receive {
@@ -1265,31 +1431,20 @@ fn ensure_min_assets_to_receive(
             });
         }
 
-        for asset in &min_assets_to_receive {
-            if !config.pair_info.asset_infos.contains(&asset.info) {
-                return Err(ContractError::AssetMismatch {});
+        for min_asset in &min_assets_to_receive {
+            let refund_asset = refund_assets
+                .iter()
+                .find(|asset| asset.info.eq(&min_asset.info))
+                .ok_or(ContractError::AssetMismatch {})?;
+
+            if refund_asset.amount < min_asset.amount {
+                return Err(ContractError::WithdrawSlippageViolation {
+                    asset_name: refund_asset.info.to_string(),
+                    received: refund_asset.amount,
+                    expected: min_asset.amount,
+                });
             }
         }
-
-        if refund_assets[0].info.ne(&min_assets_to_receive[0].info) {
-            refund_assets.swap(0, 1)
-        }
-
-        if refund_assets[0].amount < min_assets_to_receive[0].amount {
-            return Err(ContractError::WithdrawSlippageViolation {
-                asset_name: refund_assets[0].info.to_string(),
-                received: refund_assets[0].amount,
-                expected: min_assets_to_receive[0].amount,
-            });
-        }
-
-        if refund_assets[1].amount < min_assets_to_receive[1].amount {
-            return Err(ContractError::WithdrawSlippageViolation {
-                asset_name: refund_assets[1].info.to_string(),
-                received: refund_assets[1].amount,
-                expected: min_assets_to_receive[1].amount,
-            });
-        }
     }
 
     Ok(())
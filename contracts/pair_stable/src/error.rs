@@ -87,6 +87,9 @@ pub enum ContractError {
     #[error("Invalid number of assets. This pair support only {0} assets")]
     InvalidNumberOfAssets(usize),
 
+    #[error("Invalid number of assets. This pair supports from {min} to {max} assets")]
+    InvalidPoolSize { min: usize, max: usize },
+
     #[error("Contract can't be migrated!")]
     MigrationError {},
 
@@ -118,8 +121,14 @@ pub enum ContractError {
     #[error("Operation is not supported")]
     NotSupported {},
 
-    #[error("Maker fee address is not set in factory")]
-    MakerFeeAddressNotSet {},
+    #[error("Not enough LP tokens to withdraw the requested assets: required {required}, provided {provided}")]
+    InsufficientLpTokens {
+        required: Uint128,
+        provided: Uint128,
+    },
+
+    #[error("Requested assets exceed the pool reserves")]
+    ExceedsPoolReserves {},
 
     #[error("Pair is paused")]
     PairPaused {},
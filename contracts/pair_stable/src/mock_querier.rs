@@ -7,7 +7,7 @@ use cosmwasm_std::{
 };
 use cw20::{BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
-use oroswap::factory::QueryMsg::{Config, FeeInfo};
+use oroswap::factory::QueryMsg::{Config, FeeInfo, PairFeeInfo};
 use oroswap::factory::{Config as FactoryConfig, ConfigResponse, FeeInfoResponse};
 
 /// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies.
@@ -82,7 +82,7 @@ impl WasmMockQuerier {
             QueryRequest::Wasm(WasmQuery::Smart { contract_addr, msg }) => {
                 if contract_addr == "factory" {
                     match from_json(&msg).unwrap() {
-                        FeeInfo { .. } => SystemResult::Ok(
+                        FeeInfo { .. } | PairFeeInfo { .. } => SystemResult::Ok(
                             to_json_binary(&FeeInfoResponse {
                                 fee_address: Some(Addr::unchecked("fee")),
                                 total_fee_bps: 30,
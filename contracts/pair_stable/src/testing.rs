@@ -102,7 +102,7 @@ fn proper_initialization() {
     let res = instantiate(deps.as_mut(), env.clone(), info, msg).unwrap();
 
     // Check that we have the right number of messages
-    assert_eq!(res.messages.len(), 2);
+    assert_eq!(res.messages.len(), 1);
     
     // Check the first message (MsgCreateDenom)
     let create_denom_msg = &res.messages[0];
@@ -121,20 +121,6 @@ fn proper_initialization() {
     } else {
         panic!("Expected Stargate message");
     }
-    
-    // Check the second message (Bank send)
-    let bank_msg = &res.messages[1];
-    assert_eq!(bank_msg.id, 0);
-    assert_eq!(bank_msg.reply_on, ReplyOn::Never);
-    
-    if let CosmosMsg::Bank(BankMsg::Send { to_address, amount }) = &bank_msg.msg {
-        assert_eq!(to_address, "fee_address");
-        assert_eq!(amount.len(), 1);
-        assert_eq!(amount[0].denom, "uzig");
-        assert_eq!(amount[0].amount, Uint128::from(1000_u128));
-    } else {
-        panic!("Expected Bank Send message");
-    }
 
     let denom = format!("factory/{}/{}", env.contract.address, "astroport/share");
 
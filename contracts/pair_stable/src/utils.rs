@@ -11,18 +11,13 @@ use cosmwasm_std::{
 use itertools::Itertools;
 
 use oroswap::asset::{Asset, AssetInfo, DecimalAsset, MINIMUM_LIQUIDITY_AMOUNT};
-use oroswap::observation::{
-    safe_sma_buffer_not_full, safe_sma_calculation, Observation, PrecommitObservation,
-};
 use oroswap::pair::TWAP_PRECISION;
 use oroswap::querier::query_factory_config;
-use oroswap_circular_buffer::error::BufferResult;
-use oroswap_circular_buffer::BufferManager;
 use oroswap::asset::Decimal256Ext;
 
 use crate::error::ContractError;
 use crate::math::{calc_y, compute_d};
-use crate::state::{get_precision, Config, OBSERVATIONS};
+use crate::state::{get_precision, Config};
 
 /// Helper function to check if the given asset infos are valid.
 pub(crate) fn check_asset_infos(
@@ -113,6 +108,17 @@ pub(crate) fn select_pools(
     }
 }
 
+/// Returns the position of the pool with the given asset info in the pools array.
+pub(crate) fn pool_index(
+    pools: &[DecimalAsset],
+    asset_info: &AssetInfo,
+) -> Result<usize, ContractError> {
+    pools
+        .iter()
+        .position(|pool| pool.info.eq(asset_info))
+        .ok_or_else(|| ContractError::InvalidAsset(asset_info.to_string()))
+}
+
 /// Compute the current pool amplification coefficient (AMP).
 pub fn compute_current_amp(config: &Config, env: &Env) -> StdResult<Uint64> {
     let block_time = env.block.time.seconds();
@@ -262,11 +268,15 @@ pub(crate) fn compute_swap(
 ) -> Result<SwapResult, ContractError> {
     let token_precision = get_precision(storage, &ask_pool.info)?;
     let xp = pools.iter().map(|p| p.amount).collect_vec();
+    let offer_ind = pool_index(pools, &offer_pool.info)?;
+    let ask_ind = pool_index(pools, &ask_pool.info)?;
 
     let new_ask_pool = calc_y(
         compute_current_amp(config, env)?,
         offer_pool.amount + offer_asset.amount,
         &xp,
+        offer_ind,
+        ask_ind,
         token_precision,
     )?;
 
@@ -332,66 +342,6 @@ pub fn accumulate_prices(
     Ok(true)
 }
 
-/// Calculate and save price moving average
-pub fn accumulate_swap_sizes(storage: &mut dyn Storage, env: &Env) -> BufferResult<()> {
-    if let Some(PrecommitObservation {
-        base_amount,
-        quote_amount,
-        precommit_ts,
-    }) = PrecommitObservation::may_load(storage)?
-    {
-        let mut buffer = BufferManager::new(storage, OBSERVATIONS)?;
-        let observed_price = Decimal::from_ratio(base_amount, quote_amount);
-
-        let new_observation;
-        if let Some(last_obs) = buffer.read_last(storage)? {
-            // Skip saving observation if it has been already saved
-            if last_obs.ts < precommit_ts {
-                // Since this is circular buffer the next index contains the oldest value
-                let count = buffer.capacity();
-                if let Some(oldest_obs) = buffer.read_single(storage, buffer.head() + 1)? {
-                    let price_sma = safe_sma_calculation(
-                        last_obs.price_sma,
-                        oldest_obs.price,
-                        count,
-                        observed_price,
-                    )?;
-                    new_observation = Observation {
-                        ts: precommit_ts,
-                        price: observed_price,
-                        price_sma,
-                    };
-                } else {
-                    // Buffer is not full yet
-                    let count = buffer.head();
-                    let price_sma =
-                        safe_sma_buffer_not_full(last_obs.price_sma, count, observed_price)?;
-                    new_observation = Observation {
-                        ts: precommit_ts,
-                        price: observed_price,
-                        price_sma,
-                    };
-                }
-
-                buffer.instant_push(storage, &new_observation)?
-            }
-        } else {
-            // Buffer is empty
-            if env.block.time.seconds() > precommit_ts {
-                new_observation = Observation {
-                    ts: precommit_ts,
-                    price: observed_price,
-                    price_sma: observed_price,
-                };
-
-                buffer.instant_push(storage, &new_observation)?
-            }
-        }
-    }
-
-    Ok(())
-}
-
 /// Internal function to determine which asset is base one, which is quote one
 pub(crate) fn determine_base_quote_amount(
     pools: &[DecimalAsset],
@@ -163,6 +163,7 @@ impl Helper {
                 is_generator_disabled: false,
                 permissioned: false,
                 pool_creation_fee: Uint128::new(1000),
+                pool_creation_fee_denom: "uzig".to_string(),
             }],
             token_code_id,
             generator_address: None,
@@ -190,6 +191,7 @@ impl Helper {
             pair_type: PairType::Stable {},
             asset_infos: asset_infos.clone(),
             init_params: Some(to_json_binary(&StablePoolParams { amp, owner: None }).unwrap()),
+            fee_tier: None,
         };
 
         app.execute_contract(owner.clone(), factory.clone(), &init_pair_msg, &[coin(1000, "uzig")])?;
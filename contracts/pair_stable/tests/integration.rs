@@ -146,6 +146,7 @@ fn instantiate_pair(mut router: &mut TestApp, owner: &Addr) -> Addr {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id,
         generator_address: None,
@@ -184,6 +185,7 @@ fn instantiate_pair(mut router: &mut TestApp, owner: &Addr) -> Addr {
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     router
@@ -647,6 +649,7 @@ fn provide_lp_for_single_token() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -684,6 +687,7 @@ fn provide_lp_for_single_token() {
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     app.execute_contract(owner.clone(), factory_instance.clone(), &msg, &[Coin {
@@ -996,6 +1000,7 @@ fn test_compatibility_of_tokens_with_different_precision() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -1033,6 +1038,7 @@ fn test_compatibility_of_tokens_with_different_precision() {
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     app.execute_contract(owner.clone(), factory_instance.clone(), &msg, &[Coin {
@@ -1206,6 +1212,7 @@ fn test_if_twap_is_calculated_correctly_when_pool_idles() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id,
         generator_address: None,
@@ -1243,6 +1250,7 @@ fn test_if_twap_is_calculated_correctly_when_pool_idles() {
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     app.execute_contract(
@@ -1437,6 +1445,7 @@ fn update_pair_config() {
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id,
         generator_address: Some(String::from("generator")),
@@ -1475,6 +1484,7 @@ fn update_pair_config() {
             })
             .unwrap(),
         ),
+        fee_tier: None,
     };
 
     router
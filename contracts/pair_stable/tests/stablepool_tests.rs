@@ -1,12 +1,13 @@
 #![cfg(not(tarpaulin_include))]
 
-use cosmwasm_std::{Addr, Decimal, StdError};
+use cosmwasm_std::{Addr, Decimal, StdError, Uint128};
 use itertools::Itertools;
 use std::str::FromStr;
 
 use oroswap::asset::AssetInfoExt;
 use oroswap::cosmwasm_ext::AbsDiff;
 use oroswap::observation::OracleObservation;
+use oroswap::pair::{QueryMsg, SimulateImbalancedWithdrawResponse};
 use oroswap_pair_stable::error::ContractError;
 use oroswap_test::coins::TestCoin;
 use oroswap_test::convert::f64_to_dec;
@@ -71,38 +72,73 @@ fn provide_and_withdraw_no_fee() {
     assert_eq!(114296927, helper.coin_balance(&test_coins[0], &user1));
     assert_eq!(85722695, helper.coin_balance(&test_coins[1], &user1));
 
-    // Checking imbalanced withdraw. Withdrawing only the first asset x 200 with the whole amount of LP tokens
+    // Checking imbalanced withdraw. Withdrawing only the first asset x 100 with the whole amount of LP tokens
+    let withdraw_assets = vec![helper.assets[&test_coins[0]].with_balance(100_000000u128)];
+    let simulation: SimulateImbalancedWithdrawResponse = helper
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &helper.pair_addr,
+            &QueryMsg::SimulateImbalancedWithdraw {
+                lp_amount: Uint128::new(200_000000),
+                assets: withdraw_assets.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(simulation.assets[0], withdraw_assets[0]);
+    let burn_amount = simulation.lp_amount.u128();
+    helper
+        .withdraw_liquidity(&user2, 200_000000, withdraw_assets, None)
+        .unwrap();
+    assert_eq!(100_000000, helper.coin_balance(&test_coins[0], &user2));
+    assert_eq!(0, helper.coin_balance(&test_coins[1], &user2));
+    // Unused LP tokens are returned to the user
+    assert_eq!(
+        200_000000 - burn_amount,
+        helper.native_balance(&helper.lp_token, &user2)
+    );
+
+    // Not enough LP tokens to withdraw the requested assets
     let err = helper
         .withdraw_liquidity(
-            &user2,
-            200_000000,
-            vec![helper.assets[&test_coins[0]].with_balance(200_000000u128)],
+            &user3,
+            1_000000,
+            vec![helper.assets[&test_coins[1]].with_balance(101_000000u128)],
             None,
         )
         .unwrap_err();
-    assert_eq!(
-        err.root_cause().to_string(),
-        "Generic error: Imbalanced withdraw is currently disabled"
-    );
+    assert!(matches!(
+        err.downcast().unwrap(),
+        ContractError::InsufficientLpTokens { provided, .. } if provided.u128() == 1_000000
+    ));
 
     // Providing more LP tokens than needed. The rest will be kept on the user's balance
-    let err = helper
+    helper
         .withdraw_liquidity(
             &user3,
             200_892384,
             vec![helper.assets[&test_coins[1]].with_balance(101_000000u128)],
             None,
         )
+        .unwrap();
+    assert!(helper.native_balance(&helper.lp_token, &user3) > 299_927827 - 200_892384);
+    assert_eq!(0, helper.coin_balance(&test_coins[0], &user3));
+    assert_eq!(101_000000, helper.coin_balance(&test_coins[1], &user3));
+
+    // The pool can't be drained with an imbalanced withdraw
+    let pool_balance = helper.coin_balance(&test_coins[1], &helper.pair_addr);
+    let err = helper
+        .withdraw_liquidity(
+            &user3,
+            1_000000,
+            vec![helper.assets[&test_coins[1]].with_balance(pool_balance)],
+            None,
+        )
         .unwrap_err();
     assert_eq!(
-        err.root_cause().to_string(),
-        "Generic error: Imbalanced withdraw is currently disabled"
+        ContractError::ExceedsPoolReserves {},
+        err.downcast().unwrap()
     );
-
-    // initial balance - spent amount; 100 goes back to the user3
-    assert_eq!(299_927827, helper.native_balance(&helper.lp_token, &user3));
-    assert_eq!(0, helper.coin_balance(&test_coins[0], &user3));
-    assert_eq!(0, helper.coin_balance(&test_coins[1], &user3));
 }
 
 #[test]
@@ -252,7 +288,7 @@ fn check_wrong_initializations() {
     let err = Helper::new(&owner, vec![TestCoin::native("uluna")], 100u64, None).unwrap_err();
 
     assert_eq!(
-        ContractError::InvalidNumberOfAssets(2),
+        ContractError::InvalidPoolSize { min: 2, max: 5 },
         err.downcast().unwrap()
     );
 
@@ -272,7 +308,7 @@ fn check_wrong_initializations() {
     .unwrap_err();
 
     assert_eq!(
-        ContractError::InvalidNumberOfAssets(2),
+        ContractError::InvalidPoolSize { min: 2, max: 5 },
         err.downcast().unwrap()
     );
 
@@ -289,6 +325,21 @@ fn check_wrong_initializations() {
         "Doubling assets in asset infos"
     );
 
+    // 5 assets in the pool is okay
+    Helper::new(
+        &owner,
+        vec![
+            TestCoin::native("one"),
+            TestCoin::cw20("two"),
+            TestCoin::native("three"),
+            TestCoin::cw20("four"),
+            TestCoin::native("five"),
+        ],
+        100u64,
+        None,
+    )
+    .unwrap();
+
     // 2 assets in the pool is okay
     Helper::new(
         &owner,
@@ -345,40 +396,36 @@ fn check_withdraw_charges_fees() {
         .provide_liquidity(&user2, &provide_assets, None)
         .unwrap();
 
-    // Withdraw 100 x USDC
+    // Withdraw 200 x USDC. Converting 100 x LUNA to USDC this way is not free
     let lp_tokens_amount = helper.native_balance(&helper.lp_token, &user2);
     let err = helper
         .withdraw_liquidity(
             &user2,
             lp_tokens_amount,
-            vec![helper.assets[&test_coins[1]].with_balance(100_000000u128)],
+            vec![helper.assets[&test_coins[1]].with_balance(200_000000u128)],
             None,
         )
         .unwrap_err();
-    assert_eq!(
-        err.root_cause().to_string(),
-        "Generic error: Imbalanced withdraw is currently disabled"
-    );
+    assert!(matches!(
+        err.downcast().unwrap(),
+        ContractError::InsufficientLpTokens { .. }
+    ));
 
-    let err = helper
+    // Withdrawing slightly less than the provided USDC plus the usual swap amount is possible
+    let withdraw_amount = 100_000000 + usual_swap_amount - 50_000;
+    helper
         .withdraw_liquidity(
             &user2,
             lp_tokens_amount,
-            vec![helper.assets[&test_coins[1]].with_balance(usual_swap_amount)],
+            vec![helper.assets[&test_coins[1]].with_balance(withdraw_amount)],
             None,
         )
-        .unwrap_err();
-    assert_eq!(
-        err.root_cause().to_string(),
-        "Generic error: Imbalanced withdraw is currently disabled"
-    );
+        .unwrap();
 
     // A small residual of LP tokens is left
-    // assert_eq!(8, helper.native_balance(&helper.lp_token, &user2));
-    // assert_eq!(
-    //     usual_swap_amount,
-    //     helper.coin_balance(&test_coins[1], &user2)
-    // );
+    let residual = helper.native_balance(&helper.lp_token, &user2);
+    assert!(residual > 0 && residual < 100_000);
+    assert_eq!(withdraw_amount, helper.coin_balance(&test_coins[1], &user2));
 }
 
 #[test]
@@ -569,3 +616,111 @@ fn check_pool_prices() {
     );
     helper.app.next_block(10);
 }
+
+#[test]
+fn check_multi_asset_pool() {
+    let owner = Addr::unchecked("owner");
+
+    let test_coins = vec![
+        TestCoin::native("uusd"),
+        TestCoin::cw20("USDC"),
+        TestCoin::native("uluna"),
+    ];
+
+    let mut helper = Helper::new(&owner, test_coins.clone(), 100u64, None).unwrap();
+
+    let assets = vec![
+        helper.assets[&test_coins[0]].with_balance(100_000000u128),
+        helper.assets[&test_coins[1]].with_balance(100_000000u128),
+        helper.assets[&test_coins[2]].with_balance(100_000000u128),
+    ];
+    helper.provide_liquidity(&owner, &assets, None).unwrap();
+    assert_eq!(299_999000, helper.native_balance(&helper.lp_token, &owner));
+
+    // Providing only a part of the pool assets is not allowed
+    let err = helper
+        .provide_liquidity(&owner, &assets[..2], None)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidNumberOfAssets(3),
+        err.downcast().unwrap()
+    );
+
+    let user = Addr::unchecked("user");
+    let offer_asset = helper.assets[&test_coins[0]].with_balance(1_000000u128);
+    helper.give_me_money(&[offer_asset.clone()], &user);
+
+    // The ask asset must be specified in pools with more than two assets
+    let err = helper.swap(&user, &offer_asset, None).unwrap_err();
+    assert_eq!(
+        ContractError::VariableAssetMissed {},
+        err.downcast().unwrap()
+    );
+    let err = helper
+        .simulate_reverse_swap(
+            &helper.assets[&test_coins[2]].with_balance(1_000000u128),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(
+            "Querier contract error: Generic error: Ask or offer asset is missed"
+        )
+    );
+
+    let sim_resp = helper
+        .simulate_swap(&offer_asset, Some(helper.assets[&test_coins[2]].clone()))
+        .unwrap();
+    // 0.05% fee and a tiny spread in a balanced pool
+    assert!(sim_resp.return_amount.u128() < 999_500);
+    assert!(sim_resp.return_amount.u128() > 999_000);
+
+    let reverse_sim_resp = helper
+        .simulate_reverse_swap(
+            &helper.assets[&test_coins[2]].with_balance(sim_resp.return_amount.u128()),
+            Some(helper.assets[&test_coins[0]].clone()),
+        )
+        .unwrap();
+    assert!(offer_asset.amount.abs_diff(reverse_sim_resp.offer_amount) <= Uint128::new(1));
+
+    helper
+        .swap(
+            &user,
+            &offer_asset,
+            Some(helper.assets[&test_coins[2]].clone()),
+        )
+        .unwrap();
+    assert_eq!(0, helper.coin_balance(&test_coins[0], &user));
+    assert_eq!(0, helper.coin_balance(&test_coins[1], &user));
+    assert_eq!(
+        sim_resp.return_amount.u128(),
+        helper.coin_balance(&test_coins[2], &user)
+    );
+
+    // Imbalanced withdraw of two out of three assets
+    let withdraw_assets = vec![
+        helper.assets[&test_coins[1]].with_balance(10_000000u128),
+        helper.assets[&test_coins[2]].with_balance(5_000000u128),
+    ];
+    helper
+        .withdraw_liquidity(&owner, 20_000000, withdraw_assets, None)
+        .unwrap();
+    let lp_balance = helper.native_balance(&helper.lp_token, &owner);
+    assert!(lp_balance < 299_999000 - 15_000000);
+    assert!(lp_balance > 299_999000 - 20_000000);
+
+    // Balanced withdraw returns all three assets
+    let res = helper
+        .withdraw_liquidity(&owner, 100_000000, vec![], None)
+        .unwrap();
+    let refund_assets = res
+        .events
+        .iter()
+        .flat_map(|event| event.attributes.iter())
+        .find(|attr| attr.key == "refund_assets")
+        .unwrap()
+        .value
+        .clone();
+    assert_eq!(refund_assets.split(", ").count(), 3);
+}
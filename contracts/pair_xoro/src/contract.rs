@@ -0,0 +1,474 @@
+use std::str::FromStr;
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, coins, to_json_binary, wasm_execute, Addr, Binary, Decimal, Deps, DepsMut, Env,
+    MessageInfo, QuerierWrapper, Response, StdError, StdResult, Uint128, Uint256,
+};
+use cw2::set_contract_version;
+
+use oroswap::asset::{Asset, AssetInfo, PairInfo};
+use oroswap::pair::{
+    ConfigResponse, ExecuteMsg, InstantiateMsg, PoolResponse, QueryMsg, ReverseSimulationResponse,
+    SimulationResponse, DEFAULT_SLIPPAGE, MAX_ALLOWED_SLIPPAGE,
+};
+use oroswap::pair_xoro::XoroPairInitParams;
+use oroswap::querier::query_factory_config;
+use oroswap::staking;
+
+use crate::error::ContractError;
+use crate::state::{Config, CONFIG};
+
+/// Contract name that is used for migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+/// Contract version that is used for migration.
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
+/// The pair assets must be the ORO and xORO denoms of the staking contract.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    let params = XoroPairInitParams::from_json(msg.init_params)?;
+    let staking = deps.api.addr_validate(&params.staking)?;
+    let staking_config: staking::Config = deps
+        .querier
+        .query_wasm_smart(&staking, &staking::QueryMsg::Config {})?;
+
+    let expected_assets = [
+        AssetInfo::native(&staking_config.oro_denom),
+        AssetInfo::native(&staking_config.xoro_denom),
+    ];
+    if msg.asset_infos.len() != 2
+        || !expected_assets
+            .iter()
+            .all(|asset_info| msg.asset_infos.contains(asset_info))
+    {
+        return Err(ContractError::InvalidAssets {});
+    }
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = Config {
+        pair_info: PairInfo {
+            asset_infos: msg.asset_infos,
+            contract_addr: env.contract.address,
+            // The pair doesn't hold liquidity and doesn't issue LP tokens
+            liquidity_token: "".to_string(),
+            pair_type: msg.pair_type,
+        },
+        factory_addr: deps.api.addr_validate(&msg.factory_addr)?,
+        staking,
+        oro_denom: staking_config.oro_denom,
+        xoro_denom: staking_config.xoro_denom,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "instantiate"),
+        attr("contract", CONTRACT_NAME),
+        attr("staking", config.staking),
+    ]))
+}
+
+/// Exposes all the execute functions available in the contract.
+///
+/// * **ExecuteMsg::Swap {
+///             offer_asset,
+///             ask_asset_info,
+///             belief_price,
+///             max_spread,
+///             to,
+///         }** Swaps ORO to xORO or xORO to ORO via the staking contract.
+///
+/// All other messages are not supported.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Swap {
+            offer_asset,
+            ask_asset_info,
+            belief_price,
+            max_spread,
+            to,
+        } => {
+            let to = to.map(|addr| deps.api.addr_validate(&addr)).transpose()?;
+            swap(
+                deps,
+                info,
+                offer_asset,
+                ask_asset_info,
+                belief_price,
+                max_spread,
+                to,
+            )
+        }
+        _ => Err(ContractError::NonSupported {}),
+    }
+}
+
+/// Swaps ORO to xORO by entering the staking contract or xORO to ORO by leaving it.
+/// The swap is executed at the staking exchange rate without spread. If the staking contract
+/// has the unbonding period enabled, xORO to ORO swaps pay the instant exit fee.
+///
+/// * **offer_asset** asset to swap.
+///
+/// * **ask_asset_info** asset to receive. Must be the other pair asset if set.
+///
+/// * **belief_price** belief price used in the swap.
+///
+/// * **max_spread** max spread allowed relative to **belief_price**.
+///
+/// * **to** sets the recipient of the swap operation.
+pub fn swap(
+    deps: DepsMut,
+    info: MessageInfo,
+    offer_asset: Asset,
+    ask_asset_info: Option<AssetInfo>,
+    belief_price: Option<Decimal>,
+    max_spread: Option<Decimal>,
+    to: Option<Addr>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let ask_asset_info = ask_asset(&config, &offer_asset.info, ask_asset_info)?;
+    offer_asset.assert_sent_native_token_balance(&info)?;
+    if offer_asset.amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    let exit_fee = query_exit_fee(deps.querier, &config)?;
+    let (return_amount, commission_amount) =
+        compute_swap(deps.querier, &config, &offer_asset, exit_fee)?;
+    assert_max_spread(belief_price, max_spread, offer_asset.amount, return_amount)?;
+
+    let receiver = to.unwrap_or_else(|| info.sender.clone());
+    let staking_msg = if offer_asset.info == AssetInfo::native(&config.oro_denom) {
+        staking::ExecuteMsg::Enter {
+            receiver: Some(receiver.to_string()),
+        }
+    } else if exit_fee.is_some() {
+        staking::ExecuteMsg::InstantLeave {
+            receiver: Some(receiver.to_string()),
+        }
+    } else {
+        staking::ExecuteMsg::Leave {
+            receiver: Some(receiver.to_string()),
+        }
+    };
+    let funds = coins(offer_asset.amount.u128(), offer_asset.info.to_string());
+
+    Ok(Response::new()
+        .add_message(wasm_execute(&config.staking, &staking_msg, funds)?)
+        .add_attributes(vec![
+            attr("action", "swap"),
+            attr("sender", info.sender),
+            attr("receiver", receiver),
+            attr("offer_asset", offer_asset.info.to_string()),
+            attr("ask_asset", ask_asset_info.to_string()),
+            attr("offer_amount", offer_asset.amount),
+            attr("return_amount", return_amount),
+            attr("spread_amount", "0"),
+            attr("commission_amount", commission_amount),
+            attr("maker_fee_amount", "0"),
+        ]))
+}
+
+/// Returns the pair asset opposite to **offer_asset_info**.
+/// Returns an error if either asset doesn't belong to the pair.
+fn ask_asset(
+    config: &Config,
+    offer_asset_info: &AssetInfo,
+    ask_asset_info: Option<AssetInfo>,
+) -> Result<AssetInfo, ContractError> {
+    let oro = AssetInfo::native(&config.oro_denom);
+    let xoro = AssetInfo::native(&config.xoro_denom);
+
+    let expected_ask = if *offer_asset_info == oro {
+        xoro
+    } else if *offer_asset_info == xoro {
+        oro
+    } else {
+        return Err(ContractError::AssetMismatch {});
+    };
+
+    match ask_asset_info {
+        Some(ask_asset_info) if ask_asset_info != expected_ask => {
+            Err(ContractError::AssetMismatch {})
+        }
+        _ => Ok(expected_ask),
+    }
+}
+
+/// Returns the total ORO deposit and the total xORO supply of the staking contract.
+fn query_staking_totals(querier: QuerierWrapper, config: &Config) -> StdResult<(Uint128, Uint128)> {
+    let total_deposit =
+        querier.query_wasm_smart(&config.staking, &staking::QueryMsg::TotalDeposit {})?;
+    let total_shares =
+        querier.query_wasm_smart(&config.staking, &staking::QueryMsg::TotalShares {})?;
+
+    Ok((total_deposit, total_shares))
+}
+
+/// Returns the instant exit fee if the staking contract has the unbonding period enabled.
+/// In this case the pair leaves staking via `InstantLeave` so swaps are not delayed by unbonding.
+fn query_exit_fee(querier: QuerierWrapper, config: &Config) -> StdResult<Option<Decimal>> {
+    let staking_config: staking::Config =
+        querier.query_wasm_smart(&config.staking, &staking::QueryMsg::Config {})?;
+
+    Ok((staking_config.unbonding_period > 0).then_some(staking_config.instant_exit_fee))
+}
+
+/// Returns the amount of the ask asset received for **offer_asset** and the exit fee
+/// charged by the staking contract. Uses the same math as the staking contract.
+pub fn compute_swap(
+    querier: QuerierWrapper,
+    config: &Config,
+    offer_asset: &Asset,
+    exit_fee: Option<Decimal>,
+) -> Result<(Uint128, Uint128), ContractError> {
+    let (total_deposit, total_shares) = query_staking_totals(querier, config)?;
+
+    let (return_amount, commission_amount) =
+        if offer_asset.info == AssetInfo::native(&config.oro_denom) {
+            let return_amount = if total_shares.is_zero() || total_deposit.is_zero() {
+                offer_asset.amount
+            } else {
+                offer_asset
+                    .amount
+                    .multiply_ratio(total_shares, total_deposit)
+            };
+            (return_amount, Uint128::zero())
+        } else {
+            if total_shares.is_zero() {
+                return Err(ContractError::InvalidZeroAmount {});
+            }
+            let return_amount = offer_asset
+                .amount
+                .multiply_ratio(total_deposit, total_shares);
+            let commission_amount = return_amount * exit_fee.unwrap_or_default();
+            (return_amount - commission_amount, commission_amount)
+        };
+
+    if return_amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    Ok((return_amount, commission_amount))
+}
+
+/// Returns the amount of the offer asset needed to receive **ask_asset** and the exit fee
+/// charged by the staking contract.
+pub fn compute_offer_amount(
+    querier: QuerierWrapper,
+    config: &Config,
+    ask_asset: &Asset,
+    exit_fee: Option<Decimal>,
+) -> Result<(Uint128, Uint128), ContractError> {
+    let (total_deposit, total_shares) = query_staking_totals(querier, config)?;
+
+    let (offer_amount, commission_amount) =
+        if ask_asset.info == AssetInfo::native(&config.xoro_denom) {
+            let offer_amount = if total_shares.is_zero() || total_deposit.is_zero() {
+                ask_asset.amount
+            } else {
+                multiply_ratio_ceil(ask_asset.amount, total_deposit, total_shares)?
+            };
+            (offer_amount, Uint128::zero())
+        } else {
+            if total_deposit.is_zero() {
+                return Err(ContractError::InvalidZeroAmount {});
+            }
+            // ORO to withdraw before the exit fee is deducted
+            let exit_fee = exit_fee.unwrap_or_default();
+            let withdraw_amount = multiply_ratio_ceil(
+                ask_asset.amount,
+                Decimal::one().atomics(),
+                (Decimal::one() - exit_fee).atomics(),
+            )?;
+            let offer_amount = multiply_ratio_ceil(withdraw_amount, total_shares, total_deposit)?;
+            (offer_amount, withdraw_amount - ask_asset.amount)
+        };
+
+    Ok((offer_amount, commission_amount))
+}
+
+/// Returns `value * numerator / denominator` rounded up.
+fn multiply_ratio_ceil(
+    value: Uint128,
+    numerator: Uint128,
+    denominator: Uint128,
+) -> Result<Uint128, ContractError> {
+    let denominator = Uint256::from(denominator);
+    let result = (value.full_mul(numerator) + denominator - Uint256::one()) / denominator;
+
+    Ok(result.try_into()?)
+}
+
+/// Checks that the swap return doesn't deviate from **belief_price** by more than **max_spread**.
+/// The swap has no spread of its own so the check only applies if **belief_price** is set.
+pub fn assert_max_spread(
+    belief_price: Option<Decimal>,
+    max_spread: Option<Decimal>,
+    offer_amount: Uint128,
+    return_amount: Uint128,
+) -> Result<(), ContractError> {
+    let default_spread = Decimal::from_str(DEFAULT_SLIPPAGE)?;
+    let max_allowed_spread = Decimal::from_str(MAX_ALLOWED_SLIPPAGE)?;
+
+    let max_spread = max_spread.unwrap_or(default_spread);
+    if max_spread > max_allowed_spread {
+        return Err(ContractError::AllowedSpreadAssertion {});
+    }
+
+    if let Some(belief_price) = belief_price {
+        let expected_return = offer_amount
+            * belief_price
+                .inv()
+                .ok_or_else(|| StdError::generic_err("Belief price must not be zero!"))?;
+        let spread_amount = expected_return.saturating_sub(return_amount);
+
+        if return_amount < expected_return
+            && Decimal::from_ratio(spread_amount, expected_return) > max_spread
+        {
+            return Err(ContractError::MaxSpreadAssertion {});
+        }
+    }
+
+    Ok(())
+}
+
+/// Exposes all the queries available in the contract.
+///
+/// * **QueryMsg::Pair {}** Returns information about the pair in an object of type [`PairInfo`].
+///
+/// * **QueryMsg::Pool {}** Returns the pair assets with zero amounts as the pair holds no liquidity.
+///
+/// * **QueryMsg::Config {}** Returns the configuration for the pair contract.
+///
+/// * **QueryMsg::Simulation { offer_asset, ask_asset_info }** Returns the result of a swap simulation
+/// in an object of type [`SimulationResponse`].
+///
+/// * **QueryMsg::ReverseSimulation { offer_asset_info, ask_asset }** Returns the result of a reverse swap simulation
+/// in an object of type [`ReverseSimulationResponse`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Pair {} => to_json_binary(&CONFIG.load(deps.storage)?.pair_info),
+        QueryMsg::Pool {} => to_json_binary(&query_pool(deps)?),
+        QueryMsg::Config {} => to_json_binary(&query_config(deps)?),
+        QueryMsg::Simulation {
+            offer_asset,
+            ask_asset_info,
+        } => to_json_binary(&query_simulation(deps, offer_asset, ask_asset_info)?),
+        QueryMsg::ReverseSimulation {
+            offer_asset_info,
+            ask_asset,
+        } => to_json_binary(&query_reverse_simulation(
+            deps,
+            ask_asset,
+            offer_asset_info,
+        )?),
+        _ => Err(StdError::generic_err(
+            ContractError::NonSupported {}.to_string(),
+        )),
+    }
+}
+
+/// Returns the pair assets with zero amounts. The pair swaps via the staking contract
+/// and doesn't hold liquidity of its own.
+pub fn query_pool(deps: Deps) -> StdResult<PoolResponse> {
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(PoolResponse {
+        assets: config
+            .pair_info
+            .asset_infos
+            .into_iter()
+            .map(|info| Asset {
+                info,
+                amount: Uint128::zero(),
+            })
+            .collect(),
+        total_share: Uint128::zero(),
+    })
+}
+
+/// Returns the pair contract configuration in a [`ConfigResponse`] object.
+pub fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let factory_config = query_factory_config(&deps.querier, &config.factory_addr)?;
+
+    Ok(ConfigResponse {
+        block_time_last: 0,
+        params: Some(to_json_binary(&XoroPairInitParams {
+            staking: config.staking.to_string(),
+        })?),
+        owner: factory_config.owner,
+        factory_addr: config.factory_addr,
+        tracker_addr: None,
+    })
+}
+
+/// Returns the result of a swap in a [`SimulationResponse`] object.
+pub fn query_simulation(
+    deps: Deps,
+    offer_asset: Asset,
+    ask_asset_info: Option<AssetInfo>,
+) -> StdResult<SimulationResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let exit_fee = query_exit_fee(deps.querier, &config)?;
+
+    ask_asset(&config, &offer_asset.info, ask_asset_info)
+        .and_then(|_| compute_swap(deps.querier, &config, &offer_asset, exit_fee))
+        .map(|(return_amount, commission_amount)| SimulationResponse {
+            return_amount,
+            spread_amount: Uint128::zero(),
+            commission_amount,
+            tax_amount: Uint128::zero(),
+        })
+        .map_err(|err| StdError::generic_err(err.to_string()))
+}
+
+/// Returns the amount of the offer asset needed to receive **ask_asset** in a
+/// [`ReverseSimulationResponse`] object.
+pub fn query_reverse_simulation(
+    deps: Deps,
+    ask_asset: Asset,
+    offer_asset_info: Option<AssetInfo>,
+) -> StdResult<ReverseSimulationResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let exit_fee = query_exit_fee(deps.querier, &config)?;
+
+    ask_asset_for_offer(&config, &ask_asset.info, offer_asset_info)
+        .and_then(|_| compute_offer_amount(deps.querier, &config, &ask_asset, exit_fee))
+        .map(
+            |(offer_amount, commission_amount)| ReverseSimulationResponse {
+                offer_amount,
+                spread_amount: Uint128::zero(),
+                commission_amount,
+                tax_amount: Uint128::zero(),
+            },
+        )
+        .map_err(|err| StdError::generic_err(err.to_string()))
+}
+
+/// Checks that **ask_asset_info** and **offer_asset_info** are opposite pair assets.
+fn ask_asset_for_offer(
+    config: &Config,
+    ask_asset_info: &AssetInfo,
+    offer_asset_info: Option<AssetInfo>,
+) -> Result<(), ContractError> {
+    // The pair is symmetric so the ask asset is checked as if it were offered
+    ask_asset(config, ask_asset_info, offer_asset_info).map(|_| ())
+}
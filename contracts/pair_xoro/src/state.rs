@@ -0,0 +1,23 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+
+use oroswap::asset::PairInfo;
+
+/// This structure stores the main config parameters for the ORO/xORO pair contract.
+#[cw_serde]
+pub struct Config {
+    /// General pair information (e.g pair type)
+    pub pair_info: PairInfo,
+    /// The factory contract address
+    pub factory_addr: Addr,
+    /// The ORO staking contract address
+    pub staking: Addr,
+    /// The ORO denom
+    pub oro_denom: String,
+    /// The xORO denom
+    pub xoro_denom: String,
+}
+
+/// Stores the config struct at the given key
+pub const CONFIG: Item<Config> = Item::new("config");
@@ -0,0 +1,388 @@
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{
+    attr, coin, from_json, to_json_binary, Addr, ContractResult, Decimal, OwnedDeps, SubMsg,
+    SystemResult, Uint128, WasmMsg, WasmQuery,
+};
+
+use oroswap::asset::{Asset, AssetInfo, PairInfo};
+use oroswap::factory::PairType;
+use oroswap::pair::{
+    ExecuteMsg, InstantiateMsg, PoolResponse, QueryMsg, ReverseSimulationResponse,
+    SimulationResponse,
+};
+use oroswap::pair_xoro::{XoroPairInitParams, XORO_PAIR_TYPE};
+use oroswap::staking;
+
+use crate::contract::{execute, instantiate, query};
+use crate::error::ContractError;
+
+const ORO: &str = "oro";
+const XORO: &str = "xoro";
+const STAKING: &str = "staking";
+
+/// Mocks a staking contract where 1 xORO is worth 1.1 ORO
+fn mock_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    mock_deps_with_unbonding(0, Decimal::zero())
+}
+
+fn mock_deps_with_unbonding(
+    unbonding_period: u64,
+    instant_exit_fee: Decimal,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    let mut deps = mock_dependencies();
+    deps.querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == STAKING => {
+            let response = match from_json(msg).unwrap() {
+                staking::QueryMsg::Config {} => to_json_binary(&staking::Config {
+                    owner: Addr::unchecked("owner"),
+                    oro_denom: ORO.to_string(),
+                    xoro_denom: XORO.to_string(),
+                    bootstrap_amount: Uint128::new(1000),
+                    unbonding_period,
+                    instant_exit_fee,
+                    paused: false,
+                }),
+                staking::QueryMsg::TotalDeposit {} => to_json_binary(&Uint128::new(1100)),
+                staking::QueryMsg::TotalShares {} => to_json_binary(&Uint128::new(1000)),
+                _ => unimplemented!(),
+            };
+            SystemResult::Ok(ContractResult::from(response))
+        }
+        _ => unimplemented!(),
+    });
+
+    deps
+}
+
+fn instantiate_msg(asset_infos: Vec<AssetInfo>) -> InstantiateMsg {
+    InstantiateMsg {
+        pair_type: PairType::Custom(XORO_PAIR_TYPE.to_string()),
+        asset_infos,
+        token_code_id: 0,
+        factory_addr: "factory".to_string(),
+        init_params: Some(
+            to_json_binary(&XoroPairInitParams {
+                staking: STAKING.to_string(),
+            })
+            .unwrap(),
+        ),
+    }
+}
+
+fn setup() -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    setup_with_deps(mock_deps())
+}
+
+fn setup_with_deps(
+    mut deps: OwnedDeps<MockStorage, MockApi, MockQuerier>,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier> {
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("factory", &[]),
+        instantiate_msg(vec![AssetInfo::native(ORO), AssetInfo::native(XORO)]),
+    )
+    .unwrap();
+
+    deps
+}
+
+fn swap_msg(offer_asset: Asset, belief_price: Option<Decimal>) -> ExecuteMsg {
+    ExecuteMsg::Swap {
+        offer_asset,
+        ask_asset_info: None,
+        belief_price,
+        max_spread: None,
+        to: None,
+    }
+}
+
+#[test]
+fn proper_initialization() {
+    let mut deps = mock_deps();
+
+    let err = instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("factory", &[]),
+        instantiate_msg(vec![AssetInfo::native(ORO), AssetInfo::native("uzig")]),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::InvalidAssets {});
+
+    let mut msg = instantiate_msg(vec![AssetInfo::native(XORO), AssetInfo::native(ORO)]);
+    msg.init_params = None;
+    instantiate(deps.as_mut(), mock_env(), mock_info("factory", &[]), msg).unwrap_err();
+
+    let deps = setup();
+    let pair_info: PairInfo =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Pair {}).unwrap()).unwrap();
+    assert_eq!(
+        pair_info,
+        PairInfo {
+            asset_infos: vec![AssetInfo::native(ORO), AssetInfo::native(XORO)],
+            contract_addr: mock_env().contract.address,
+            liquidity_token: "".to_string(),
+            pair_type: PairType::Custom(XORO_PAIR_TYPE.to_string()),
+        }
+    );
+
+    let pool: PoolResponse =
+        from_json(query(deps.as_ref(), mock_env(), QueryMsg::Pool {}).unwrap()).unwrap();
+    assert_eq!(
+        pool,
+        PoolResponse {
+            assets: vec![Asset::native(ORO, 0u128), Asset::native(XORO, 0u128)],
+            total_share: Uint128::zero(),
+        }
+    );
+}
+
+#[test]
+fn swap_through_staking() {
+    let mut deps = setup();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[coin(1000, ORO)]),
+        swap_msg(Asset::native(ORO, 1000u128), None),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: STAKING.to_string(),
+            msg: to_json_binary(&staking::ExecuteMsg::Enter {
+                receiver: Some("user".to_string()),
+            })
+            .unwrap(),
+            funds: vec![coin(1000, ORO)],
+        })]
+    );
+    assert!(res.attributes.contains(&attr("return_amount", "909")));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("router", &[coin(1000, XORO)]),
+        ExecuteMsg::Swap {
+            offer_asset: Asset::native(XORO, 1000u128),
+            ask_asset_info: Some(AssetInfo::native(ORO)),
+            belief_price: None,
+            max_spread: None,
+            to: Some("user".to_string()),
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: STAKING.to_string(),
+            msg: to_json_binary(&staking::ExecuteMsg::Leave {
+                receiver: Some("user".to_string()),
+            })
+            .unwrap(),
+            funds: vec![coin(1000, XORO)],
+        })]
+    );
+    assert!(res.attributes.contains(&attr("return_amount", "1100")));
+    assert!(res.attributes.contains(&attr("spread_amount", "0")));
+
+    // Funds must match the offer asset
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[coin(999, ORO)]),
+        swap_msg(Asset::native(ORO, 1000u128), None),
+    )
+    .unwrap_err();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[coin(1000, ORO)]),
+        ExecuteMsg::Swap {
+            offer_asset: Asset::native(ORO, 1000u128),
+            ask_asset_info: Some(AssetInfo::native(ORO)),
+            belief_price: None,
+            max_spread: None,
+            to: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AssetMismatch {});
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[coin(1000, "uzig")]),
+        swap_msg(Asset::native("uzig", 1000u128), None),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::AssetMismatch {});
+
+    // 1 ORO is expected to buy 1 xORO but the staking rate gives less
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[coin(1000, ORO)]),
+        swap_msg(Asset::native(ORO, 1000u128), Some(Decimal::one())),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::MaxSpreadAssertion {});
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[coin(1000, ORO)]),
+        swap_msg(Asset::native(ORO, 1000u128), Some(Decimal::percent(110))),
+    )
+    .unwrap();
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[]),
+        ExecuteMsg::WithdrawLiquidity {
+            assets: vec![],
+            min_assets_to_receive: None,
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NonSupported {});
+}
+
+#[test]
+fn simulations_use_staking_rate() {
+    let deps = setup();
+
+    let simulation: SimulationResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Simulation {
+                offer_asset: Asset::native(ORO, 1000u128),
+                ask_asset_info: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(simulation.return_amount, Uint128::new(909));
+    assert_eq!(simulation.spread_amount, Uint128::zero());
+    assert_eq!(simulation.commission_amount, Uint128::zero());
+
+    let simulation: SimulationResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Simulation {
+                offer_asset: Asset::native(XORO, 1000u128),
+                ask_asset_info: Some(AssetInfo::native(ORO)),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(simulation.return_amount, Uint128::new(1100));
+
+    // Offer amounts are rounded up so the swap returns at least the asked amount
+    let reverse: ReverseSimulationResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ReverseSimulation {
+                offer_asset_info: None,
+                ask_asset: Asset::native(XORO, 909u128),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(reverse.offer_amount, Uint128::new(1000));
+
+    let reverse: ReverseSimulationResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ReverseSimulation {
+                offer_asset_info: Some(AssetInfo::native(XORO)),
+                ask_asset: Asset::native(ORO, 1101u128),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(reverse.offer_amount, Uint128::new(1001));
+
+    query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::ReverseSimulation {
+            offer_asset_info: Some(AssetInfo::native(ORO)),
+            ask_asset: Asset::native(ORO, 1000u128),
+        },
+    )
+    .unwrap_err();
+}
+
+#[test]
+fn swap_with_unbonding_pays_exit_fee() {
+    let mut deps = setup_with_deps(mock_deps_with_unbonding(86400, Decimal::percent(10)));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("user", &[coin(1000, XORO)]),
+        swap_msg(Asset::native(XORO, 1000u128), None),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::new(WasmMsg::Execute {
+            contract_addr: STAKING.to_string(),
+            msg: to_json_binary(&staking::ExecuteMsg::InstantLeave {
+                receiver: Some("user".to_string()),
+            })
+            .unwrap(),
+            funds: vec![coin(1000, XORO)],
+        })]
+    );
+    assert!(res.attributes.contains(&attr("return_amount", "990")));
+    assert!(res.attributes.contains(&attr("commission_amount", "110")));
+
+    // Entering staking is not affected
+    let simulation: SimulationResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Simulation {
+                offer_asset: Asset::native(ORO, 1000u128),
+                ask_asset_info: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(simulation.return_amount, Uint128::new(909));
+    assert_eq!(simulation.commission_amount, Uint128::zero());
+
+    // 1100 ORO must be withdrawn to receive 990 ORO after the fee
+    let reverse: ReverseSimulationResponse = from_json(
+        query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ReverseSimulation {
+                offer_asset_info: None,
+                ask_asset: Asset::native(ORO, 990u128),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(reverse.offer_amount, Uint128::new(1000));
+    assert_eq!(reverse.commission_amount, Uint128::new(110));
+}
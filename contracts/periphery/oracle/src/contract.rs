@@ -0,0 +1,264 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, to_json_binary, Binary, Decimal256, Deps, DepsMut, Env, MessageInfo, Response, StdError,
+    StdResult, Uint128, Uint256,
+};
+use cw2::{get_contract_version, set_contract_version};
+
+use oroswap::asset::AssetInfo;
+use oroswap::oracle::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use oroswap::pair::TWAP_PRECISION;
+use oroswap::querier::query_pair_info;
+
+use crate::error::ContractError;
+use crate::querier::query_cumulative_prices;
+use crate::state::{Config, PriceCumulativeLast, CONFIG, PRICE_LAST};
+
+/// Contract name that is used for migration.
+const CONTRACT_NAME: &str = "oroswap-oracle";
+/// Contract version that is used for migration.
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Default time between two consecutive TWAP updates.
+pub const PERIOD: u64 = 86400;
+/// The number of periods after which the latest TWAP is considered stale.
+pub const MAX_AGE_PERIODS: u64 = 2;
+
+/// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    for asset_info in &msg.asset_infos {
+        asset_info.check(deps.api)?;
+    }
+
+    let period = msg.period.unwrap_or(PERIOD);
+    if period == 0 {
+        return Err(ContractError::InvalidPeriod {});
+    }
+
+    let factory = deps.api.addr_validate(&msg.factory_contract)?;
+    let pair_info = query_pair_info(
+        &deps.querier,
+        &factory,
+        &msg.asset_infos,
+        msg.pair_type.clone(),
+    )?;
+
+    let config = Config {
+        owner: info.sender,
+        factory,
+        asset_infos: msg.asset_infos,
+        pair: pair_info.clone(),
+        period,
+    };
+    CONFIG.save(deps.storage, &config)?;
+
+    let prices = query_cumulative_prices(deps.querier, &pair_info.contract_addr)?;
+    let average_prices = prices
+        .cumulative_prices
+        .iter()
+        .cloned()
+        .map(|(from, to, _)| (from, to, Decimal256::zero()))
+        .collect();
+
+    PRICE_LAST.save(
+        deps.storage,
+        &PriceCumulativeLast {
+            cumulative_prices: prices.cumulative_prices,
+            average_prices,
+            block_timestamp_last: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "instantiate"),
+        attr("pair", pair_info.contract_addr),
+        attr("pair_type", msg.pair_type.to_string()),
+    ]))
+}
+
+/// Exposes all the execute functions available in the contract.
+///
+/// ## Variants
+/// * **ExecuteMsg::Update {}** Updates the local TWAP values for the tokens in the target Oroswap pool.
+///
+/// * **ExecuteMsg::UpdateConfig { period }** Updates the contract configuration.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Update {} => update(deps, env),
+        ExecuteMsg::UpdateConfig { period } => update_config(deps, info, period),
+    }
+}
+
+/// Updates the contract configuration.
+///
+/// * **period** minimum time in seconds between two consecutive TWAP updates.
+///
+/// ## Executor
+/// Only the owner can execute this.
+pub fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    period: Option<u64>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attributes = vec![attr("action", "update_config")];
+
+    if let Some(period) = period {
+        if period == 0 {
+            return Err(ContractError::InvalidPeriod {});
+        }
+        config.period = period;
+        attributes.push(attr("period", period.to_string()));
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(attributes))
+}
+
+/// Updates the local TWAP values for the assets in the target Oroswap pool.
+/// The average prices are computed over the window between the previous snapshot and now.
+///
+/// The update is rejected if less than the configured period passed since the previous snapshot.
+pub fn update(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let price_last = PRICE_LAST.load(deps.storage)?;
+
+    let prices = query_cumulative_prices(deps.querier, &config.pair.contract_addr)?;
+    let time_elapsed = env
+        .block
+        .time
+        .seconds()
+        .saturating_sub(price_last.block_timestamp_last);
+
+    // Ensure that at least one full period has passed since the last update
+    if time_elapsed < config.period {
+        return Err(ContractError::WrongPeriod {});
+    }
+
+    let mut average_prices = vec![];
+    for (from_last, to_last, value_last) in price_last.cumulative_prices.iter() {
+        for (from, to, value) in prices.cumulative_prices.iter() {
+            if from.equal(from_last) && to.equal(to_last) {
+                // Cumulative prices can overflow, so the difference is computed with wrapping
+                average_prices.push((
+                    from.clone(),
+                    to.clone(),
+                    Decimal256::from_ratio(value.wrapping_sub(*value_last), time_elapsed),
+                ));
+            }
+        }
+    }
+
+    PRICE_LAST.save(
+        deps.storage,
+        &PriceCumulativeLast {
+            cumulative_prices: prices.cumulative_prices,
+            average_prices,
+            block_timestamp_last: env.block.time.seconds(),
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "update"),
+        attr("time_elapsed", time_elapsed.to_string()),
+    ]))
+}
+
+/// Exposes all the queries available in the contract.
+///
+/// ## Queries
+/// * **QueryMsg::Consult { token, amount }** Validates assets and calculates a new average
+/// amount with updated precision
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Consult { token, amount } => to_json_binary(&consult(deps, env, token, amount)?),
+    }
+}
+
+/// Multiplies a token amount by its latest TWAP value.
+/// Fails if no TWAP was computed yet or if the latest TWAP is older than
+/// [`MAX_AGE_PERIODS`] periods.
+///
+/// * **token** token for which we multiply its TWAP value by an amount.
+///
+/// * **amount** amount of tokens we multiply the TWAP by.
+fn consult(
+    deps: Deps,
+    env: Env,
+    token: AssetInfo,
+    amount: Uint128,
+) -> StdResult<Vec<(AssetInfo, Uint256)>> {
+    let config = CONFIG.load(deps.storage)?;
+    let price_last = PRICE_LAST.load(deps.storage)?;
+
+    let age = env
+        .block
+        .time
+        .seconds()
+        .saturating_sub(price_last.block_timestamp_last);
+    if age > config.period.saturating_mul(MAX_AGE_PERIODS) {
+        return Err(StdError::generic_err("The latest TWAP is stale"));
+    }
+
+    let average_prices: Vec<_> = price_last
+        .average_prices
+        .into_iter()
+        .filter(|(from, _, _)| from.equal(&token))
+        .map(|(_, to, value)| (to, value))
+        .collect();
+
+    if average_prices.is_empty() {
+        return Err(StdError::generic_err("Invalid Token"));
+    }
+
+    let price_precision = Uint256::from(10_u128.pow(TWAP_PRECISION.into()));
+
+    average_prices
+        .into_iter()
+        .map(|(asset, price_average)| {
+            if price_average.is_zero() {
+                return Err(StdError::generic_err("No TWAP was computed yet"));
+            }
+
+            Ok((
+                asset,
+                Uint256::from(amount) * price_average / price_precision,
+            ))
+        })
+        .collect()
+}
+
+/// Manages contract migration.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract_version = get_contract_version(deps.storage)?;
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_attribute("previous_contract_name", &contract_version.contract)
+        .add_attribute("previous_contract_version", &contract_version.version)
+        .add_attribute("new_contract_name", CONTRACT_NAME)
+        .add_attribute("new_contract_version", CONTRACT_VERSION))
+}
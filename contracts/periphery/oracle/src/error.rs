@@ -0,0 +1,18 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+/// This enum describes oracle contract errors
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Period not elapsed")]
+    WrongPeriod {},
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Period must be greater than zero")]
+    InvalidPeriod {},
+}
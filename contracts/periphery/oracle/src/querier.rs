@@ -0,0 +1,13 @@
+use cosmwasm_std::{QuerierWrapper, StdResult};
+
+use oroswap::pair::{CumulativePricesResponse, QueryMsg as PairQueryMsg};
+
+/// Returns information about the cumulative prices of the assets in a pair.
+///
+/// * **pair_contract** address of the pair for which we query cumulative prices.
+pub fn query_cumulative_prices(
+    querier: QuerierWrapper,
+    pair_contract: impl Into<String>,
+) -> StdResult<CumulativePricesResponse> {
+    querier.query_wasm_smart(pair_contract, &PairQueryMsg::CumulativePrices {})
+}
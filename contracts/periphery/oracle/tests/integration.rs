@@ -0,0 +1,545 @@
+#![cfg(not(tarpaulin_include))]
+
+use cosmwasm_std::{coin, coins, to_json_binary, Addr, Binary, Decimal, Uint128, Uint256};
+
+use oroswap::asset::{Asset, AssetInfo, PairInfo};
+use oroswap::factory::{
+    ExecuteMsg as FactoryExecuteMsg, PairConfig, PairType, QueryMsg as FactoryQueryMsg,
+};
+use oroswap::oracle::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use oroswap::pair::{ExecuteMsg as PairExecuteMsg, StablePoolParams};
+use oroswap::pair_concentrated::ConcentratedPoolParams;
+use oroswap_oracle::contract::PERIOD;
+use oroswap_oracle::error::ContractError;
+use oroswap_test::convert::f64_to_dec;
+use oroswap_test::cw_multi_test::{AppBuilder, ContractWrapper, Executor};
+use oroswap_test::modules::stargate::{MockStargate, StargateApp as App};
+
+const OWNER: &str = "owner";
+
+struct Helper {
+    app: App,
+    owner: Addr,
+    factory: Addr,
+    oracle_code_id: u64,
+}
+
+impl Helper {
+    fn new() -> Self {
+        let owner = Addr::unchecked(OWNER);
+        let mut app = AppBuilder::new()
+            .with_stargate(MockStargate::default())
+            .build(|router, _, storage| {
+                router
+                    .bank
+                    .init_balance(
+                        storage,
+                        &owner,
+                        vec![
+                            coin(10_000_000_000, "uluna"),
+                            coin(10_000_000_000, "uusd"),
+                            coin(10_000, "uzig"),
+                        ],
+                    )
+                    .unwrap()
+            });
+
+        let token_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            cw20_base::contract::execute,
+            cw20_base::contract::instantiate,
+            cw20_base::contract::query,
+        )));
+
+        let pair_code_id = app.store_code(Box::new(
+            ContractWrapper::new_with_empty(
+                oroswap_pair::contract::execute,
+                oroswap_pair::contract::instantiate,
+                oroswap_pair::contract::query,
+            )
+            .with_reply_empty(oroswap_pair::contract::reply),
+        ));
+
+        let stable_code_id = app.store_code(Box::new(
+            ContractWrapper::new_with_empty(
+                oroswap_pair_stable::contract::execute,
+                oroswap_pair_stable::contract::instantiate,
+                oroswap_pair_stable::contract::query,
+            )
+            .with_reply_empty(oroswap_pair_stable::contract::reply),
+        ));
+
+        let pcl_code_id = app.store_code(Box::new(
+            ContractWrapper::new_with_empty(
+                oroswap_pair_concentrated::contract::execute,
+                oroswap_pair_concentrated::contract::instantiate,
+                oroswap_pair_concentrated::queries::query,
+            )
+            .with_reply_empty(oroswap_pair_concentrated::contract::reply),
+        ));
+
+        let coin_registry_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            oroswap_native_coin_registry::contract::execute,
+            oroswap_native_coin_registry::contract::instantiate,
+            oroswap_native_coin_registry::contract::query,
+        )));
+
+        let factory_code_id = app.store_code(Box::new(
+            ContractWrapper::new_with_empty(
+                oroswap_factory::contract::execute,
+                oroswap_factory::contract::instantiate,
+                oroswap_factory::contract::query,
+            )
+            .with_reply_empty(oroswap_factory::contract::reply),
+        ));
+
+        let oracle_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            oroswap_oracle::contract::execute,
+            oroswap_oracle::contract::instantiate,
+            oroswap_oracle::contract::query,
+        )));
+
+        let coin_registry = app
+            .instantiate_contract(
+                coin_registry_code_id,
+                owner.clone(),
+                &oroswap::native_coin_registry::InstantiateMsg {
+                    owner: owner.to_string(),
+                },
+                &[],
+                "coin_registry",
+                None,
+            )
+            .unwrap();
+
+        app.execute_contract(
+            owner.clone(),
+            coin_registry.clone(),
+            &oroswap::native_coin_registry::ExecuteMsg::Add {
+                native_coins: vec![("uluna".to_string(), 6), ("uusd".to_string(), 6)],
+            },
+            &[],
+        )
+        .unwrap();
+
+        let pair_config = |code_id: u64, pair_type: PairType| PairConfig {
+            code_id,
+            pair_type,
+            total_fee_bps: 0,
+            maker_fee_bps: 0,
+            is_disabled: false,
+            is_generator_disabled: false,
+            permissioned: false,
+            pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
+        };
+
+        let factory = app
+            .instantiate_contract(
+                factory_code_id,
+                owner.clone(),
+                &oroswap::factory::InstantiateMsg {
+                    pair_configs: vec![
+                        pair_config(pair_code_id, PairType::Xyk {}),
+                        pair_config(stable_code_id, PairType::Stable {}),
+                        pair_config(pcl_code_id, PairType::Custom("concentrated".to_string())),
+                    ],
+                    token_code_id,
+                    fee_address: Some("maker".to_string()),
+                    generator_address: None,
+                    owner: owner.to_string(),
+                    whitelist_code_id: 0,
+                    coin_registry_address: coin_registry.to_string(),
+                    tracker_config: None,
+                },
+                &[],
+                "factory",
+                None,
+            )
+            .unwrap();
+
+        Self {
+            app,
+            owner,
+            factory,
+            oracle_code_id,
+        }
+    }
+
+    fn asset_infos() -> Vec<AssetInfo> {
+        vec![AssetInfo::native("uusd"), AssetInfo::native("uluna")]
+    }
+
+    /// Creates a pair of the given type and provides 1000 uusd and 1000 uluna to it.
+    fn create_pair(&mut self, pair_type: PairType, init_params: Option<Binary>) -> Addr {
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                self.factory.clone(),
+                &FactoryExecuteMsg::CreatePair {
+                    pair_type: pair_type.clone(),
+                    asset_infos: Self::asset_infos(),
+                    init_params,
+                    fee_tier: None,
+                },
+                &[coin(1000, "uzig")],
+            )
+            .unwrap();
+
+        let pair_info: PairInfo = self
+            .app
+            .wrap()
+            .query_wasm_smart(
+                &self.factory,
+                &FactoryQueryMsg::Pair {
+                    asset_infos: Self::asset_infos(),
+                    pair_type,
+                    period: None,
+                },
+            )
+            .unwrap();
+
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                pair_info.contract_addr.clone(),
+                &PairExecuteMsg::ProvideLiquidity {
+                    assets: vec![
+                        Asset::native("uusd", 1_000_000_000u128),
+                        Asset::native("uluna", 1_000_000_000u128),
+                    ],
+                    slippage_tolerance: None,
+                    auto_stake: None,
+                    receiver: None,
+                    min_lp_to_receive: None,
+                },
+                &[coin(1_000_000_000, "uluna"), coin(1_000_000_000, "uusd")],
+            )
+            .unwrap();
+
+        pair_info.contract_addr
+    }
+
+    fn instantiate_oracle(&mut self, pair_type: PairType) -> Addr {
+        self.app
+            .instantiate_contract(
+                self.oracle_code_id,
+                self.owner.clone(),
+                &InstantiateMsg {
+                    factory_contract: self.factory.to_string(),
+                    asset_infos: Self::asset_infos(),
+                    pair_type,
+                    period: None,
+                },
+                &[],
+                "oracle",
+                None,
+            )
+            .unwrap()
+    }
+
+    fn swap(&mut self, pair: &Addr, denom: &str, amount: u128) {
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                pair.clone(),
+                &PairExecuteMsg::Swap {
+                    offer_asset: Asset::native(denom, amount),
+                    ask_asset_info: None,
+                    belief_price: None,
+                    max_spread: Some(Decimal::percent(50)),
+                    to: None,
+                },
+                &coins(amount, denom),
+            )
+            .unwrap();
+    }
+
+    fn update(&mut self, oracle: &Addr) -> anyhow::Result<()> {
+        self.app
+            .execute_contract(
+                self.owner.clone(),
+                oracle.clone(),
+                &ExecuteMsg::Update {},
+                &[],
+            )
+            .map(|_| ())
+    }
+
+    fn consult(&self, oracle: &Addr, denom: &str, amount: u128) -> Vec<(AssetInfo, Uint256)> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                oracle,
+                &QueryMsg::Consult {
+                    token: AssetInfo::native(denom),
+                    amount: amount.into(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn next_period(&mut self) {
+        self.app.update_block(|block| {
+            block.time = block.time.plus_seconds(PERIOD);
+            block.height += 1;
+        });
+    }
+}
+
+#[test]
+fn test_oracle_xyk() {
+    let mut helper = Helper::new();
+    let pair = helper.create_pair(PairType::Xyk {}, None);
+    let oracle = helper.instantiate_oracle(PairType::Xyk {});
+
+    // No TWAP has been computed yet
+    let err = helper
+        .app
+        .wrap()
+        .query_wasm_smart::<Vec<(AssetInfo, Uint256)>>(
+            &oracle,
+            &QueryMsg::Consult {
+                token: AssetInfo::native("uusd"),
+                amount: Uint128::new(1_000_000),
+            },
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("No TWAP was computed yet"));
+
+    let err = helper
+        .app
+        .wrap()
+        .query_wasm_smart::<Vec<(AssetInfo, Uint256)>>(
+            &oracle,
+            &QueryMsg::Consult {
+                token: AssetInfo::native("ucny"),
+                amount: Uint128::new(1_000_000),
+            },
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("Invalid Token"));
+
+    // Updates within the same period are rejected
+    let err = helper.update(&oracle).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::WrongPeriod {}
+    );
+
+    helper.next_period();
+    helper.update(&oracle).unwrap();
+
+    assert_eq!(
+        helper.consult(&oracle, "uusd", 1_000_000),
+        vec![(AssetInfo::native("uluna"), Uint256::from(1_000_000u128))]
+    );
+
+    // Pool becomes 1100 uusd and 909.090910 uluna
+    helper.swap(&pair, "uusd", 100_000_000);
+
+    let err = helper.update(&oracle).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::WrongPeriod {}
+    );
+
+    helper.next_period();
+    helper.update(&oracle).unwrap();
+
+    assert_eq!(
+        helper.consult(&oracle, "uusd", 1_000_000),
+        vec![(AssetInfo::native("uluna"), Uint256::from(826446u128))]
+    );
+    assert_eq!(
+        helper.consult(&oracle, "uluna", 1_000_000),
+        vec![(AssetInfo::native("uusd"), Uint256::from(1209999u128))]
+    );
+}
+
+#[test]
+fn test_oracle_stable() {
+    let mut helper = Helper::new();
+    let pair = helper.create_pair(
+        PairType::Stable {},
+        Some(
+            to_json_binary(&StablePoolParams {
+                amp: 100,
+                owner: None,
+            })
+            .unwrap(),
+        ),
+    );
+    let oracle = helper.instantiate_oracle(PairType::Stable {});
+
+    let err = helper.update(&oracle).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::WrongPeriod {}
+    );
+
+    helper.next_period();
+    helper.update(&oracle).unwrap();
+
+    let (asset, price_before) = helper.consult(&oracle, "uusd", 1_000_000)[0].clone();
+    assert_eq!(asset, AssetInfo::native("uluna"));
+    assert!(
+        price_before >= Uint256::from(999_000u128) && price_before <= Uint256::from(1_000_000u128),
+        "unexpected price {price_before}"
+    );
+
+    helper.swap(&pair, "uusd", 500_000_000);
+    helper.next_period();
+    helper.update(&oracle).unwrap();
+
+    // uusd became cheaper relative to uluna
+    let (_, uusd_price) = helper.consult(&oracle, "uusd", 1_000_000)[0].clone();
+    let (_, uluna_price) = helper.consult(&oracle, "uluna", 1_000_000)[0].clone();
+    assert!(uusd_price < price_before, "unexpected price {uusd_price}");
+    assert!(uluna_price > price_before, "unexpected price {uluna_price}");
+}
+
+#[test]
+fn test_oracle_pcl() {
+    let mut helper = Helper::new();
+    let pair_type = PairType::Custom("concentrated".to_string());
+    let pair = helper.create_pair(
+        pair_type.clone(),
+        Some(
+            to_json_binary(&ConcentratedPoolParams {
+                amp: f64_to_dec(40f64),
+                gamma: f64_to_dec(0.000145),
+                mid_fee: f64_to_dec(0.0026),
+                out_fee: f64_to_dec(0.0045),
+                fee_gamma: f64_to_dec(0.00023),
+                repeg_profit_threshold: f64_to_dec(0.000002),
+                min_price_scale_delta: f64_to_dec(0.000146),
+                price_scale: Decimal::one(),
+                ma_half_time: 600,
+                track_asset_balances: None,
+                fee_share: None,
+            })
+            .unwrap(),
+        ),
+    );
+    let oracle = helper.instantiate_oracle(pair_type);
+
+    let err = helper.update(&oracle).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::WrongPeriod {}
+    );
+
+    helper.next_period();
+    helper.update(&oracle).unwrap();
+
+    let (asset, price_before) = helper.consult(&oracle, "uusd", 1_000_000)[0].clone();
+    assert_eq!(asset, AssetInfo::native("uluna"));
+    assert!(
+        price_before >= Uint256::from(999_000u128) && price_before <= Uint256::from(1_000_000u128),
+        "unexpected price {price_before}"
+    );
+
+    helper.swap(&pair, "uusd", 100_000_000);
+    helper.next_period();
+    helper.update(&oracle).unwrap();
+
+    // uusd became cheaper relative to uluna
+    let (_, uusd_price) = helper.consult(&oracle, "uusd", 1_000_000)[0].clone();
+    let (_, uluna_price) = helper.consult(&oracle, "uluna", 1_000_000)[0].clone();
+    assert!(uusd_price < price_before, "unexpected price {uusd_price}");
+    assert!(uluna_price > price_before, "unexpected price {uluna_price}");
+}
+
+#[test]
+fn test_oracle_stale_twap() {
+    let mut helper = Helper::new();
+    helper.create_pair(PairType::Xyk {}, None);
+    let oracle = helper.instantiate_oracle(PairType::Xyk {});
+
+    helper.next_period();
+    helper.update(&oracle).unwrap();
+    helper.next_period();
+    helper.next_period();
+    assert_eq!(
+        helper.consult(&oracle, "uusd", 1_000_000),
+        vec![(AssetInfo::native("uluna"), Uint256::from(1_000_000u128))]
+    );
+
+    // The TWAP is rejected once more than two periods passed since the last update
+    helper
+        .app
+        .update_block(|block| block.time = block.time.plus_seconds(1));
+    let err = helper
+        .app
+        .wrap()
+        .query_wasm_smart::<Vec<(AssetInfo, Uint256)>>(
+            &oracle,
+            &QueryMsg::Consult {
+                token: AssetInfo::native("uusd"),
+                amount: Uint128::new(1_000_000),
+            },
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("The latest TWAP is stale"));
+
+    helper.update(&oracle).unwrap();
+    assert_eq!(
+        helper.consult(&oracle, "uusd", 1_000_000),
+        vec![(AssetInfo::native("uluna"), Uint256::from(1_000_000u128))]
+    );
+}
+
+#[test]
+fn test_oracle_update_config() {
+    let mut helper = Helper::new();
+    helper.create_pair(PairType::Xyk {}, None);
+    let oracle = helper.instantiate_oracle(PairType::Xyk {});
+
+    let err = helper
+        .app
+        .execute_contract(
+            Addr::unchecked("random"),
+            oracle.clone(),
+            &ExecuteMsg::UpdateConfig {
+                period: Some(PERIOD / 2),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    let err = helper
+        .app
+        .execute_contract(
+            helper.owner.clone(),
+            oracle.clone(),
+            &ExecuteMsg::UpdateConfig { period: Some(0) },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidPeriod {}
+    );
+
+    helper
+        .app
+        .execute_contract(
+            helper.owner.clone(),
+            oracle.clone(),
+            &ExecuteMsg::UpdateConfig {
+                period: Some(PERIOD / 2),
+            },
+            &[],
+        )
+        .unwrap();
+
+    helper.app.update_block(|block| {
+        block.time = block.time.plus_seconds(PERIOD / 2);
+        block.height += 1;
+    });
+    helper.update(&oracle).unwrap();
+}
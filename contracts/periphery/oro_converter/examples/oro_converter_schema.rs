@@ -0,0 +1,11 @@
+use cosmwasm_schema::write_api;
+
+use oroswap::oro_converter::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        query: QueryMsg,
+        execute: ExecuteMsg,
+    }
+}
@@ -0,0 +1,249 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, coin, ensure, from_json, to_json_binary, wasm_execute, Addr, BankMsg, Binary, CosmosMsg,
+    Deps, DepsMut, Env, IbcMsg, IbcTimeout, MessageInfo, Response, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, Cw20ReceiveMsg};
+use cw_utils::must_pay;
+
+use oroswap::asset::{validate_native_denom, AssetInfo};
+use oroswap::oro_converter::{
+    Config, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, DEFAULT_TIMEOUT, TIMEOUT_LIMITS,
+};
+use oroswap::querier::query_balance;
+use oroswap::token_factory::tf_mint_msg;
+
+use crate::error::ContractError;
+use crate::state::CONFIG;
+
+/// Contract name that is used for migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+/// Contract version that is used for migration.
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    msg.old_oro_asset_info.check(deps.api)?;
+    validate_native_denom(&msg.new_oro_denom)?;
+
+    // Old Hub holds cw20 ORO and burns it locally; outposts hold IBCed ORO and send it back to the Hub
+    let is_outpost = msg.old_oro_asset_info.is_native_token();
+    ensure!(
+        is_outpost == msg.outpost_burn_params.is_some(),
+        ContractError::InvalidOutpostBurnParams {}
+    );
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            old_oro_asset_info: msg.old_oro_asset_info,
+            new_oro_denom: msg.new_oro_denom,
+            outpost_burn_params: msg.outpost_burn_params,
+        },
+    )?;
+
+    Ok(Response::default())
+}
+
+/// Exposes all the execute functions available in the contract.
+///
+/// ## Variants
+/// * **ExecuteMsg::Convert { receiver }** Converts native old ORO to new ORO.
+///
+/// * **ExecuteMsg::Receive(msg)** Receives a message of type [`Cw20ReceiveMsg`] and converts
+/// cw20 old ORO to new ORO.
+///
+/// * **ExecuteMsg::TransferForBurning { timeout }** Sends all collected old ORO to the old Hub
+/// over IBC so it can be burned there.
+///
+/// * **ExecuteMsg::Burn {}** Burns all collected cw20 old ORO.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    match msg {
+        ExecuteMsg::Convert { receiver } => convert(deps, env, info, config, receiver),
+        ExecuteMsg::Receive(cw20_msg) => receive_cw20(deps, env, info, config, cw20_msg),
+        ExecuteMsg::TransferForBurning { timeout } => {
+            transfer_for_burning(deps, env, config, timeout)
+        }
+        ExecuteMsg::Burn {} => burn(deps, env, config),
+    }
+}
+
+/// Converts native old ORO sent along with the message to new ORO.
+pub fn convert(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: Config,
+    receiver: Option<String>,
+) -> Result<Response, ContractError> {
+    let old_oro_denom = match &config.old_oro_asset_info {
+        AssetInfo::NativeToken { denom } => denom,
+        AssetInfo::Token { .. } => {
+            return Err(ContractError::InvalidEndpoint {
+                old_oro: config.old_oro_asset_info.to_string(),
+            })
+        }
+    };
+
+    let amount = must_pay(&info, old_oro_denom)?;
+    let receiver = receiver
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?
+        .unwrap_or(info.sender);
+
+    convert_to_new_oro(&env, &config, receiver, amount)
+}
+
+/// Converts cw20 old ORO received via [`Cw20ReceiveMsg`] to new ORO.
+pub fn receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    config: Config,
+    cw20_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    match &config.old_oro_asset_info {
+        AssetInfo::Token { contract_addr } => {
+            ensure!(info.sender == contract_addr, ContractError::Unauthorized {})
+        }
+        AssetInfo::NativeToken { .. } => {
+            return Err(ContractError::InvalidEndpoint {
+                old_oro: config.old_oro_asset_info.to_string(),
+            })
+        }
+    }
+
+    let Cw20HookMsg { receiver } = from_json(&cw20_msg.msg)?;
+    let receiver = deps
+        .api
+        .addr_validate(&receiver.unwrap_or(cw20_msg.sender))?;
+
+    convert_to_new_oro(&env, &config, receiver, cw20_msg.amount)
+}
+
+/// Sends new ORO to the `receiver` 1:1 for the converted old ORO.
+/// If the converter is the admin of the new ORO token factory denom then new ORO is minted,
+/// otherwise it is released from the contract balance which must be prefunded.
+fn convert_to_new_oro(
+    env: &Env,
+    config: &Config,
+    receiver: Addr,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    let new_oro = coin(amount.u128(), &config.new_oro_denom);
+
+    let messages: Vec<CosmosMsg> = if config
+        .new_oro_denom
+        .starts_with(&format!("coin.{}.", env.contract.address))
+    {
+        tf_mint_msg(&env.contract.address, new_oro, &receiver)
+    } else {
+        vec![BankMsg::Send {
+            to_address: receiver.to_string(),
+            amount: vec![new_oro],
+        }
+        .into()]
+    };
+
+    Ok(Response::new().add_messages(messages).add_attributes([
+        attr("action", "convert"),
+        attr("receiver", receiver),
+        attr("amount", amount),
+    ]))
+}
+
+/// Sends the whole old ORO balance of the contract to the burn address on the old Hub.
+/// Only available on outposts.
+pub fn transfer_for_burning(
+    deps: DepsMut,
+    env: Env,
+    config: Config,
+    timeout: Option<u64>,
+) -> Result<Response, ContractError> {
+    let (old_oro_denom, burn_params) =
+        match (&config.old_oro_asset_info, &config.outpost_burn_params) {
+            (AssetInfo::NativeToken { denom }, Some(burn_params)) => (denom, burn_params),
+            _ => {
+                return Err(ContractError::InvalidEndpoint {
+                    old_oro: config.old_oro_asset_info.to_string(),
+                })
+            }
+        };
+
+    let timeout = timeout.unwrap_or(DEFAULT_TIMEOUT);
+    ensure!(
+        TIMEOUT_LIMITS.contains(&timeout),
+        ContractError::InvalidTimeout {}
+    );
+
+    let amount = query_balance(&deps.querier, &env.contract.address, old_oro_denom)?;
+    ensure!(!amount.is_zero(), ContractError::NothingToBurn {});
+
+    let transfer_msg = IbcMsg::Transfer {
+        channel_id: burn_params.old_oro_transfer_channel.clone(),
+        to_address: burn_params.terra_burn_addr.clone(),
+        amount: coin(amount.u128(), old_oro_denom),
+        timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(timeout)),
+    };
+
+    Ok(Response::new().add_message(transfer_msg).add_attributes([
+        attr("action", "transfer_for_burning"),
+        attr("channel", &burn_params.old_oro_transfer_channel),
+        attr("amount", amount),
+    ]))
+}
+
+/// Burns the whole cw20 old ORO balance of the contract. Only available on the old Hub.
+pub fn burn(deps: DepsMut, env: Env, config: Config) -> Result<Response, ContractError> {
+    let contract_addr = match &config.old_oro_asset_info {
+        AssetInfo::Token { contract_addr } => contract_addr,
+        AssetInfo::NativeToken { .. } => {
+            return Err(ContractError::InvalidEndpoint {
+                old_oro: config.old_oro_asset_info.to_string(),
+            })
+        }
+    };
+
+    let BalanceResponse { balance: amount } = deps.querier.query_wasm_smart(
+        contract_addr,
+        &Cw20QueryMsg::Balance {
+            address: env.contract.address.to_string(),
+        },
+    )?;
+    ensure!(!amount.is_zero(), ContractError::NothingToBurn {});
+
+    let burn_msg = wasm_execute(contract_addr, &Cw20ExecuteMsg::Burn { amount }, vec![])?;
+
+    Ok(Response::new()
+        .add_message(burn_msg)
+        .add_attributes([attr("action", "burn"), attr("amount", amount)]))
+}
+
+/// Exposes all the queries available in the contract.
+///
+/// ## Queries
+/// * **QueryMsg::Config {}** Returns the contract configuration in a [`Config`] object.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+    }
+}
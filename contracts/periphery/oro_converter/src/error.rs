@@ -0,0 +1,30 @@
+use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+use oroswap::oro_converter::TIMEOUT_LIMITS;
+
+/// This enum describes ORO converter contract errors
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Outpost burn params must be set if and only if old ORO is a native coin")]
+    InvalidOutpostBurnParams {},
+
+    #[error("This endpoint is not available for {old_oro}")]
+    InvalidEndpoint { old_oro: String },
+
+    #[error("Timeout must be between {} and {} seconds", TIMEOUT_LIMITS.start(), TIMEOUT_LIMITS.end())]
+    InvalidTimeout {},
+
+    #[error("The contract doesn't hold any old ORO")]
+    NothingToBurn {},
+}
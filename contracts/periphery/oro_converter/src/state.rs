@@ -0,0 +1,6 @@
+use cw_storage_plus::Item;
+
+use oroswap::oro_converter::Config;
+
+/// Stores the contract config at the given key
+pub const CONFIG: Item<Config> = Item::new("config");
@@ -0,0 +1,535 @@
+#![cfg(not(tarpaulin_include))]
+
+use std::fmt::Debug;
+
+use anyhow::Result as AnyResult;
+use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{
+    coin, coins, to_json_binary, Addr, Api, BankMsg, Binary, BlockInfo, CustomQuery, Empty, Event,
+    GovMsg, IbcMsg, IbcQuery, MemoryStorage, Querier, Storage, Uint128,
+};
+use cw20::{BalanceResponse, Cw20Coin, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
+use cw_multi_test::{
+    App, AppBuilder, AppResponse, BankKeeper, ContractWrapper, CosmosRouter, DistributionKeeper,
+    Executor, FailingModule, Ibc, Module, StakeKeeper, StargateFailingModule, WasmKeeper,
+};
+
+use oroswap::asset::AssetInfo;
+use oroswap::oro_converter::{
+    Config, Cw20HookMsg, ExecuteMsg, InstantiateMsg, OutpostBurnParams, QueryMsg,
+};
+use oroswap_oro_converter::error::ContractError;
+
+const OWNER: &str = "owner";
+const USER: &str = "user";
+const OLD_ORO: &str = "ibc/old_oro";
+const NEW_ORO: &str = "uoro";
+const CHANNEL: &str = "channel-1";
+const BURN_ADDR: &str = "terra1burn";
+/// Address holding funds sent through the IBC stand-in
+const IBC_ESCROW: &str = "ibc_escrow";
+
+/// Local stand-in for ICS20 transfers. It escrows the transferred coins and emits an event
+/// describing the packet so tests can check what would have been sent over IBC.
+#[derive(Default)]
+struct IbcTransferKeeper {}
+
+impl Module for IbcTransferKeeper {
+    type ExecT = IbcMsg;
+    type QueryT = IbcQuery;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: Self::ExecT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            IbcMsg::Transfer {
+                channel_id,
+                to_address,
+                amount,
+                timeout,
+            } => {
+                let timeout = timeout
+                    .timestamp()
+                    .ok_or_else(|| anyhow::anyhow!("Timestamp timeout is required"))?;
+                let escrow_msg = BankMsg::Send {
+                    to_address: IBC_ESCROW.to_string(),
+                    amount: vec![amount.clone()],
+                };
+                router.execute(api, storage, block, sender, escrow_msg.into())?;
+
+                Ok(AppResponse {
+                    events: vec![Event::new("ibc_transfer").add_attributes([
+                        ("channel", channel_id),
+                        ("receiver", to_address),
+                        ("amount", amount.to_string()),
+                        ("timeout", timeout.seconds().to_string()),
+                    ])],
+                    data: None,
+                })
+            }
+            _ => Err(anyhow::anyhow!("Unexpected IBC msg {msg:?}")),
+        }
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        _querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: Self::QueryT,
+    ) -> AnyResult<Binary> {
+        Err(anyhow::anyhow!("Unexpected IBC query {request:?}"))
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        msg: Self::SudoT,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        Err(anyhow::anyhow!("Unexpected IBC sudo {msg:?}"))
+    }
+}
+
+impl Ibc for IbcTransferKeeper {}
+
+type ConverterApp = App<
+    BankKeeper,
+    MockApi,
+    MemoryStorage,
+    FailingModule<Empty, Empty, Empty>,
+    WasmKeeper<Empty, Empty>,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcTransferKeeper,
+    FailingModule<GovMsg, Empty, Empty>,
+    StargateFailingModule,
+>;
+
+fn mock_app() -> ConverterApp {
+    AppBuilder::new()
+        .with_ibc(IbcTransferKeeper::default())
+        .build(|router, _, storage| {
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked(USER), coins(1_000, OLD_ORO))
+                .unwrap();
+            router
+                .bank
+                .init_balance(storage, &Addr::unchecked(OWNER), coins(10_000, NEW_ORO))
+                .unwrap();
+        })
+}
+
+fn instantiate_converter(
+    app: &mut ConverterApp,
+    old_oro_asset_info: AssetInfo,
+    outpost_burn_params: Option<OutpostBurnParams>,
+) -> AnyResult<Addr> {
+    let code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+        oroswap_oro_converter::contract::execute,
+        oroswap_oro_converter::contract::instantiate,
+        oroswap_oro_converter::contract::query,
+    )));
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(OWNER),
+        &InstantiateMsg {
+            old_oro_asset_info,
+            new_oro_denom: NEW_ORO.to_string(),
+            outpost_burn_params,
+        },
+        &[],
+        "ORO converter",
+        None,
+    )
+}
+
+fn instantiate_old_cw20_oro(app: &mut ConverterApp) -> Addr {
+    let code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    )));
+
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(OWNER),
+        &cw20_base::msg::InstantiateMsg {
+            name: "Old ORO".to_string(),
+            symbol: "ORO".to_string(),
+            decimals: 6,
+            initial_balances: vec![Cw20Coin {
+                address: USER.to_string(),
+                amount: Uint128::new(1_000),
+            }],
+            mint: Some(MinterResponse {
+                minter: OWNER.to_string(),
+                cap: None,
+            }),
+            marketing: None,
+        },
+        &[],
+        "Old ORO",
+        None,
+    )
+    .unwrap()
+}
+
+fn outpost_burn_params() -> OutpostBurnParams {
+    OutpostBurnParams {
+        terra_burn_addr: BURN_ADDR.to_string(),
+        old_oro_transfer_channel: CHANNEL.to_string(),
+    }
+}
+
+fn balance(app: &ConverterApp, address: impl Into<String>, denom: &str) -> u128 {
+    app.wrap()
+        .query_balance(address, denom)
+        .unwrap()
+        .amount
+        .u128()
+}
+
+#[test]
+fn test_instantiate_validation() {
+    let mut app = mock_app();
+    let old_cw20_oro = instantiate_old_cw20_oro(&mut app);
+
+    let err = instantiate_converter(
+        &mut app,
+        AssetInfo::NativeToken {
+            denom: OLD_ORO.to_string(),
+        },
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidOutpostBurnParams {}
+    );
+
+    let err = instantiate_converter(
+        &mut app,
+        AssetInfo::Token {
+            contract_addr: old_cw20_oro,
+        },
+        Some(outpost_burn_params()),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidOutpostBurnParams {}
+    );
+
+    let converter = instantiate_converter(
+        &mut app,
+        AssetInfo::NativeToken {
+            denom: OLD_ORO.to_string(),
+        },
+        Some(outpost_burn_params()),
+    )
+    .unwrap();
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&converter, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.new_oro_denom, NEW_ORO);
+    assert_eq!(config.outpost_burn_params, Some(outpost_burn_params()));
+}
+
+#[test]
+fn test_outpost_convert_and_transfer_for_burning() {
+    let mut app = mock_app();
+    let user = Addr::unchecked(USER);
+    let converter = instantiate_converter(
+        &mut app,
+        AssetInfo::NativeToken {
+            denom: OLD_ORO.to_string(),
+        },
+        Some(outpost_burn_params()),
+    )
+    .unwrap();
+
+    // Nothing to send for burning yet
+    let err = app
+        .execute_contract(
+            user.clone(),
+            converter.clone(),
+            &ExecuteMsg::TransferForBurning { timeout: None },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NothingToBurn {}
+    );
+
+    // The converter is not prefunded with new ORO
+    app.execute_contract(
+        user.clone(),
+        converter.clone(),
+        &ExecuteMsg::Convert { receiver: None },
+        &coins(100, OLD_ORO),
+    )
+    .unwrap_err();
+
+    app.send_tokens(
+        Addr::unchecked(OWNER),
+        converter.clone(),
+        &coins(10_000, NEW_ORO),
+    )
+    .unwrap();
+
+    // Cw20 hook is not available on outposts
+    let err = app
+        .execute_contract(
+            user.clone(),
+            converter.clone(),
+            &ExecuteMsg::Receive(cw20::Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: Uint128::new(100),
+                msg: to_json_binary(&Cw20HookMsg { receiver: None }).unwrap(),
+            }),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidEndpoint {
+            old_oro: OLD_ORO.to_string()
+        }
+    );
+
+    app.execute_contract(
+        user.clone(),
+        converter.clone(),
+        &ExecuteMsg::Convert { receiver: None },
+        &coins(600, OLD_ORO),
+    )
+    .unwrap();
+    app.execute_contract(
+        user.clone(),
+        converter.clone(),
+        &ExecuteMsg::Convert {
+            receiver: Some("receiver".to_string()),
+        },
+        &coins(400, OLD_ORO),
+    )
+    .unwrap();
+
+    assert_eq!(balance(&app, USER, OLD_ORO), 0);
+    assert_eq!(balance(&app, USER, NEW_ORO), 600);
+    assert_eq!(balance(&app, "receiver", NEW_ORO), 400);
+    assert_eq!(balance(&app, &converter, OLD_ORO), 1_000);
+    assert_eq!(balance(&app, &converter, NEW_ORO), 9_000);
+
+    // Burn is only available on the old Hub
+    let err = app
+        .execute_contract(user.clone(), converter.clone(), &ExecuteMsg::Burn {}, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidEndpoint {
+            old_oro: OLD_ORO.to_string()
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            user.clone(),
+            converter.clone(),
+            &ExecuteMsg::TransferForBurning { timeout: Some(60) },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidTimeout {}
+    );
+
+    let resp = app
+        .execute_contract(
+            user,
+            converter.clone(),
+            &ExecuteMsg::TransferForBurning { timeout: None },
+            &[],
+        )
+        .unwrap();
+    let block_time = app.block_info().time.seconds();
+    let event = resp
+        .events
+        .iter()
+        .find(|event| event.ty == "ibc_transfer")
+        .unwrap();
+    let attrs: Vec<_> = event
+        .attributes
+        .iter()
+        .map(|attr| (attr.key.as_str(), attr.value.clone()))
+        .collect();
+    assert_eq!(
+        attrs,
+        [
+            ("channel", CHANNEL.to_string()),
+            ("receiver", BURN_ADDR.to_string()),
+            ("amount", coin(1_000, OLD_ORO).to_string()),
+            ("timeout", (block_time + 300).to_string()),
+        ]
+    );
+
+    assert_eq!(balance(&app, &converter, OLD_ORO), 0);
+    assert_eq!(balance(&app, IBC_ESCROW, OLD_ORO), 1_000);
+}
+
+#[test]
+fn test_hub_convert_and_burn() {
+    let mut app = mock_app();
+    let user = Addr::unchecked(USER);
+    let old_cw20_oro = instantiate_old_cw20_oro(&mut app);
+    let converter = instantiate_converter(
+        &mut app,
+        AssetInfo::Token {
+            contract_addr: old_cw20_oro.clone(),
+        },
+        None,
+    )
+    .unwrap();
+    app.send_tokens(
+        Addr::unchecked(OWNER),
+        converter.clone(),
+        &coins(10_000, NEW_ORO),
+    )
+    .unwrap();
+
+    // Native conversion is not available on the old Hub
+    let err = app
+        .execute_contract(
+            user.clone(),
+            converter.clone(),
+            &ExecuteMsg::Convert { receiver: None },
+            &coins(100, OLD_ORO),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidEndpoint {
+            old_oro: old_cw20_oro.to_string()
+        }
+    );
+
+    // Only the old ORO contract can call the hook
+    let err = app
+        .execute_contract(
+            user.clone(),
+            converter.clone(),
+            &ExecuteMsg::Receive(cw20::Cw20ReceiveMsg {
+                sender: USER.to_string(),
+                amount: Uint128::new(100),
+                msg: to_json_binary(&Cw20HookMsg { receiver: None }).unwrap(),
+            }),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    let err = app
+        .execute_contract(user.clone(), converter.clone(), &ExecuteMsg::Burn {}, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NothingToBurn {}
+    );
+
+    app.execute_contract(
+        user.clone(),
+        old_cw20_oro.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: converter.to_string(),
+            amount: Uint128::new(700),
+            msg: to_json_binary(&Cw20HookMsg { receiver: None }).unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        user.clone(),
+        old_cw20_oro.clone(),
+        &Cw20ExecuteMsg::Send {
+            contract: converter.to_string(),
+            amount: Uint128::new(300),
+            msg: to_json_binary(&Cw20HookMsg {
+                receiver: Some("receiver".to_string()),
+            })
+            .unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(balance(&app, USER, NEW_ORO), 700);
+    assert_eq!(balance(&app, "receiver", NEW_ORO), 300);
+
+    // Transfer for burning is only available on outposts
+    let err = app
+        .execute_contract(
+            user.clone(),
+            converter.clone(),
+            &ExecuteMsg::TransferForBurning { timeout: None },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidEndpoint {
+            old_oro: old_cw20_oro.to_string()
+        }
+    );
+
+    app.execute_contract(user, converter.clone(), &ExecuteMsg::Burn {}, &[])
+        .unwrap();
+
+    let cw20_balance = |address: &str| -> Uint128 {
+        let resp: BalanceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                &old_cw20_oro,
+                &Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        resp.balance
+    };
+    assert_eq!(cw20_balance(converter.as_str()), Uint128::zero());
+    assert_eq!(cw20_balance(USER), Uint128::zero());
+
+    let token_info: cw20::TokenInfoResponse = app
+        .wrap()
+        .query_wasm_smart(&old_cw20_oro, &Cw20QueryMsg::TokenInfo {})
+        .unwrap();
+    assert_eq!(token_info.total_supply, Uint128::zero());
+}
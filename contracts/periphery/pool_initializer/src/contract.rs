@@ -329,6 +329,7 @@ pub fn execute_create_pair_and_provide_liquidity(
         pair_type,
         asset_infos,
         init_params,
+        fee_tier: None,
     };
     let submsg = SubMsg::reply_on_success(
         WasmMsg::Execute {
@@ -0,0 +1,175 @@
+use cosmwasm_std::{Addr, Deps};
+
+use oroswap::asset::{Asset, AssetInfo};
+use oroswap::factory::PairType;
+use oroswap::querier::query_unpaused_pairs;
+use oroswap::router::{
+    FindBestRouteResponse, SwapOperation, DEFAULT_ROUTE_HOPS, MAX_ROUTE_CANDIDATES, MAX_ROUTE_HOPS,
+};
+
+use crate::contract::simulate_swap_operations;
+use crate::error::ContractError;
+use crate::state::CONFIG;
+
+/// Searches the direct pairs and the routes through the configured hub assets for the route with
+/// the highest return amount. At most [`MAX_ROUTE_CANDIDATES`] routes are simulated.
+///
+/// * **offer_asset** asset being swapped together with the amount to swap.
+///
+/// * **ask_asset_info** asset to swap to.
+///
+/// * **max_hops** maximum number of swaps in the route.
+pub fn find_best_route(
+    deps: Deps,
+    offer_asset: Asset,
+    ask_asset_info: AssetInfo,
+    max_hops: Option<u8>,
+) -> Result<FindBestRouteResponse, ContractError> {
+    let max_hops = max_hops.unwrap_or(DEFAULT_ROUTE_HOPS);
+    if max_hops == 0 || max_hops > MAX_ROUTE_HOPS {
+        return Err(ContractError::InvalidMaxHops {
+            max: MAX_ROUTE_HOPS,
+        });
+    }
+
+    offer_asset.info.check(deps.api)?;
+    ask_asset_info.check(deps.api)?;
+    if offer_asset.info.equal(&ask_asset_info) {
+        return Err(ContractError::DoublingAssetsPath {
+            offer_asset: offer_asset.info.to_string(),
+            ask_asset: ask_asset_info.to_string(),
+        });
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let asset_paths = collect_asset_paths(
+        &config.hub_assets,
+        &offer_asset.info,
+        &ask_asset_info,
+        max_hops as usize,
+    );
+
+    let mut hop_pairs = vec![];
+    let mut candidates_left = MAX_ROUTE_CANDIDATES;
+    let mut best_route: Option<FindBestRouteResponse> = None;
+    for asset_path in asset_paths {
+        if candidates_left == 0 {
+            break;
+        }
+
+        let candidates =
+            path_operations(deps, &config.oroswap_factory, &asset_path, &mut hop_pairs)?;
+        for operations in candidates.into_iter().take(candidates_left) {
+            candidates_left -= 1;
+
+            // Candidates which can't be simulated (e.g. a pool without liquidity) are skipped
+            let simulation =
+                match simulate_swap_operations(deps, offer_asset.amount, operations.clone()) {
+                    Ok(simulation) => simulation,
+                    Err(_) => continue,
+                };
+
+            if best_route
+                .as_ref()
+                .map_or(true, |best| simulation.amount > best.simulation.amount)
+            {
+                best_route = Some(FindBestRouteResponse {
+                    operations,
+                    simulation,
+                });
+            }
+        }
+    }
+
+    best_route.ok_or(ContractError::NoRouteFound {
+        offer_asset: offer_asset.info.to_string(),
+        ask_asset: ask_asset_info.to_string(),
+    })
+}
+
+/// Returns the direct path followed by all paths going through distinct **hub_assets**
+/// which consist of at most **max_hops** swaps. Shorter paths come first.
+fn collect_asset_paths(
+    hub_assets: &[AssetInfo],
+    offer_asset_info: &AssetInfo,
+    ask_asset_info: &AssetInfo,
+    max_hops: usize,
+) -> Vec<Vec<AssetInfo>> {
+    let hubs: Vec<&AssetInfo> = hub_assets
+        .iter()
+        .filter(|hub| *hub != offer_asset_info && *hub != ask_asset_info)
+        .collect();
+
+    let mut paths = vec![vec![offer_asset_info.clone(), ask_asset_info.clone()]];
+    if max_hops >= 2 {
+        for hub in &hubs {
+            paths.push(vec![
+                offer_asset_info.clone(),
+                (*hub).clone(),
+                ask_asset_info.clone(),
+            ]);
+        }
+    }
+    if max_hops >= 3 {
+        for first in &hubs {
+            for second in hubs.iter().filter(|second| *second != first) {
+                paths.push(vec![
+                    offer_asset_info.clone(),
+                    (*first).clone(),
+                    (*second).clone(),
+                    ask_asset_info.clone(),
+                ]);
+            }
+        }
+    }
+
+    paths
+}
+
+/// Returns swap operations for every combination of unpaused pools along **asset_path**.
+/// Pools found for each hop are cached in **hop_pairs** so that paths sharing a hop
+/// query the factory only once.
+fn path_operations(
+    deps: Deps,
+    factory: &Addr,
+    asset_path: &[AssetInfo],
+    hop_pairs: &mut Vec<(Vec<AssetInfo>, Vec<PairType>)>,
+) -> Result<Vec<Vec<SwapOperation>>, ContractError> {
+    let mut candidates: Vec<Vec<SwapOperation>> = vec![vec![]];
+
+    for hop in asset_path.windows(2) {
+        let pair_types = match hop_pairs.iter().find(|(assets, _)| assets == hop) {
+            Some((_, pair_types)) => pair_types.clone(),
+            None => {
+                let pair_types: Vec<_> = query_unpaused_pairs(&deps.querier, factory, hop)?
+                    .into_iter()
+                    .map(|pair| pair.pair_type)
+                    .collect();
+                hop_pairs.push((hop.to_vec(), pair_types.clone()));
+                pair_types
+            }
+        };
+
+        // Later hops are not queried once the path is known to be unreachable
+        if pair_types.is_empty() {
+            return Ok(vec![]);
+        }
+
+        candidates = candidates
+            .into_iter()
+            .flat_map(|operations| {
+                pair_types.iter().map(move |pair_type| {
+                    let mut operations = operations.clone();
+                    operations.push(SwapOperation::OroSwap {
+                        offer_asset_info: hop[0].clone(),
+                        ask_asset_info: hop[1].clone(),
+                        pair_type: pair_type.clone(),
+                    });
+                    operations
+                })
+            })
+            .collect();
+    }
+
+    Ok(candidates)
+}
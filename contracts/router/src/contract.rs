@@ -6,17 +6,23 @@ use cosmwasm_std::{
 use cw2::{get_contract_version, set_contract_version};
 use cw20::Cw20ReceiveMsg;
 
-use oroswap::asset::{addr_opt_validate, Asset, AssetInfo, AssetInfoExt};
+use oroswap::asset::{
+    addr_opt_validate, native_asset_info, token_asset, Asset, AssetInfo, AssetInfoExt,
+};
 use oroswap::factory::PairType;
 use oroswap::pair::{QueryMsg as PairQueryMsg, ReverseSimulationResponse, SimulationResponse};
-use oroswap::querier::query_pair_info;
+use oroswap::querier::{query_factory_config, query_pair_info};
 use oroswap::router::{
-    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg,
-    SimulateSwapOperationsResponse, RouterStage, SwapOperation, SwapResponseData, MAX_SWAP_OPERATIONS,
+    ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, QueryMsg, RouterStage,
+    SimulateSplitSwapOperationsResponse, SimulateSwapOperationsResponse, SplitRoute,
+    SplitRouteResponse, SwapOperation, SwapResponseData, MAX_HUB_ASSETS, MAX_SWAP_OPERATIONS,
 };
 
+use crate::best_route::find_best_route;
 use crate::error::ContractError;
-use crate::operations::execute_swap_operation;
+use crate::operations::{
+    asset_into_swap_msg, execute_swap_operation, get_best_pair_reverse, query_operation_pair,
+};
 use crate::state::{Config, ReplyData, CONFIG, REPLY_DATA};
 
 /// Contract name that is used for migration.
@@ -25,6 +31,8 @@ const CONTRACT_NAME: &str = "oroswap-router";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 pub const AFTER_SWAP_REPLY_ID: u64 = 1;
+/// The number of attempts to top up the reverse simulated offer amount for exact output swaps
+const EXACT_OUT_ITERATIONS: usize = 3;
 
 /// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -40,6 +48,7 @@ pub fn instantiate(
         deps.storage,
         &Config {
             oroswap_factory: deps.api.addr_validate(&msg.oroswap_factory)?,
+            hub_assets: vec![],
         },
     )?;
 
@@ -63,6 +72,29 @@ pub fn instantiate(
 ///             to
 ///         }** Performs swap operations with the specified parameters.
 ///
+/// * **ExecuteMsg::ExecuteSplitSwapOperations {
+///             routes,
+///             minimum_receive,
+///             to
+///         }** Splits the offer amount between several weighted routes and performs them.
+///
+/// * **ExecuteMsg::ExecuteSwapOperationsExactOut {
+///             operations,
+///             ask_amount,
+///             max_offer,
+///             to
+///         }** Swaps the amount needed to receive `ask_amount` and refunds the rest.
+///
+/// * **ExecuteMsg::ExecuteBestRoute {
+///             ask_asset_info,
+///             max_hops,
+///             minimum_receive,
+///             to
+///         }** Finds the best route for the sent coin and performs it.
+///
+/// * **ExecuteMsg::UpdateHubAssets { hub_assets }** Sets the intermediate assets used by the
+/// best route search.
+///
 /// * **ExecuteMsg::ExecuteSwapOperation { operation, to }** Execute a single swap operation.
 ///
 /// * **ExecuteMsg::AssertMinimumReceive {
@@ -79,7 +111,7 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Receive(msg) => receive_cw20(deps, env, msg),
+        ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
         ExecuteMsg::ExecuteSwapOperations {
             operations,
             minimum_receive,
@@ -94,6 +126,61 @@ pub fn execute(
             to,
             max_spread,
         ),
+        ExecuteMsg::ExecuteSplitSwapOperations {
+            routes,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_split_swap_operations(
+            deps,
+            env,
+            info.sender,
+            routes,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
+        ExecuteMsg::ExecuteSwapOperationsExactOut {
+            operations,
+            ask_amount,
+            max_offer,
+            to,
+            max_spread,
+        } => execute_swap_operations_exact_out(
+            deps,
+            env,
+            info.sender,
+            operations,
+            ask_amount,
+            max_offer,
+            to,
+            max_spread,
+        ),
+        ExecuteMsg::ExecuteBestRoute {
+            ask_asset_info,
+            max_hops,
+            minimum_receive,
+            to,
+            max_spread,
+        } => {
+            let offer_asset = match info.funds.as_slice() {
+                [coin] => Asset::from(coin),
+                _ => return Err(ContractError::InvalidFunds {}),
+            };
+
+            execute_best_route(
+                deps,
+                env,
+                info.sender,
+                offer_asset,
+                ask_asset_info,
+                max_hops,
+                minimum_receive,
+                to,
+                max_spread,
+            )
+        }
+        ExecuteMsg::UpdateHubAssets { hub_assets } => update_hub_assets(deps, info, hub_assets),
         ExecuteMsg::ExecuteSwapOperation {
             operation,
             to,
@@ -109,6 +196,7 @@ pub fn execute(
 pub fn receive_cw20(
     deps: DepsMut,
     env: Env,
+    info: MessageInfo,
     cw20_msg: Cw20ReceiveMsg,
 ) -> Result<Response, ContractError> {
     match from_json(&cw20_msg.msg)? {
@@ -126,6 +214,53 @@ pub fn receive_cw20(
             to,
             max_spread,
         ),
+        Cw20HookMsg::ExecuteSplitSwapOperations {
+            routes,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_split_swap_operations(
+            deps,
+            env,
+            Addr::unchecked(cw20_msg.sender),
+            routes,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
+        Cw20HookMsg::ExecuteSwapOperationsExactOut {
+            operations,
+            ask_amount,
+            max_offer,
+            to,
+            max_spread,
+        } => execute_swap_operations_exact_out(
+            deps,
+            env,
+            Addr::unchecked(cw20_msg.sender),
+            operations,
+            ask_amount,
+            max_offer,
+            to,
+            max_spread,
+        ),
+        Cw20HookMsg::ExecuteBestRoute {
+            ask_asset_info,
+            max_hops,
+            minimum_receive,
+            to,
+            max_spread,
+        } => execute_best_route(
+            deps,
+            env,
+            Addr::unchecked(cw20_msg.sender),
+            token_asset(info.sender, cw20_msg.amount),
+            ask_asset_info,
+            max_hops,
+            minimum_receive,
+            to,
+            max_spread,
+        ),
     }
 }
 
@@ -200,6 +335,270 @@ pub fn execute_swap_operations(
     Ok(Response::new().add_submessages(messages))
 }
 
+/// Swaps only the part of the router's offer asset balance needed to receive **ask_amount**
+/// and refunds the rest to the sender.
+///
+/// * **sender** address that swaps tokens and receives the refund.
+///
+/// * **operations** all swap operations to perform.
+///
+/// * **ask_amount** amount of ask tokens to receive. Because of rounding the recipient
+/// may receive slightly more.
+///
+/// * **max_offer** maximum amount of offer tokens to swap.
+///
+/// * **to** recipient of the ask tokens.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_swap_operations_exact_out(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    operations: Vec<SwapOperation>,
+    ask_amount: Uint128,
+    max_offer: Uint128,
+    to: Option<String>,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    assert_operations(deps.api, &operations)?;
+
+    let offer_asset_info = operations[0].get_offer_asset_info();
+    let balance = offer_asset_info.query_pool(&deps.querier, &env.contract.address)?;
+    let offer_amount = exact_out_offer_amount(deps.as_ref(), ask_amount, &operations)?;
+
+    let max_offer = max_offer.min(balance);
+    if offer_amount > max_offer {
+        return Err(ContractError::MaxOfferExceeded {
+            max_offer,
+            offer_amount,
+        });
+    }
+
+    // The refund is sent before the swaps so that the first hop only swaps the offer amount
+    let mut response = Response::new().add_attribute("offer_amount", offer_amount);
+    let refund_amount = balance - offer_amount;
+    if !refund_amount.is_zero() {
+        response = response
+            .add_message(
+                offer_asset_info
+                    .with_balance(refund_amount)
+                    .into_msg(&sender)?,
+            )
+            .add_attribute("refund_amount", refund_amount);
+    }
+
+    let swap_response = execute_swap_operations(
+        deps,
+        env,
+        sender,
+        operations,
+        Some(ask_amount),
+        to,
+        max_spread,
+    )?;
+
+    Ok(response.add_submessages(swap_response.messages))
+}
+
+/// Returns the offer amount required to receive **ask_amount** from **operations**.
+/// Reverse simulations round the offer amount down, so it is topped up until the forward
+/// simulation covers the ask amount.
+fn exact_out_offer_amount(
+    deps: Deps,
+    ask_amount: Uint128,
+    operations: &[SwapOperation],
+) -> Result<Uint128, ContractError> {
+    let mut offer_amount = simulate_reverse_swap_operations(deps, ask_amount, operations.to_vec())?;
+
+    for _ in 0..EXACT_OUT_ITERATIONS {
+        let return_amount =
+            simulate_swap_operations(deps, offer_amount, operations.to_vec())?.amount;
+        if return_amount >= ask_amount {
+            return Ok(offer_amount);
+        }
+
+        let shortfall = ask_amount - return_amount;
+        offer_amount = offer_amount
+            .checked_add(offer_amount.multiply_ratio(shortfall, return_amount.max(Uint128::one())))?
+            .checked_add(Uint128::one())?;
+    }
+
+    Err(ContractError::ExactOutUnreachable { ask_amount })
+}
+
+/// Finds the route with the highest return amount for the offer asset and performs it.
+///
+/// * **sender** address that swaps tokens.
+///
+/// * **offer_asset** asset sent to the router.
+///
+/// * **ask_asset_info** asset to swap to.
+///
+/// * **max_hops** maximum number of swaps in the route.
+///
+/// * **minimum_receive** used to guarantee that the ask amount is above a minimum amount.
+///
+/// * **to** recipient of the ask tokens.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_best_route(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    offer_asset: Asset,
+    ask_asset_info: AssetInfo,
+    max_hops: Option<u8>,
+    minimum_receive: Option<Uint128>,
+    to: Option<String>,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let best_route = find_best_route(deps.as_ref(), offer_asset, ask_asset_info, max_hops)?;
+
+    execute_swap_operations(
+        deps,
+        env,
+        sender,
+        best_route.operations,
+        minimum_receive,
+        to,
+        max_spread,
+    )
+}
+
+/// Sets the intermediate assets which the best route search may go through.
+///
+/// * **hub_assets** new list of hub assets. Can contain at most [`MAX_HUB_ASSETS`] assets.
+///
+/// ## Executor
+/// Only the factory owner can execute this.
+pub fn update_hub_assets(
+    deps: DepsMut,
+    info: MessageInfo,
+    hub_assets: Vec<AssetInfo>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    let factory_config = query_factory_config(&deps.querier, &config.oroswap_factory)?;
+    if info.sender != factory_config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if hub_assets.len() > MAX_HUB_ASSETS {
+        return Err(ContractError::TooManyHubAssets {
+            max: MAX_HUB_ASSETS,
+        });
+    }
+
+    for (i, asset) in hub_assets.iter().enumerate() {
+        asset.check(deps.api)?;
+        if hub_assets[..i].contains(asset) {
+            return Err(ContractError::DuplicateHubAsset {
+                asset: asset.to_string(),
+            });
+        }
+    }
+
+    let attrs = vec![
+        attr("action", "update_hub_assets"),
+        attr(
+            "hub_assets",
+            hub_assets
+                .iter()
+                .map(|asset| asset.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+    ];
+
+    config.hub_assets = hub_assets;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Splits the router's balance of the offer asset between several routes and performs them.
+///
+/// * **sender** address that swaps tokens.
+///
+/// * **routes** weighted routes to perform. Each route is a sequence of swap operations.
+///
+/// * **minimum_receive** used to guarantee that the ask amount received from all routes is above a minimum amount.
+///
+/// * **to** recipient of the ask tokens.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_split_swap_operations(
+    deps: DepsMut,
+    env: Env,
+    sender: Addr,
+    routes: Vec<SplitRoute>,
+    minimum_receive: Option<Uint128>,
+    to: Option<String>,
+    max_spread: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let (offer_asset_info, target_asset_info) = assert_split_routes(deps.api, &routes)?;
+
+    let to = addr_opt_validate(deps.api, &to)?.unwrap_or(sender);
+    let offer_amount = offer_asset_info.query_pool(&deps.querier, &env.contract.address)?;
+    let offer_amounts = split_offer_amount(offer_amount, &routes)?;
+    let config = CONFIG.load(deps.storage)?;
+    let routes_len = routes.len();
+
+    let mut messages = vec![];
+    for (route_index, (route, amount)) in routes.into_iter().zip(offer_amounts).enumerate() {
+        let operations_len = route.operations.len();
+
+        for (operation_index, op) in route.operations.into_iter().enumerate() {
+            let is_last_operation = operation_index == operations_len - 1;
+            let receiver = is_last_operation.then(|| to.to_string());
+
+            // The first hop swaps exactly the route's share with the pair. Next hops swap
+            // the whole intermediate balance, which has only been produced by this route.
+            let msg = if operation_index == 0 {
+                let (pair_info, offer_asset_info, ask_asset_info) =
+                    query_operation_pair(&deps.querier, &config.oroswap_factory, op, amount)?;
+
+                asset_into_swap_msg(
+                    pair_info.contract_addr.to_string(),
+                    offer_asset_info.with_balance(amount),
+                    ask_asset_info,
+                    max_spread,
+                    receiver,
+                    operations_len == 1,
+                )?
+            } else {
+                wasm_execute(
+                    env.contract.address.to_string(),
+                    &ExecuteMsg::ExecuteSwapOperation {
+                        operation: op,
+                        to: receiver,
+                        max_spread,
+                        single: false,
+                    },
+                    vec![],
+                )?
+                .into()
+            };
+
+            if route_index == routes_len - 1 && is_last_operation {
+                messages.push(SubMsg::reply_on_success(msg, AFTER_SWAP_REPLY_ID));
+            } else {
+                messages.push(SubMsg::new(msg));
+            }
+        }
+    }
+
+    let prev_balance = target_asset_info.query_pool(&deps.querier, &to)?;
+    REPLY_DATA.save(
+        deps.storage,
+        &ReplyData {
+            asset_info: target_asset_info,
+            prev_balance,
+            minimum_receive,
+            receiver: to.to_string(),
+        },
+    )?;
+
+    Ok(Response::new().add_submessages(messages))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     match msg {
@@ -244,6 +643,15 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 ///            ask_amount,
 ///           operations,
 ///        }** Simulates one or multiple swap operations in reverse and returns the end result in a [`Uint128`] object.
+/// * **QueryMsg::SimulateSplitSwapOperations {
+///             offer_amount,
+///             routes,
+///         }** Simulates swaps split between several routes and returns the result in a [`SimulateSplitSwapOperationsResponse`] object.
+/// * **QueryMsg::FindBestRoute {
+///             offer_asset,
+///             ask_asset_info,
+///             max_hops,
+///         }** Returns the route with the highest return amount in a [`oroswap::router::FindBestRouteResponse`] object.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     match msg {
@@ -262,6 +670,24 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
         } => Ok(to_json_binary(&simulate_reverse_swap_operations(
             deps, ask_amount, operations,
         )?)?),
+        QueryMsg::SimulateSplitSwapOperations {
+            offer_amount,
+            routes,
+        } => Ok(to_json_binary(&simulate_split_swap_operations(
+            deps,
+            offer_amount,
+            routes,
+        )?)?),
+        QueryMsg::FindBestRoute {
+            offer_asset,
+            ask_asset_info,
+            max_hops,
+        } => Ok(to_json_binary(&find_best_route(
+            deps,
+            offer_asset,
+            ask_asset_info,
+            max_hops,
+        )?)?),
     }
 }
 
@@ -270,6 +696,7 @@ pub fn query_config(deps: Deps) -> Result<ConfigResponse, ContractError> {
     let state = CONFIG.load(deps.storage)?;
     let resp = ConfigResponse {
         oroswap_factory: state.oroswap_factory.into_string(),
+        hub_assets: state.hub_assets,
     };
 
     Ok(resp)
@@ -297,7 +724,7 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: Empty) -> Result<Response, Contra
 ///
 /// * **operations** is a vector that contains objects of type [`SwapOperation`].
 /// These are all the swap operations for which we perform a simulation.
-fn simulate_swap_operations(
+pub fn simulate_swap_operations(
     deps: Deps,
     offer_amount: Uint128,
     operations: Vec<SwapOperation>,
@@ -310,48 +737,33 @@ fn simulate_swap_operations(
     let mut router_stages = Vec::new();
 
     for (stage_index, operation) in operations.into_iter().enumerate() {
-        match operation {
-            SwapOperation::OroSwap {
-                offer_asset_info,
-                ask_asset_info,
-                pair_type,
-            } => {
-                let pair_info = query_pair_info(
-                    &deps.querier,
-                    oroswap_factory.clone(),
-                    &[offer_asset_info.clone(), ask_asset_info.clone()],
-                    pair_type,
-                )?;
+        let (pair_info, offer_asset_info, ask_asset_info) =
+            query_operation_pair(&deps.querier, &oroswap_factory, operation, return_amount)?;
 
-                let contract_addr = pair_info.contract_addr.clone();
-                let res: SimulationResponse = deps.querier.query_wasm_smart(
-                    contract_addr.clone(),
-                    &PairQueryMsg::Simulation {
-                        offer_asset: Asset {
-                            info: offer_asset_info.clone(),
-                            amount: return_amount,
-                        },
-                        ask_asset_info: Some(ask_asset_info.clone()),
-                    },
-                )?;
+        let contract_addr = pair_info.contract_addr.clone();
+        let res: SimulationResponse = deps.querier.query_wasm_smart(
+            contract_addr.clone(),
+            &PairQueryMsg::Simulation {
+                offer_asset: Asset {
+                    info: offer_asset_info.clone(),
+                    amount: return_amount,
+                },
+                ask_asset_info: Some(ask_asset_info.clone()),
+            },
+        )?;
 
-                // Track stage information (in the offer asset's token)
-                router_stages.push(RouterStage {
-                    stage: stage_index as u8,
-                    pair_address: contract_addr.to_string(),
-                    offer_asset: offer_asset_info,
-                    ask_asset: ask_asset_info,
-                    return_amount: res.return_amount,
-                    commission_amount: res.commission_amount,
-                    spread_amount: res.spread_amount,
-                });
+        // Track stage information (in the offer asset's token)
+        router_stages.push(RouterStage {
+            stage: stage_index as u8,
+            pair_address: contract_addr.to_string(),
+            offer_asset: offer_asset_info,
+            ask_asset: ask_asset_info,
+            return_amount: res.return_amount,
+            commission_amount: res.commission_amount,
+            spread_amount: res.spread_amount,
+        });
 
-                return_amount = res.return_amount;
-            }
-            SwapOperation::NativeSwap { .. } => {
-                return Err(ContractError::NativeSwapNotSupported {})
-            }
-        }
+        return_amount = res.return_amount;
     }
 
     Ok(SimulateSwapOperationsResponse {
@@ -360,6 +772,39 @@ fn simulate_swap_operations(
     })
 }
 
+/// Returns the end result of a split swap simulation together with a breakdown for every route
+/// using a [`SimulateSplitSwapOperationsResponse`] object.
+///
+/// * **offer_amount** total amount of offer assets being swapped.
+///
+/// * **routes** weighted routes between which the offer amount is split.
+fn simulate_split_swap_operations(
+    deps: Deps,
+    offer_amount: Uint128,
+    routes: Vec<SplitRoute>,
+) -> Result<SimulateSplitSwapOperationsResponse, ContractError> {
+    assert_split_routes(deps.api, &routes)?;
+
+    let offer_amounts = split_offer_amount(offer_amount, &routes)?;
+    let mut amount = Uint128::zero();
+    let mut routes_response = Vec::with_capacity(routes.len());
+
+    for (route, offer_amount) in routes.into_iter().zip(offer_amounts) {
+        let res = simulate_swap_operations(deps, offer_amount, route.operations)?;
+        amount = amount.checked_add(res.amount)?;
+        routes_response.push(SplitRouteResponse {
+            offer_amount,
+            return_amount: res.amount,
+            router_stages: res.router_stages,
+        });
+    }
+
+    Ok(SimulateSplitSwapOperationsResponse {
+        amount,
+        routes: routes_response,
+    })
+}
+
 fn simulate_reverse_swap_operations(
     deps: Deps,
     ask_amount: Uint128,
@@ -394,8 +839,18 @@ fn simulate_reverse_swap_operations(
 
                 step_amount = res.offer_amount;
             }
-            SwapOperation::NativeSwap { .. } => {
-                return Err(ContractError::NativeSwapNotSupported {})
+            SwapOperation::NativeSwap {
+                offer_denom,
+                ask_denom,
+            } => {
+                let (_, offer_amount) = get_best_pair_reverse(
+                    &deps.querier,
+                    &config.oroswap_factory,
+                    &native_asset_info(offer_denom),
+                    &native_asset_info(ask_denom).with_balance(step_amount),
+                )?;
+
+                step_amount = offer_amount;
             }
         }
     }
@@ -423,11 +878,15 @@ fn assert_operations(api: &dyn Api, operations: &[SwapOperation]) -> Result<(),
             SwapOperation::OroSwap {
                 offer_asset_info,
                 ask_asset_info,
-                pair_type,
+                ..
             } => (offer_asset_info.clone(), ask_asset_info.clone()),
-            SwapOperation::NativeSwap { .. } => {
-                return Err(ContractError::NativeSwapNotSupported {})
-            }
+            SwapOperation::NativeSwap {
+                offer_denom,
+                ask_denom,
+            } => (
+                native_asset_info(offer_denom.clone()),
+                native_asset_info(ask_denom.clone()),
+            ),
         };
 
         offer_asset.check(api)?;
@@ -456,6 +915,137 @@ fn assert_operations(api: &dyn Api, operations: &[SwapOperation]) -> Result<(),
     Ok(())
 }
 
+/// Validates split routes and returns the common offer and ask assets.
+///
+/// * **routes** weighted routes to check. Every route must be a valid path on its own,
+/// all of them must start and end with the same assets and no pair can be used by two routes.
+fn assert_split_routes(
+    api: &dyn Api,
+    routes: &[SplitRoute],
+) -> Result<(AssetInfo, AssetInfo), ContractError> {
+    let first_route = routes.first().ok_or(ContractError::MustProvideRoutes {})?;
+
+    let operations_count: usize = routes.iter().map(|route| route.operations.len()).sum();
+    if operations_count > MAX_SWAP_OPERATIONS {
+        return Err(ContractError::SwapLimitExceeded {});
+    }
+
+    assert_operations(api, &first_route.operations)?;
+    let offer_asset = first_route.operations[0].get_offer_asset_info();
+    let ask_asset = first_route
+        .operations
+        .last()
+        .unwrap()
+        .get_target_asset_info();
+
+    for route in routes {
+        if route.weight == 0 {
+            return Err(ContractError::ZeroRouteWeight {});
+        }
+
+        assert_operations(api, &route.operations)?;
+
+        if route.operations[0].get_offer_asset_info() != offer_asset
+            || route.operations.last().unwrap().get_target_asset_info() != ask_asset
+        {
+            return Err(ContractError::InconsistentSplitRoutes {
+                offer_asset: offer_asset.to_string(),
+                ask_asset: ask_asset.to_string(),
+            });
+        }
+
+        // The router holds the offer amounts of routes that have not run yet, so a route
+        // returning to the offer asset would mix its output with them
+        if route
+            .operations
+            .iter()
+            .any(|operation| operation.get_target_asset_info() == offer_asset)
+        {
+            return Err(ContractError::SplitRouteReturnsOfferAsset {
+                offer_asset: offer_asset.to_string(),
+            });
+        }
+    }
+
+    // Every route is simulated against the current pool balances, so a pair used by several
+    // routes would be quoted as if the other routes had not swapped through it
+    for (i, route) in routes.iter().enumerate() {
+        for operation in &route.operations {
+            let (assets, pair_type) = operation_pair(operation);
+            let shared = routes[..i]
+                .iter()
+                .flat_map(|other| &other.operations)
+                .map(operation_pair)
+                .any(|(other_assets, other_pair_type)| {
+                    other_assets == assets
+                        && (pair_type.is_none()
+                            || other_pair_type.is_none()
+                            || pair_type == other_pair_type)
+                });
+
+            if shared {
+                let [first_asset, second_asset] = assets;
+                return Err(ContractError::SplitRoutesSharePair {
+                    first_asset,
+                    second_asset,
+                });
+            }
+        }
+    }
+
+    Ok((offer_asset, ask_asset))
+}
+
+/// Returns the sorted assets and the type of the pair a swap operation goes through.
+/// The pair type is not known for native swaps as they use the best pair between both denoms.
+fn operation_pair(operation: &SwapOperation) -> ([String; 2], Option<PairType>) {
+    let (mut assets, pair_type) = match operation {
+        SwapOperation::OroSwap {
+            offer_asset_info,
+            ask_asset_info,
+            pair_type,
+        } => (
+            [offer_asset_info.to_string(), ask_asset_info.to_string()],
+            Some(pair_type.clone()),
+        ),
+        SwapOperation::NativeSwap {
+            offer_denom,
+            ask_denom,
+        } => ([offer_denom.clone(), ask_denom.clone()], None),
+    };
+    assets.sort();
+
+    (assets, pair_type)
+}
+
+/// Splits the offer amount between routes proportionally to their weights.
+/// The last route receives the rounding remainder.
+fn split_offer_amount(
+    offer_amount: Uint128,
+    routes: &[SplitRoute],
+) -> Result<Vec<Uint128>, ContractError> {
+    let total_weight: u128 = routes.iter().map(|route| route.weight as u128).sum();
+    let mut remaining = offer_amount;
+
+    routes
+        .iter()
+        .enumerate()
+        .map(|(ind, route)| {
+            let amount = if ind == routes.len() - 1 {
+                remaining
+            } else {
+                offer_amount.multiply_ratio(route.weight, total_weight)
+            };
+            if amount.is_zero() {
+                return Err(ContractError::ZeroSplitAmount {});
+            }
+            remaining = remaining.checked_sub(amount)?;
+
+            Ok(amount)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod testing {
     use super::*;
@@ -572,4 +1162,110 @@ mod testing {
             .is_err()
         );
     }
+
+    #[test]
+    fn test_split_offer_amount() {
+        let route = |weight| SplitRoute {
+            operations: vec![],
+            weight,
+        };
+
+        assert_eq!(
+            split_offer_amount(Uint128::new(1000), &[route(1), route(3)]).unwrap(),
+            vec![Uint128::new(250), Uint128::new(750)]
+        );
+        // The last route takes the rounding remainder
+        assert_eq!(
+            split_offer_amount(Uint128::new(100), &[route(1), route(1), route(1)]).unwrap(),
+            vec![Uint128::new(33), Uint128::new(33), Uint128::new(34)]
+        );
+        assert_eq!(
+            split_offer_amount(Uint128::new(10), &[route(1), route(100)]).unwrap_err(),
+            ContractError::ZeroSplitAmount {}
+        );
+    }
+
+    #[test]
+    fn test_invalid_split_routes() {
+        use crate::testing::mock_querier::mock_dependencies;
+        let deps = mock_dependencies(&[]);
+        let swap = |offer: &str, ask: &str| SwapOperation::OroSwap {
+            offer_asset_info: AssetInfo::NativeToken {
+                denom: offer.to_string(),
+            },
+            ask_asset_info: AssetInfo::NativeToken {
+                denom: ask.to_string(),
+            },
+            pair_type: PairType::Xyk {},
+        };
+
+        assert_eq!(
+            assert_split_routes(deps.as_ref().api, &[]).unwrap_err(),
+            ContractError::MustProvideRoutes {}
+        );
+
+        assert_eq!(
+            assert_split_routes(
+                deps.as_ref().api,
+                &[
+                    SplitRoute {
+                        operations: vec![swap("ukrw", "uluna")],
+                        weight: 1,
+                    },
+                    SplitRoute {
+                        operations: vec![swap("ukrw", "uusd")],
+                        weight: 1,
+                    },
+                ]
+            )
+            .unwrap_err(),
+            ContractError::InconsistentSplitRoutes {
+                offer_asset: "ukrw".to_string(),
+                ask_asset: "uluna".to_string(),
+            }
+        );
+
+        assert_eq!(
+            assert_split_routes(
+                deps.as_ref().api,
+                &[
+                    SplitRoute {
+                        operations: vec![swap("ukrw", "uluna")],
+                        weight: 1,
+                    },
+                    SplitRoute {
+                        operations: vec![swap("ukrw", "uusd"), swap("uusd", "uluna")],
+                        weight: 0,
+                    },
+                ]
+            )
+            .unwrap_err(),
+            ContractError::ZeroRouteWeight {}
+        );
+
+        assert_eq!(
+            assert_split_routes(
+                deps.as_ref().api,
+                &[
+                    SplitRoute {
+                        operations: vec![swap("ukrw", "uluna")],
+                        weight: 1,
+                    },
+                    SplitRoute {
+                        operations: vec![swap("ukrw", "uusd"), swap("uusd", "uluna")],
+                        weight: 2,
+                    },
+                ]
+            )
+            .unwrap(),
+            (
+                AssetInfo::NativeToken {
+                    denom: "ukrw".to_string()
+                },
+                AssetInfo::NativeToken {
+                    denom: "uluna".to_string()
+                }
+            )
+        );
+    }
 }
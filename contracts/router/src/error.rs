@@ -38,8 +38,62 @@ pub enum ContractError {
     #[error("The swap operation limit was exceeded!")]
     SwapLimitExceeded {},
 
-    #[error("Native swap operations are not supported!")]
-    NativeSwapNotSupported {},
+    #[error("No pair found to swap {offer_asset} to {ask_asset}")]
+    PairNotFound {
+        offer_asset: String,
+        ask_asset: String,
+    },
+
+    #[error("Must specify split routes!")]
+    MustProvideRoutes {},
+
+    #[error("All split routes must swap {offer_asset} to {ask_asset}")]
+    InconsistentSplitRoutes {
+        offer_asset: String,
+        ask_asset: String,
+    },
+
+    #[error("Split routes cannot swap through the same pair; {first_asset} <-> {second_asset}")]
+    SplitRoutesSharePair {
+        first_asset: String,
+        second_asset: String,
+    },
+
+    #[error("Split routes cannot swap back to the offer asset {offer_asset}")]
+    SplitRouteReturnsOfferAsset { offer_asset: String },
+
+    #[error("Split route weight must be greater than zero")]
+    ZeroRouteWeight {},
+
+    #[error("Offer amount is too small to be split between the routes")]
+    ZeroSplitAmount {},
+
+    #[error("Max hops must be between 1 and {max}")]
+    InvalidMaxHops { max: u8 },
+
+    #[error("No route found from {offer_asset} to {ask_asset}")]
+    NoRouteFound {
+        offer_asset: String,
+        ask_asset: String,
+    },
+
+    #[error("The number of hub assets cannot exceed {max}")]
+    TooManyHubAssets { max: usize },
+
+    #[error("Duplicate hub asset {asset}")]
+    DuplicateHubAsset { asset: String },
+
+    #[error("Exactly one native coin must be sent")]
+    InvalidFunds {},
+
+    #[error("Required offer amount {offer_amount} exceeds the maximum offer {max_offer}")]
+    MaxOfferExceeded {
+        max_offer: Uint128,
+        offer_amount: Uint128,
+    },
+
+    #[error("Failed to find an offer amount which returns {ask_amount}")]
+    ExactOutUnreachable { ask_amount: Uint128 },
 
     #[error("Contract can't be migrated!")]
     MigrationError {},
@@ -3,6 +3,7 @@ pub mod state;
 
 pub mod error;
 
+mod best_route;
 mod operations;
 
 #[cfg(test)]
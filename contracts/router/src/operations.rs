@@ -1,10 +1,12 @@
-use oroswap::asset::{Asset, AssetInfo};
+use oroswap::asset::{native_asset_info, Asset, AssetInfo, PairInfo};
 use oroswap::pair::ExecuteMsg as PairExecuteMsg;
-use oroswap::querier::{query_balance, query_pair_info, query_token_balance};
+use oroswap::querier::{
+    query_balance, query_best_pair, query_best_pair_reverse, query_pair_info, query_token_balance,
+};
 use oroswap::router::SwapOperation;
 use cosmwasm_std::{
-    to_json_binary, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response, StdResult,
-    WasmMsg,
+    to_json_binary, Addr, Coin, CosmosMsg, Decimal, DepsMut, Env, MessageInfo, QuerierWrapper,
+    Response, StdResult, Uint128, WasmMsg,
 };
 use cw20::Cw20ExecuteMsg;
 
@@ -31,46 +33,117 @@ pub fn execute_swap_operation(
         return Err(ContractError::Unauthorized {});
     }
 
-    let message = match operation {
+    let config = CONFIG.load(deps.storage)?;
+    let amount = match &operation.get_offer_asset_info() {
+        AssetInfo::NativeToken { denom } => {
+            query_balance(&deps.querier, env.contract.address, denom)?
+        }
+        AssetInfo::Token { contract_addr } => {
+            query_token_balance(&deps.querier, contract_addr, env.contract.address)?
+        }
+    };
+
+    let (pair_info, offer_asset_info, ask_asset_info) =
+        query_operation_pair(&deps.querier, &config.oroswap_factory, operation, amount)?;
+    let offer_asset = Asset {
+        info: offer_asset_info,
+        amount,
+    };
+
+    let message = asset_into_swap_msg(
+        pair_info.contract_addr.to_string(),
+        offer_asset,
+        ask_asset_info,
+        max_spread,
+        to,
+        single,
+    )?;
+
+    Ok(Response::new().add_message(message))
+}
+
+/// Returns the pair used by a swap operation together with the operation's offer and ask assets.
+/// [`SwapOperation::NativeSwap`] uses the factory pair between both denoms which returns
+/// the most for **offer_amount**.
+pub fn query_operation_pair(
+    querier: &QuerierWrapper,
+    factory: &Addr,
+    operation: SwapOperation,
+    offer_amount: Uint128,
+) -> Result<(PairInfo, AssetInfo, AssetInfo), ContractError> {
+    match operation {
         SwapOperation::OroSwap {
             offer_asset_info,
             ask_asset_info,
             pair_type,
         } => {
-            let config = CONFIG.load(deps.storage)?;
             let pair_info = query_pair_info(
-                &deps.querier,
-                config.oroswap_factory,
+                querier,
+                factory,
                 &[offer_asset_info.clone(), ask_asset_info.clone()],
                 pair_type,
             )?;
 
-            let amount = match &offer_asset_info {
-                AssetInfo::NativeToken { denom } => {
-                    query_balance(&deps.querier, env.contract.address, denom)?
-                }
-                AssetInfo::Token { contract_addr } => {
-                    query_token_balance(&deps.querier, contract_addr, env.contract.address)?
-                }
-            };
-            let offer_asset = Asset {
-                info: offer_asset_info,
-                amount,
-            };
+            Ok((pair_info, offer_asset_info, ask_asset_info))
+        }
+        SwapOperation::NativeSwap {
+            offer_denom,
+            ask_denom,
+        } => {
+            let offer_asset_info = native_asset_info(offer_denom);
+            let ask_asset_info = native_asset_info(ask_denom);
+            let pair_info = get_best_pair(
+                querier,
+                factory,
+                &Asset {
+                    info: offer_asset_info.clone(),
+                    amount: offer_amount,
+                },
+                &ask_asset_info,
+            )?;
 
-            asset_into_swap_msg(
-                pair_info.contract_addr.to_string(),
-                offer_asset,
-                ask_asset_info,
-                max_spread,
-                to,
-                single,
-            )?
+            Ok((pair_info, offer_asset_info, ask_asset_info))
         }
-        SwapOperation::NativeSwap { .. } => return Err(ContractError::NativeSwapNotSupported {}),
-    };
+    }
+}
 
-    Ok(Response::new().add_message(message))
+/// Gets all available pairs for the given assets and selects the one with the highest return amount.
+///
+/// * **offer_asset** asset that is swapped together with the amount to swap.
+///
+/// * **ask_asset_info** asset that is swapped to.
+pub fn get_best_pair(
+    querier: &QuerierWrapper,
+    factory: &Addr,
+    offer_asset: &Asset,
+    ask_asset_info: &AssetInfo,
+) -> Result<PairInfo, ContractError> {
+    query_best_pair(querier, factory, offer_asset, ask_asset_info)?
+        .map(|(pair, _)| pair)
+        .ok_or(ContractError::PairNotFound {
+            offer_asset: offer_asset.info.to_string(),
+            ask_asset: ask_asset_info.to_string(),
+        })
+}
+
+/// Gets all available pairs for the given assets and selects the one which requires the lowest
+/// offer amount to return **ask_asset**.
+///
+/// * **offer_asset_info** asset that is swapped.
+///
+/// * **ask_asset** asset that is swapped to together with the amount to receive.
+pub fn get_best_pair_reverse(
+    querier: &QuerierWrapper,
+    factory: &Addr,
+    offer_asset_info: &AssetInfo,
+    ask_asset: &Asset,
+) -> Result<(PairInfo, Uint128), ContractError> {
+    query_best_pair_reverse(querier, factory, offer_asset_info, ask_asset)?.ok_or(
+        ContractError::PairNotFound {
+            offer_asset: offer_asset_info.to_string(),
+            ask_asset: ask_asset.info.to_string(),
+        },
+    )
 }
 
 /// Creates a message of type [`CosmosMsg`] representing a swap operation.
@@ -11,6 +11,9 @@ pub const CONFIG: Item<Config> = Item::new("config");
 pub struct Config {
     /// The factory contract address
     pub oroswap_factory: Addr,
+    /// The intermediate assets used when searching for the best route
+    #[serde(default)]
+    pub hub_assets: Vec<AssetInfo>,
 }
 
 pub const REPLY_DATA: Item<ReplyData> = Item::new("reply_data");
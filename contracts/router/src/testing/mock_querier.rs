@@ -7,7 +7,7 @@ use cosmwasm_std::{
 use std::collections::HashMap;
 
 use oroswap::asset::{Asset, AssetInfo, PairInfo};
-use oroswap::factory::PairType;
+use oroswap::factory::{PairType, PairsResponse};
 use oroswap::pair::SimulationResponse;
 use cw20::{BalanceResponse, Cw20QueryMsg, TokenInfoResponse};
 
@@ -21,6 +21,13 @@ pub enum QueryMsg {
         offer_asset: Asset,
         ask_asset_info: Option<AssetInfo>,
     },
+    PairsByAssets {
+        asset_infos: Vec<AssetInfo>,
+    },
+    IsPairPaused {
+        asset_infos: Vec<AssetInfo>,
+        pair_type: PairType,
+    },
 }
 
 /// mock_dependencies is a drop-in replacement for cosmwasm_std::testing::mock_dependencies.
@@ -158,11 +165,34 @@ impl WasmMockQuerier {
                     }),
                 }
             }
+            QueryMsg::PairsByAssets { asset_infos } => {
+                let key = asset_infos[0].to_string() + asset_infos[1].to_string().as_str();
+                let pairs = self
+                    .oroswap_factory_querier
+                    .pairs
+                    .get(&key)
+                    .map(|(pair_addr, pair_type)| PairInfo {
+                        contract_addr: Addr::unchecked(pair_addr),
+                        liquidity_token: "liquidity".to_string(),
+                        asset_infos: asset_infos.clone(),
+                        pair_type: pair_type.clone(),
+                    })
+                    .into_iter()
+                    .collect();
+
+                SystemResult::Ok(ContractResult::from(to_json_binary(&PairsResponse {
+                    pairs,
+                })))
+            }
+            QueryMsg::IsPairPaused { .. } => {
+                SystemResult::Ok(ContractResult::from(to_json_binary(&false)))
+            }
             QueryMsg::Simulation { offer_asset, .. } => {
                 SystemResult::Ok(ContractResult::from(to_json_binary(&SimulationResponse {
                     return_amount: offer_asset.amount,
                     commission_amount: Uint128::zero(),
                     spread_amount: Uint128::zero(),
+                    tax_amount: Uint128::zero(),
                 })))
             }
         }
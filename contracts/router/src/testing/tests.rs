@@ -500,8 +500,25 @@ fn query_buy_with_routes() {
             ask_denom: "test".to_string(),
         }],
     };
-    let err = query(deps.as_ref(), env.clone(), msg).unwrap_err();
-    assert_eq!(err, ContractError::NativeSwapNotSupported {});
+    let err = query(deps.as_ref(), env.clone(), msg.clone()).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::PairNotFound {
+            offer_asset: "ukrw".to_string(),
+            ask_asset: "test".to_string(),
+        }
+    );
+
+    // Native swaps resolve to the factory pair between both denoms
+    deps.querier.with_oroswap_pairs(&[(
+        &"ukrwtest".to_string(),
+        &String::from("pair0002"),
+        PairType::Xyk {},
+    )]);
+    let res: SimulateSwapOperationsResponse =
+        from_json(&query(deps.as_ref(), env.clone(), msg).unwrap()).unwrap();
+    assert_eq!(res.amount, Uint128::from(1000000u128));
+    assert_eq!(res.router_stages[0].pair_address, "pair0002");
 }
 
 #[test]
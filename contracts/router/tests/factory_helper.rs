@@ -89,6 +89,7 @@ impl FactoryHelper {
                     is_generator_disabled: false,
                     permissioned: false,
                     pool_creation_fee: Uint128::new(1000),
+                    pool_creation_fee_denom: "uzig".to_string(),
                 },
                 PairConfig {
                     code_id: pair_code_id,
@@ -99,6 +100,7 @@ impl FactoryHelper {
                     is_generator_disabled: false,
                     permissioned: false,
                     pool_creation_fee: Uint128::new(1000),
+                    pool_creation_fee_denom: "uzig".to_string(),
                 },
                 PairConfig {
                     code_id: pcl_code_id,
@@ -109,6 +111,7 @@ impl FactoryHelper {
                     is_generator_disabled: false,
                     permissioned: false,
                     pool_creation_fee: Uint128::new(1000),
+                    pool_creation_fee_denom: "uzig".to_string(),
                 },
             ],
             token_code_id: cw20_token_code_id,
@@ -175,6 +178,7 @@ impl FactoryHelper {
                 pair_type: pair_type.clone(),
                 asset_infos: asset_infos.to_vec(),
                 init_params,
+                fee_tier: None,
             },
             &[coin(1000, "uzig")],
         )?;
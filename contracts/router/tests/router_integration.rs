@@ -3,12 +3,13 @@
 use cosmwasm_std::{coins, from_json, to_json_binary, Addr, Decimal, Empty, StdError, Uint128};
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 
-use oroswap::asset::{native_asset_info, token_asset_info, AssetInfo};
+use oroswap::asset::{native_asset_info, token_asset_info, AssetInfo, AssetInfoExt};
 use oroswap::factory::PairType;
 use oroswap::pair_concentrated::ConcentratedPoolParams;
 use oroswap::router::{
-    ExecuteMsg, InstantiateMsg, QueryMsg, SimulateSwapOperationsResponse, SwapOperation,
-    SwapResponseData,
+    ConfigResponse, ExecuteMsg, FindBestRouteResponse, InstantiateMsg, QueryMsg,
+    SimulateSplitSwapOperationsResponse, SimulateSwapOperationsResponse, SplitRoute, SwapOperation,
+    SwapResponseData, MAX_HUB_ASSETS,
 };
 use oroswap_router::error::ContractError;
 use oroswap_test::convert::f64_to_dec;
@@ -208,27 +209,6 @@ fn route_through_pairs_with_natives() {
         err.downcast::<ContractError>().unwrap(),
         ContractError::Unauthorized {}
     );
-    let err = app
-        .execute_contract(
-            owner.clone(),
-            router.clone(),
-            &ExecuteMsg::ExecuteSwapOperations {
-                operations: vec![SwapOperation::NativeSwap {
-                    offer_denom: denom_x.to_string(),
-                    ask_denom: denom_y.to_string(),
-                }],
-                to: None,
-                max_spread: None,
-                minimum_receive: None,
-            },
-            &[],
-        )
-        .unwrap_err();
-    assert_eq!(
-        err.downcast::<ContractError>().unwrap(),
-        ContractError::NativeSwapNotSupported {}
-    );
-
     let err = app
         .execute_contract(
             owner.clone(),
@@ -290,7 +270,7 @@ fn route_through_pairs_with_natives() {
     let err = app
         .execute_contract(
             owner.clone(),
-            router,
+            router.clone(),
             &ExecuteMsg::ExecuteSwapOperations {
                 operations: vec![
                     SwapOperation::OroSwap {
@@ -319,6 +299,708 @@ fn route_through_pairs_with_natives() {
             amount: 15_360_983102u128.into()
         }
     );
+
+    // Native swaps resolve to the same pairs
+    let native_swap_operations = vec![
+        SwapOperation::NativeSwap {
+            offer_denom: denom_x.to_string(),
+            ask_denom: denom_y.to_string(),
+        },
+        SwapOperation::NativeSwap {
+            offer_denom: denom_y.to_string(),
+            ask_denom: denom_z.to_string(),
+        },
+    ];
+    let simulation: SimulateSwapOperationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::SimulateSwapOperations {
+                offer_amount: 1_000_000000u128.into(),
+                operations: native_swap_operations.clone(),
+            },
+        )
+        .unwrap();
+    let oro_swap_simulation: SimulateSwapOperationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::SimulateSwapOperations {
+                offer_amount: 1_000_000000u128.into(),
+                operations: vec![
+                    SwapOperation::OroSwap {
+                        offer_asset_info: native_asset_info(denom_x.to_string()),
+                        ask_asset_info: native_asset_info(denom_y.to_string()),
+                        pair_type: PairType::Xyk {},
+                    },
+                    SwapOperation::OroSwap {
+                        offer_asset_info: native_asset_info(denom_y.to_string()),
+                        ask_asset_info: native_asset_info(denom_z.to_string()),
+                        pair_type: PairType::Stable {},
+                    },
+                ],
+            },
+        )
+        .unwrap();
+    assert_eq!(simulation, oro_swap_simulation);
+
+    let reverse_simulation: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::ReverseSimulateSwapOperations {
+                ask_amount: simulation.amount,
+                operations: native_swap_operations.clone(),
+            },
+        )
+        .unwrap();
+    assert!(reverse_simulation <= Uint128::new(1_000_000000));
+
+    mint_native(&mut app, &denom_x, 1_000_000000, &owner).unwrap();
+    let resp = app
+        .execute_contract(
+            owner.clone(),
+            router.clone(),
+            &ExecuteMsg::ExecuteSwapOperations {
+                operations: native_swap_operations,
+                minimum_receive: Some(simulation.amount),
+                to: None,
+                max_spread: None,
+            },
+            &coins(1_000_000000, denom_x),
+        )
+        .unwrap();
+    let resp_data: SwapResponseData = from_json(&resp.data.unwrap()).unwrap();
+    assert_eq!(resp_data.return_amount, simulation.amount);
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            router,
+            &ExecuteMsg::ExecuteSwapOperations {
+                operations: vec![SwapOperation::NativeSwap {
+                    offer_denom: denom_x.to_string(),
+                    ask_denom: "denom_w".to_string(),
+                }],
+                to: None,
+                max_spread: None,
+                minimum_receive: None,
+            },
+            &coins(1_000000, denom_x),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::PairNotFound {
+            offer_asset: denom_x.to_string(),
+            ask_asset: "denom_w".to_string(),
+        }
+    );
+}
+
+#[test]
+fn split_swap_between_pair_types() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let denom_x = "denom_x";
+    let denom_y = "denom_y";
+    let denom_z = "denom_z";
+
+    for (a, b, typ) in [
+        (&denom_x, &denom_y, PairType::Xyk {}),
+        (&denom_x, &denom_y, PairType::Stable {}),
+        (&denom_x, &denom_z, PairType::Xyk {}),
+        (&denom_x, &denom_z, PairType::Stable {}),
+        (&denom_z, &denom_y, PairType::Xyk {}),
+    ] {
+        let pair = helper
+            .create_pair(
+                &mut app,
+                &owner,
+                typ,
+                [
+                    native_asset_info(a.to_string()),
+                    native_asset_info(b.to_string()),
+                ],
+                None,
+            )
+            .unwrap();
+        mint_native(&mut app, a, 100_000_000000, &pair).unwrap();
+        mint_native(&mut app, b, 100_000_000000, &pair).unwrap();
+    }
+
+    let router_code = app.store_code(router_contract());
+    let router = app
+        .instantiate_contract(
+            router_code,
+            owner.clone(),
+            &InstantiateMsg {
+                oroswap_factory: helper.factory.to_string(),
+            },
+            &[],
+            "router",
+            None,
+        )
+        .unwrap();
+
+    let swap = |offer: &str, ask: &str, pair_type: PairType| SwapOperation::OroSwap {
+        offer_asset_info: native_asset_info(offer.to_string()),
+        ask_asset_info: native_asset_info(ask.to_string()),
+        pair_type,
+    };
+    let routes = vec![
+        SplitRoute {
+            operations: vec![swap(denom_x, denom_y, PairType::Xyk {})],
+            weight: 1,
+        },
+        SplitRoute {
+            operations: vec![swap(denom_x, denom_y, PairType::Stable {})],
+            weight: 1,
+        },
+        SplitRoute {
+            operations: vec![
+                swap(denom_x, denom_z, PairType::Xyk {}),
+                swap(denom_z, denom_y, PairType::Xyk {}),
+            ],
+            weight: 2,
+        },
+    ];
+
+    let simulation: SimulateSplitSwapOperationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::SimulateSplitSwapOperations {
+                offer_amount: 40_000_000000u128.into(),
+                routes: routes.clone(),
+            },
+        )
+        .unwrap();
+    let offer_amounts: Vec<_> = simulation
+        .routes
+        .iter()
+        .map(|route| route.offer_amount.u128())
+        .collect();
+    assert_eq!(offer_amounts, [10_000_000000, 10_000_000000, 20_000_000000]);
+    assert_eq!(simulation.routes[2].router_stages.len(), 2);
+    // Equal pools with equal shares return the same amount
+    assert_eq!(
+        simulation.routes[0].return_amount,
+        simulation.routes[1].return_amount
+    );
+    assert_eq!(
+        simulation.amount,
+        simulation
+            .routes
+            .iter()
+            .map(|route| route.return_amount)
+            .sum::<Uint128>()
+    );
+
+    // Splitting the order gives a better price than swapping it in one pool
+    let single: SimulateSwapOperationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::SimulateSwapOperations {
+                offer_amount: 40_000_000000u128.into(),
+                operations: vec![swap(denom_x, denom_y, PairType::Xyk {})],
+            },
+        )
+        .unwrap();
+    assert!(simulation.amount > single.amount);
+
+    // The summed output is checked against minimum_receive
+    mint_native(&mut app, denom_x, 40_000_000000, &owner).unwrap();
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            router.clone(),
+            &ExecuteMsg::ExecuteSplitSwapOperations {
+                routes: routes.clone(),
+                minimum_receive: Some(simulation.amount + Uint128::one()),
+                to: None,
+                max_spread: Some(f64_to_dec(0.5)),
+            },
+            &coins(40_000_000000, denom_x),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AssertionMinimumReceive {
+            receive: simulation.amount + Uint128::one(),
+            amount: simulation.amount,
+        }
+    );
+
+    let resp = app
+        .execute_contract(
+            owner.clone(),
+            router.clone(),
+            &ExecuteMsg::ExecuteSplitSwapOperations {
+                routes,
+                minimum_receive: Some(simulation.amount),
+                to: None,
+                max_spread: Some(f64_to_dec(0.5)),
+            },
+            &coins(40_000_000000, denom_x),
+        )
+        .unwrap();
+    let resp_data: SwapResponseData = from_json(&resp.data.unwrap()).unwrap();
+    assert_eq!(resp_data.return_amount, simulation.amount);
+
+    let balance = app.wrap().query_balance(&owner, denom_y).unwrap();
+    assert_eq!(balance.amount, simulation.amount);
+    for denom in [denom_x, denom_y, denom_z] {
+        let router_balance = app.wrap().query_balance(&router, denom).unwrap();
+        assert!(router_balance.amount.is_zero());
+    }
+
+    // All routes must swap the same assets
+    mint_native(&mut app, denom_x, 1_000000, &owner).unwrap();
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            router.clone(),
+            &ExecuteMsg::ExecuteSplitSwapOperations {
+                routes: vec![
+                    SplitRoute {
+                        operations: vec![swap(denom_x, denom_y, PairType::Xyk {})],
+                        weight: 1,
+                    },
+                    SplitRoute {
+                        operations: vec![swap(denom_x, denom_z, PairType::Xyk {})],
+                        weight: 1,
+                    },
+                ],
+                minimum_receive: None,
+                to: None,
+                max_spread: None,
+            },
+            &coins(1_000000, denom_x),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InconsistentSplitRoutes {
+            offer_asset: denom_x.to_string(),
+            ask_asset: denom_y.to_string(),
+        }
+    );
+
+    // Routes can't share a hop as each of them is simulated against the same pool balances
+    let shared_hop_routes = vec![
+        SplitRoute {
+            operations: vec![
+                swap(denom_x, denom_z, PairType::Xyk {}),
+                swap(denom_z, denom_y, PairType::Xyk {}),
+            ],
+            weight: 1,
+        },
+        SplitRoute {
+            operations: vec![
+                swap(denom_x, denom_z, PairType::Stable {}),
+                swap(denom_z, denom_y, PairType::Xyk {}),
+            ],
+            weight: 1,
+        },
+    ];
+    let err = app
+        .wrap()
+        .query_wasm_smart::<SimulateSplitSwapOperationsResponse>(
+            &router,
+            &QueryMsg::SimulateSplitSwapOperations {
+                offer_amount: 1_000000u128.into(),
+                routes: shared_hop_routes.clone(),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err(
+            "Querier contract error: Split routes cannot swap through the same pair; \
+             denom_y <-> denom_z"
+        )
+    );
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            router.clone(),
+            &ExecuteMsg::ExecuteSplitSwapOperations {
+                routes: shared_hop_routes,
+                minimum_receive: None,
+                to: None,
+                max_spread: None,
+            },
+            &coins(1_000000, denom_x),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::SplitRoutesSharePair {
+            first_asset: denom_y.to_string(),
+            second_asset: denom_z.to_string(),
+        }
+    );
+
+    // Routes can't pass through the offer asset while other routes still hold their offer amounts
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            router,
+            &ExecuteMsg::ExecuteSplitSwapOperations {
+                routes: vec![
+                    SplitRoute {
+                        operations: vec![swap(denom_x, denom_y, PairType::Xyk {})],
+                        weight: 1,
+                    },
+                    SplitRoute {
+                        operations: vec![
+                            swap(denom_x, denom_z, PairType::Xyk {}),
+                            swap(denom_z, denom_x, PairType::Stable {}),
+                            swap(denom_x, denom_y, PairType::Stable {}),
+                        ],
+                        weight: 1,
+                    },
+                ],
+                minimum_receive: None,
+                to: None,
+                max_spread: None,
+            },
+            &coins(1_000000, denom_x),
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::SplitRouteReturnsOfferAsset {
+            offer_asset: denom_x.to_string(),
+        }
+    );
+}
+
+#[test]
+fn exact_out_swap_refunds_unused_offer() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let denom_x = "denom_x";
+    let denom_y = "denom_y";
+    let denom_z = "denom_z";
+
+    for (a, b, typ, liq) in [
+        (&denom_x, &denom_y, PairType::Xyk {}, 100_000_000000),
+        (&denom_y, &denom_z, PairType::Stable {}, 1_000_000_000000),
+    ] {
+        let pair = helper
+            .create_pair(
+                &mut app,
+                &owner,
+                typ,
+                [
+                    native_asset_info(a.to_string()),
+                    native_asset_info(b.to_string()),
+                ],
+                None,
+            )
+            .unwrap();
+        mint_native(&mut app, a, liq, &pair).unwrap();
+        mint_native(&mut app, b, liq, &pair).unwrap();
+    }
+
+    let router_code = app.store_code(router_contract());
+    let router = app
+        .instantiate_contract(
+            router_code,
+            owner.clone(),
+            &InstantiateMsg {
+                oroswap_factory: helper.factory.to_string(),
+            },
+            &[],
+            "router",
+            None,
+        )
+        .unwrap();
+
+    let operations = vec![
+        SwapOperation::OroSwap {
+            offer_asset_info: native_asset_info(denom_x.to_string()),
+            ask_asset_info: native_asset_info(denom_y.to_string()),
+            pair_type: PairType::Xyk {},
+        },
+        SwapOperation::OroSwap {
+            offer_asset_info: native_asset_info(denom_y.to_string()),
+            ask_asset_info: native_asset_info(denom_z.to_string()),
+            pair_type: PairType::Stable {},
+        },
+    ];
+    let ask_amount = Uint128::new(10_000_000000);
+
+    let reverse_offer: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::ReverseSimulateSwapOperations {
+                ask_amount,
+                operations: operations.clone(),
+            },
+        )
+        .unwrap();
+
+    mint_native(&mut app, denom_x, 50_000_000000, &owner).unwrap();
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            router.clone(),
+            &ExecuteMsg::ExecuteSwapOperationsExactOut {
+                operations: operations.clone(),
+                ask_amount,
+                max_offer: reverse_offer - Uint128::new(1_000000),
+                to: None,
+                max_spread: None,
+            },
+            &coins(50_000_000000, denom_x),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast().unwrap(),
+        ContractError::MaxOfferExceeded { .. }
+    ));
+
+    app.execute_contract(
+        owner.clone(),
+        router.clone(),
+        &ExecuteMsg::ExecuteSwapOperationsExactOut {
+            operations,
+            ask_amount,
+            max_offer: 50_000_000000u128.into(),
+            to: None,
+            max_spread: None,
+        },
+        &coins(50_000_000000, denom_x),
+    )
+    .unwrap();
+
+    // The recipient gets the ask amount, rounding may add a few units on top
+    let received = app.wrap().query_balance(&owner, denom_z).unwrap().amount;
+    assert!(received >= ask_amount && received - ask_amount < Uint128::new(10));
+
+    // Everything but the swapped amount was refunded
+    let spent =
+        Uint128::new(50_000_000000) - app.wrap().query_balance(&owner, denom_x).unwrap().amount;
+    assert!(spent >= reverse_offer && spent - reverse_offer < Uint128::new(10));
+    let router_balance = app.wrap().query_balance(&router, denom_x).unwrap();
+    assert!(router_balance.amount.is_zero());
+}
+
+#[test]
+fn find_and_execute_best_route() {
+    let mut app = mock_app();
+
+    let owner = Addr::unchecked("owner");
+    let mut helper = FactoryHelper::init(&mut app, &owner);
+
+    let denom_x = "denom_x";
+    let denom_y = "denom_y";
+    let denom_z = "denom_z";
+
+    // The direct pools are shallow, the route through denom_z is deep
+    for (a, b, typ, liq) in [
+        (&denom_x, &denom_y, PairType::Xyk {}, 1_000_000000),
+        (&denom_x, &denom_y, PairType::Stable {}, 2_000_000000),
+        (&denom_x, &denom_z, PairType::Xyk {}, 100_000_000000),
+        (&denom_z, &denom_y, PairType::Xyk {}, 100_000_000000),
+    ] {
+        let pair = helper
+            .create_pair(
+                &mut app,
+                &owner,
+                typ,
+                [
+                    native_asset_info(a.to_string()),
+                    native_asset_info(b.to_string()),
+                ],
+                None,
+            )
+            .unwrap();
+        mint_native(&mut app, a, liq, &pair).unwrap();
+        mint_native(&mut app, b, liq, &pair).unwrap();
+    }
+
+    let router_code = app.store_code(router_contract());
+    let router = app
+        .instantiate_contract(
+            router_code,
+            owner.clone(),
+            &InstantiateMsg {
+                oroswap_factory: helper.factory.to_string(),
+            },
+            &[],
+            "router",
+            None,
+        )
+        .unwrap();
+
+    let swap = |offer: &str, ask: &str, pair_type: PairType| SwapOperation::OroSwap {
+        offer_asset_info: native_asset_info(offer.to_string()),
+        ask_asset_info: native_asset_info(ask.to_string()),
+        pair_type,
+    };
+    let find_best_route = |app: &App, max_hops: Option<u8>| {
+        app.wrap().query_wasm_smart::<FindBestRouteResponse>(
+            &router,
+            &QueryMsg::FindBestRoute {
+                offer_asset: native_asset_info(denom_x.to_string()).with_balance(1_000_000000u128),
+                ask_asset_info: native_asset_info(denom_y.to_string()),
+                max_hops,
+            },
+        )
+    };
+
+    // Only direct pools are considered with a single hop
+    let best_route = find_best_route(&app, Some(1)).unwrap();
+    assert_eq!(
+        best_route.operations,
+        vec![swap(denom_x, denom_y, PairType::Stable {})]
+    );
+
+    // Routes only go through hub assets which are not configured yet
+    let best_route = find_best_route(&app, None).unwrap();
+    assert_eq!(
+        best_route.operations,
+        vec![swap(denom_x, denom_y, PairType::Stable {})]
+    );
+
+    let update_hub_assets = |app: &mut App, sender: &Addr, hub_assets: Vec<&str>| {
+        app.execute_contract(
+            sender.clone(),
+            router.clone(),
+            &ExecuteMsg::UpdateHubAssets {
+                hub_assets: hub_assets
+                    .into_iter()
+                    .map(|denom| native_asset_info(denom.to_string()))
+                    .collect(),
+            },
+            &[],
+        )
+    };
+
+    let err = update_hub_assets(&mut app, &Addr::unchecked("random"), vec![denom_z]).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+    let err = update_hub_assets(&mut app, &owner, vec![denom_z, denom_z]).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::DuplicateHubAsset {
+            asset: denom_z.to_string()
+        }
+    );
+    let err = update_hub_assets(&mut app, &owner, vec![denom_z; MAX_HUB_ASSETS + 1]).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::TooManyHubAssets {
+            max: MAX_HUB_ASSETS
+        }
+    );
+
+    update_hub_assets(&mut app, &owner, vec![denom_z]).unwrap();
+    let config: ConfigResponse = app
+        .wrap()
+        .query_wasm_smart(&router, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        config.hub_assets,
+        vec![native_asset_info(denom_z.to_string())]
+    );
+
+    let best_route = find_best_route(&app, None).unwrap();
+    assert_eq!(
+        best_route.operations,
+        vec![
+            swap(denom_x, denom_z, PairType::Xyk {}),
+            swap(denom_z, denom_y, PairType::Xyk {}),
+        ]
+    );
+    let simulation: SimulateSwapOperationsResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &router,
+            &QueryMsg::SimulateSwapOperations {
+                offer_amount: 1_000_000000u128.into(),
+                operations: best_route.operations.clone(),
+            },
+        )
+        .unwrap();
+    assert_eq!(best_route.simulation, simulation);
+
+    let err = find_best_route(&app, Some(4)).unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Querier contract error: Max hops must be between 1 and 3")
+    );
+
+    let err = app
+        .wrap()
+        .query_wasm_smart::<FindBestRouteResponse>(
+            &router,
+            &QueryMsg::FindBestRoute {
+                offer_asset: native_asset_info(denom_x.to_string()).with_balance(1_000000u128),
+                ask_asset_info: native_asset_info("denom_w".to_string()),
+                max_hops: None,
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        StdError::generic_err("Querier contract error: No route found from denom_x to denom_w")
+    );
+
+    mint_native(&mut app, denom_x, 1_000_000000, &owner).unwrap();
+    let resp = app
+        .execute_contract(
+            owner.clone(),
+            router.clone(),
+            &ExecuteMsg::ExecuteBestRoute {
+                ask_asset_info: native_asset_info(denom_y.to_string()),
+                max_hops: None,
+                minimum_receive: Some(best_route.simulation.amount),
+                to: None,
+                max_spread: None,
+            },
+            &coins(1_000_000000, denom_x),
+        )
+        .unwrap();
+    let resp_data: SwapResponseData = from_json(&resp.data.unwrap()).unwrap();
+    assert_eq!(resp_data.return_amount, best_route.simulation.amount);
+    assert_eq!(
+        app.wrap().query_balance(&owner, denom_y).unwrap().amount,
+        best_route.simulation.amount
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            router,
+            &ExecuteMsg::ExecuteBestRoute {
+                ask_asset_info: native_asset_info(denom_y.to_string()),
+                max_hops: None,
+                minimum_receive: None,
+                to: None,
+                max_spread: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidFunds {}
+    );
 }
 
 #[test]
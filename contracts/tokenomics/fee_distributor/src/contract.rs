@@ -0,0 +1,228 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, coins, to_json_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+
+use oroswap::fee_distributor::{
+    ClaimableResponse, Config, ExecuteMsg, InstantiateMsg, QueryMsg, DEFAULT_CLAIM_LIMIT,
+    MAX_CLAIM_LIMIT,
+};
+use oroswap::incentives::epoch_start;
+use oroswap::querier::query_balance;
+use oroswap::voting_escrow::QueryMsg as VotingEscrowQueryMsg;
+
+use crate::error::ContractError;
+use crate::state::{read_epochs, CONFIG, EPOCH_TOKENS, LAST_TOKEN_BALANCE, USER_LAST_EPOCH};
+
+/// Contract name that is used for migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+/// Contract version that is used for migration.
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            voting_escrow: deps.api.addr_validate(&msg.voting_escrow)?,
+            token_denom: msg.token_denom.clone(),
+        },
+    )?;
+    LAST_TOKEN_BALANCE.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "instantiate"),
+        attr("contract", CONTRACT_NAME),
+        attr("token_denom", msg.token_denom),
+    ]))
+}
+
+/// Exposes execute functions available in the contract.
+///
+/// * **ExecuteMsg::CheckpointToken {}** Assigns ORO received since the last checkpoint to the current epoch.
+///
+/// * **ExecuteMsg::Claim { recipient, limit }** Claims the sender's share of ORO from finished epochs.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::CheckpointToken {} => {
+            let amount = checkpoint_token(deps, &env)?;
+            Ok(Response::new().add_attributes(vec![
+                attr("action", "checkpoint_token"),
+                attr("epoch", epoch_start(env.block.time.seconds()).to_string()),
+                attr("amount", amount),
+            ]))
+        }
+        ExecuteMsg::Claim { recipient, limit } => claim(deps, env, info, recipient, limit),
+    }
+}
+
+/// Assigns ORO received since the last checkpoint to the current epoch and returns the assigned amount.
+///
+/// ORO is left for a later checkpoint while there is no voting power in the current epoch,
+/// otherwise it could never be claimed.
+fn checkpoint_token(deps: DepsMut, env: &Env) -> Result<Uint128, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let balance = query_balance(&deps.querier, &env.contract.address, &config.token_denom)?;
+    let last_balance = LAST_TOKEN_BALANCE.load(deps.storage)?;
+    let amount = balance.checked_sub(last_balance)?;
+    if amount.is_zero() {
+        return Ok(amount);
+    }
+
+    let epoch = epoch_start(env.block.time.seconds());
+    if total_voting_power_at(deps.as_ref(), &config, epoch)?.is_zero() {
+        return Ok(Uint128::zero());
+    }
+
+    EPOCH_TOKENS.update(deps.storage, epoch, |tokens| -> StdResult<_> {
+        Ok(tokens.unwrap_or_default().checked_add(amount)?)
+    })?;
+    LAST_TOKEN_BALANCE.save(deps.storage, &balance)?;
+
+    Ok(amount)
+}
+
+/// Claims the sender's share of ORO from at most **limit** finished epochs.
+/// The ORO is sent to **recipient** or to the sender if it is not set.
+fn claim(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: Option<String>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => info.sender.clone(),
+    };
+
+    checkpoint_token(deps.branch(), &env)?;
+
+    let claimable = compute_claimable(deps.as_ref(), &env, &info.sender, limit)?;
+    let mut response = Response::new().add_attributes(vec![
+        attr("action", "claim"),
+        attr("user", &info.sender),
+        attr("recipient", &recipient),
+        attr("amount", claimable.amount),
+    ]);
+
+    if let Some(last_epoch) = claimable.last_epoch {
+        USER_LAST_EPOCH.save(deps.storage, &info.sender, &last_epoch)?;
+        response = response.add_attribute("last_epoch", last_epoch.to_string());
+    }
+
+    if !claimable.amount.is_zero() {
+        let config = CONFIG.load(deps.storage)?;
+        LAST_TOKEN_BALANCE.update(deps.storage, |balance| -> StdResult<_> {
+            Ok(balance.checked_sub(claimable.amount)?)
+        })?;
+        response = response.add_message(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: coins(claimable.amount.u128(), config.token_denom),
+        });
+    }
+
+    Ok(response)
+}
+
+/// Computes the share of ORO that **user** can claim from at most **limit** finished epochs
+/// following the last epoch the user claimed from.
+///
+/// The share in each epoch is proportional to the user's voting power at the epoch start.
+fn compute_claimable(
+    deps: Deps,
+    env: &Env,
+    user: &Addr,
+    limit: Option<u32>,
+) -> StdResult<ClaimableResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let limit = limit.unwrap_or(DEFAULT_CLAIM_LIMIT).min(MAX_CLAIM_LIMIT) as usize;
+    let last_claimed = USER_LAST_EPOCH.may_load(deps.storage, user)?;
+    let current_epoch = epoch_start(env.block.time.seconds());
+
+    let epochs = EPOCH_TOKENS
+        .range(
+            deps.storage,
+            last_claimed.map(Bound::exclusive),
+            Some(Bound::exclusive(current_epoch)),
+            Order::Ascending,
+        )
+        .take(limit + 1)
+        .collect::<StdResult<Vec<_>>>()?;
+    let has_more = epochs.len() > limit;
+
+    let mut amount = Uint128::zero();
+    let mut last_epoch = None;
+    for (epoch, tokens) in epochs.into_iter().take(limit) {
+        last_epoch = Some(epoch);
+
+        let voting_power: Uint128 = deps.querier.query_wasm_smart(
+            &config.voting_escrow,
+            &VotingEscrowQueryMsg::BalanceAt {
+                address: user.to_string(),
+                timestamp: Some(epoch),
+            },
+        )?;
+        if voting_power.is_zero() {
+            continue;
+        }
+
+        let total_voting_power = total_voting_power_at(deps, &config, epoch)?;
+        amount = amount.checked_add(tokens.multiply_ratio(voting_power, total_voting_power))?;
+    }
+
+    Ok(ClaimableResponse {
+        amount,
+        last_epoch,
+        has_more,
+    })
+}
+
+/// Returns the total vxORO voting power at **timestamp**.
+fn total_voting_power_at(deps: Deps, config: &Config, timestamp: u64) -> StdResult<Uint128> {
+    deps.querier.query_wasm_smart(
+        &config.voting_escrow,
+        &VotingEscrowQueryMsg::TotalSupplyAt {
+            timestamp: Some(timestamp),
+        },
+    )
+}
+
+/// Exposes all the queries available in the contract.
+///
+/// * **QueryMsg::Config {}** Returns the fee distributor contract configuration
+///
+/// * **QueryMsg::Claimable { address, limit }** Returns the amount of ORO the given address can claim
+///
+/// * **QueryMsg::Epochs { start_after, limit }** Returns the amount of ORO distributed in each epoch
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Claimable { address, limit } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_json_binary(&compute_claimable(deps, &env, &address, limit)?)
+        }
+        QueryMsg::Epochs { start_after, limit } => {
+            to_json_binary(&read_epochs(deps.storage, start_after, limit)?)
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+
+use oroswap::fee_distributor::{Config, EpochInfo};
+
+/// Stores the contract config at the given key
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// The ORO balance of the contract at the last checkpoint minus claims since then
+pub const LAST_TOKEN_BALANCE: Item<Uint128> = Item::new("last_token_balance");
+
+/// The amount of ORO distributed in an epoch. The key is the epoch start timestamp.
+pub const EPOCH_TOKENS: Map<u64, Uint128> = Map::new("epoch_tokens");
+
+/// The start of the last epoch an address claimed from
+pub const USER_LAST_EPOCH: Map<&Addr, u64> = Map::new("user_last_epoch");
+
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Returns the amount of ORO distributed in each epoch in ascending order.
+/// ## Params
+///
+/// * **start_after** epoch start timestamp from which to start reading.
+///
+/// * **limit** amount of epochs to read.
+pub fn read_epochs(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<EpochInfo>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    EPOCH_TOKENS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(start, amount)| EpochInfo { start, amount }))
+        .collect()
+}
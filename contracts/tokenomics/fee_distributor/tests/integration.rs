@@ -0,0 +1,338 @@
+#![cfg(not(tarpaulin_include))]
+
+use cosmwasm_std::{coin, coins, Addr, Decimal, Timestamp, Uint128};
+use cw_multi_test::{App, AppResponse, ContractWrapper, Executor};
+
+use oroswap::fee_distributor::{
+    ClaimableResponse, Config, EpochInfo, ExecuteMsg, InstantiateMsg, QueryMsg,
+};
+use oroswap::incentives::{EPOCHS_START, EPOCH_LENGTH};
+use oroswap::voting_escrow;
+
+const OWNER: &str = "owner";
+const MAKER: &str = "maker";
+const USER1: &str = "user1";
+const USER2: &str = "user2";
+const USER3: &str = "user3";
+const ORO: &str = "oro";
+const XORO: &str = "xoro";
+/// The start of the first epoch used in tests
+const EPOCH0: u64 = EPOCHS_START + 10 * EPOCH_LENGTH;
+
+struct Helper {
+    app: App,
+    escrow: Addr,
+    distributor: Addr,
+}
+
+impl Helper {
+    fn new() -> Self {
+        let mut app = App::new(|app, _, storage| {
+            app.bank
+                .init_balance(storage, &Addr::unchecked(MAKER), coins(100_000, ORO))
+                .unwrap();
+            for user in [USER1, USER2, USER3] {
+                app.bank
+                    .init_balance(storage, &Addr::unchecked(user), coins(10_000, XORO))
+                    .unwrap();
+            }
+        });
+        // Start one day before the first epoch
+        app.update_block(|block| block.time = Timestamp::from_seconds(EPOCH0 - 86400));
+
+        let escrow_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            oroswap_voting_escrow::contract::execute,
+            oroswap_voting_escrow::contract::instantiate,
+            oroswap_voting_escrow::contract::query,
+        )));
+        let escrow = app
+            .instantiate_contract(
+                escrow_code_id,
+                Addr::unchecked(OWNER),
+                &voting_escrow::InstantiateMsg {
+                    owner: OWNER.to_string(),
+                    deposit_denom: XORO.to_string(),
+                    maker: MAKER.to_string(),
+                    max_lock_weeks: 104,
+                    max_exit_penalty: Decimal::percent(50),
+                },
+                &[],
+                "vxORO",
+                None,
+            )
+            .unwrap();
+
+        let distributor_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            oroswap_fee_distributor::contract::execute,
+            oroswap_fee_distributor::contract::instantiate,
+            oroswap_fee_distributor::contract::query,
+        )));
+        let distributor = app
+            .instantiate_contract(
+                distributor_code_id,
+                Addr::unchecked(OWNER),
+                &InstantiateMsg {
+                    voting_escrow: escrow.to_string(),
+                    token_denom: ORO.to_string(),
+                },
+                &[],
+                "Fee distributor",
+                None,
+            )
+            .unwrap();
+
+        Self {
+            app,
+            escrow,
+            distributor,
+        }
+    }
+
+    fn create_lock(&mut self, user: &str, amount: u128) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(user),
+                self.escrow.clone(),
+                &voting_escrow::ExecuteMsg::CreateLock { weeks: 52 },
+                &coins(amount, XORO),
+            )
+            .unwrap();
+    }
+
+    /// Sends ORO from the Maker and checkpoints it
+    fn distribute(&mut self, amount: u128) {
+        self.app
+            .send_tokens(
+                Addr::unchecked(MAKER),
+                self.distributor.clone(),
+                &coins(amount, ORO),
+            )
+            .unwrap();
+        self.app
+            .execute_contract(
+                Addr::unchecked(MAKER),
+                self.distributor.clone(),
+                &ExecuteMsg::CheckpointToken {},
+                &[],
+            )
+            .unwrap();
+    }
+
+    fn claim(&mut self, user: &str, limit: Option<u32>) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(user),
+            self.distributor.clone(),
+            &ExecuteMsg::Claim {
+                recipient: None,
+                limit,
+            },
+            &[],
+        )
+    }
+
+    fn claimable(&self, user: &str, limit: Option<u32>) -> ClaimableResponse {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.distributor,
+                &QueryMsg::Claimable {
+                    address: user.to_string(),
+                    limit,
+                },
+            )
+            .unwrap()
+    }
+
+    fn epochs(&self) -> Vec<EpochInfo> {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.distributor,
+                &QueryMsg::Epochs {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    }
+
+    fn voting_power_at(&self, user: Option<&str>, timestamp: u64) -> Uint128 {
+        let msg = match user {
+            Some(user) => voting_escrow::QueryMsg::BalanceAt {
+                address: user.to_string(),
+                timestamp: Some(timestamp),
+            },
+            None => voting_escrow::QueryMsg::TotalSupplyAt {
+                timestamp: Some(timestamp),
+            },
+        };
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.escrow, &msg)
+            .unwrap()
+    }
+
+    fn oro_balance(&self, user: &str) -> u128 {
+        self.app
+            .wrap()
+            .query_balance(user, ORO)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
+    fn set_time(&mut self, timestamp: u64) {
+        self.app
+            .update_block(|block| block.time = Timestamp::from_seconds(timestamp));
+    }
+}
+
+#[test]
+fn distributes_fees_by_voting_power() {
+    let mut helper = Helper::new();
+
+    let config: Config = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&helper.distributor, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            voting_escrow: helper.escrow.clone(),
+            token_denom: ORO.to_string(),
+        }
+    );
+
+    // Nobody has voting power yet so ORO waits for a later checkpoint
+    helper.distribute(4000);
+    assert_eq!(helper.epochs(), vec![]);
+
+    helper.create_lock(USER1, 1000);
+    helper.create_lock(USER2, 3000);
+
+    // USER3 locks after the epoch start and doesn't get a share of it
+    helper.set_time(EPOCH0 + 86400);
+    helper.create_lock(USER3, 5000);
+
+    helper.distribute(6000);
+    assert_eq!(
+        helper.epochs(),
+        vec![EpochInfo {
+            start: EPOCH0,
+            amount: Uint128::new(10_000),
+        }]
+    );
+
+    // The epoch hasn't finished yet
+    assert_eq!(
+        helper.claimable(USER1, None),
+        ClaimableResponse {
+            amount: Uint128::zero(),
+            last_epoch: None,
+            has_more: false,
+        }
+    );
+
+    helper.set_time(EPOCH0 + EPOCH_LENGTH);
+
+    let total = helper.voting_power_at(None, EPOCH0);
+    let user1_share = Uint128::new(10_000)
+        .multiply_ratio(helper.voting_power_at(Some(USER1), EPOCH0), total)
+        .u128();
+    let user2_share = Uint128::new(10_000)
+        .multiply_ratio(helper.voting_power_at(Some(USER2), EPOCH0), total)
+        .u128();
+    // USER2 locked three times more than USER1
+    assert_eq!((user1_share, user2_share), (2497, 7497));
+
+    assert_eq!(
+        helper.claimable(USER1, None),
+        ClaimableResponse {
+            amount: Uint128::new(user1_share),
+            last_epoch: Some(EPOCH0),
+            has_more: false,
+        }
+    );
+
+    helper.claim(USER1, None).unwrap();
+    helper.claim(USER2, None).unwrap();
+    helper.claim(USER3, None).unwrap();
+
+    assert_eq!(helper.oro_balance(USER1), user1_share);
+    assert_eq!(helper.oro_balance(USER2), user2_share);
+    assert_eq!(helper.oro_balance(USER3), 0);
+
+    // Claiming again doesn't send anything
+    let resp = helper.claim(USER1, None).unwrap();
+    assert!(resp.events.iter().all(|event| event.ty != "transfer"));
+    assert_eq!(helper.oro_balance(USER1), user1_share);
+
+    // Claimed ORO isn't distributed again
+    helper.distribute(1000);
+    assert_eq!(
+        helper.epochs()[1],
+        EpochInfo {
+            start: EPOCH0 + EPOCH_LENGTH,
+            amount: Uint128::new(1000),
+        }
+    );
+}
+
+#[test]
+fn claims_are_paginated() {
+    let mut helper = Helper::new();
+
+    helper.create_lock(USER1, 1000);
+
+    for (week, amount) in [(0, 1000), (1, 2000), (2, 3000)] {
+        helper.set_time(EPOCH0 + week * EPOCH_LENGTH + 86400);
+        helper.distribute(amount);
+    }
+
+    helper.set_time(EPOCH0 + 3 * EPOCH_LENGTH);
+
+    assert_eq!(
+        helper.claimable(USER1, Some(2)),
+        ClaimableResponse {
+            amount: Uint128::new(3000),
+            last_epoch: Some(EPOCH0 + EPOCH_LENGTH),
+            has_more: true,
+        }
+    );
+
+    helper.claim(USER1, Some(2)).unwrap();
+    assert_eq!(helper.oro_balance(USER1), 3000);
+
+    assert_eq!(
+        helper.claimable(USER1, Some(2)),
+        ClaimableResponse {
+            amount: Uint128::new(3000),
+            last_epoch: Some(EPOCH0 + 2 * EPOCH_LENGTH),
+            has_more: false,
+        }
+    );
+
+    helper
+        .app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            helper.distributor.clone(),
+            &ExecuteMsg::Claim {
+                recipient: Some(USER2.to_string()),
+                limit: None,
+            },
+            &[],
+        )
+        .unwrap();
+    assert_eq!(helper.oro_balance(USER1), 3000);
+    assert_eq!(helper.oro_balance(USER2), 3000);
+    assert_eq!(
+        helper
+            .app
+            .wrap()
+            .query_balance(&helper.distributor, ORO)
+            .unwrap(),
+        coin(0, ORO)
+    );
+}
@@ -0,0 +1,340 @@
+use std::collections::HashSet;
+
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, ensure, to_json_binary, wasm_execute, Binary, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Order, Response, StdError, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+
+use oroswap::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
+use oroswap::factory::{self, PairType};
+use oroswap::generator_controller::{
+    Config, ExecuteMsg, InstantiateMsg, QueryMsg, TuneInfo, UserVote, MAX_POOLS_LIMIT,
+    VOTE_WEIGHTS_SUM,
+};
+use oroswap::incentives::{self, epoch_start, EPOCH_LENGTH};
+use oroswap::voting_escrow::QueryMsg as VotingEscrowQueryMsg;
+
+use crate::error::ContractError;
+use crate::state::{CONFIG, OWNERSHIP_PROPOSAL, POOL_VOTES, TUNE_INFO, USER_VOTES};
+use crate::utils::{apply_pool_cap, query_blocked_tokens, query_lp_pair};
+
+/// Contract name that is used for migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+/// Contract version that is used for migration.
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    validate_pools_limit(msg.pools_limit)?;
+    validate_pool_cap(msg.pool_cap)?;
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: deps.api.addr_validate(&msg.owner)?,
+            voting_escrow: deps.api.addr_validate(&msg.voting_escrow)?,
+            factory: deps.api.addr_validate(&msg.factory)?,
+            incentives: deps.api.addr_validate(&msg.incentives)?,
+            pools_limit: msg.pools_limit,
+            pool_cap: msg.pool_cap,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "instantiate"),
+        attr("contract", CONTRACT_NAME),
+    ]))
+}
+
+/// Exposes execute functions available in the contract.
+///
+/// * **ExecuteMsg::Vote { votes }** Splits the sender's voting power between LP tokens.
+///
+/// * **ExecuteMsg::TunePools {}** Pushes alloc points based on the votes of the previous epoch to the incentives contract.
+///
+/// * **ExecuteMsg::UpdateConfig { pools_limit, pool_cap }** Updates contract parameters.
+///
+/// * **ExecuteMsg::ProposeNewOwner { owner, expires_in }** Creates a new request to change contract ownership.
+///
+/// * **ExecuteMsg::DropOwnershipProposal {}** Removes a request to change contract ownership.
+///
+/// * **ExecuteMsg::ClaimOwnership {}** Claims contract ownership.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Vote { votes } => vote(deps, env, info, votes),
+        ExecuteMsg::TunePools {} => tune_pools(deps, env),
+        ExecuteMsg::UpdateConfig {
+            pools_limit,
+            pool_cap,
+        } => update_config(deps, info, pools_limit, pool_cap),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            propose_new_owner(
+                deps,
+                info,
+                env,
+                owner,
+                expires_in,
+                config.owner,
+                OWNERSHIP_PROPOSAL,
+            )
+            .map_err(Into::into)
+        }
+        ExecuteMsg::DropOwnershipProposal {} => {
+            let config = CONFIG.load(deps.storage)?;
+
+            drop_ownership_proposal(deps, info, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(Into::into)
+        }
+        ExecuteMsg::ClaimOwnership {} => {
+            claim_ownership(deps, info, env, OWNERSHIP_PROPOSAL, |deps, new_owner| {
+                CONFIG.update::<_, StdError>(deps.storage, |mut v| {
+                    v.owner = new_owner;
+                    Ok(v)
+                })?;
+
+                Ok(())
+            })
+            .map_err(Into::into)
+        }
+    }
+}
+
+/// Splits the sender's voting power at the start of the current epoch between LP tokens.
+/// Locks created during the epoch only get voting power in the next one, so moving xORO
+/// between locks can't be used to vote twice. Votes cast earlier in the same epoch are replaced.
+fn vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    votes: Vec<(String, u16)>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let epoch = epoch_start(env.block.time.seconds());
+
+    let mut weights_sum = 0u16;
+    let mut lp_tokens = HashSet::new();
+    for (lp_token, weight) in &votes {
+        ensure!(*weight > 0, ContractError::InvalidVoteWeights {});
+        weights_sum = weights_sum.saturating_add(*weight);
+        ensure!(lp_tokens.insert(lp_token), ContractError::DuplicatedPool {});
+        query_lp_pair(deps.as_ref(), &config, lp_token)?;
+    }
+    ensure!(
+        weights_sum <= VOTE_WEIGHTS_SUM,
+        ContractError::InvalidVoteWeights {}
+    );
+
+    let voting_power: Uint128 = deps.querier.query_wasm_smart(
+        &config.voting_escrow,
+        &VotingEscrowQueryMsg::BalanceAt {
+            address: info.sender.to_string(),
+            timestamp: Some(epoch),
+        },
+    )?;
+    ensure!(!voting_power.is_zero(), ContractError::ZeroVotingPower {});
+
+    if let Some(old_vote) = USER_VOTES.may_load(deps.storage, &info.sender)? {
+        if old_vote.epoch == epoch {
+            for (lp_token, weight) in &old_vote.votes {
+                let pool_votes = old_vote
+                    .voting_power
+                    .multiply_ratio(*weight, VOTE_WEIGHTS_SUM);
+                POOL_VOTES.update(deps.storage, (epoch, lp_token), |votes| -> StdResult<_> {
+                    Ok(votes.unwrap_or_default().checked_sub(pool_votes)?)
+                })?;
+            }
+        }
+    }
+
+    for (lp_token, weight) in &votes {
+        let pool_votes = voting_power.multiply_ratio(*weight, VOTE_WEIGHTS_SUM);
+        POOL_VOTES.update(deps.storage, (epoch, lp_token), |votes| -> StdResult<_> {
+            Ok(votes.unwrap_or_default().checked_add(pool_votes)?)
+        })?;
+    }
+
+    USER_VOTES.save(
+        deps.storage,
+        &info.sender,
+        &UserVote {
+            epoch,
+            voting_power,
+            votes,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "vote"),
+        attr("user", info.sender),
+        attr("epoch", epoch.to_string()),
+        attr("voting_power", voting_power),
+    ]))
+}
+
+/// Tallies the votes of the previous epoch and pushes the resulting alloc points to the
+/// incentives contract. Pools with blocked tokens or blacklisted pair types are skipped.
+fn tune_pools(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let epoch = epoch_start(env.block.time.seconds());
+
+    if let Some(tune_info) = TUNE_INFO.may_load(deps.storage)? {
+        ensure!(tune_info.epoch != epoch, ContractError::AlreadyTuned {});
+    }
+
+    let mut pool_votes = POOL_VOTES
+        .prefix(epoch.saturating_sub(EPOCH_LENGTH))
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, votes)) if votes.is_zero()))
+        .collect::<StdResult<Vec<_>>>()?;
+    // Pools with the same votes keep the LP token order
+    pool_votes.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let blocked_tokens = query_blocked_tokens(deps.as_ref(), &config)?;
+    let blacklisted_pair_types: Vec<PairType> = deps
+        .querier
+        .query_wasm_smart(&config.factory, &factory::QueryMsg::BlacklistedPairTypes {})?;
+
+    let mut eligible_pools = vec![];
+    for (lp_token, votes) in pool_votes {
+        if eligible_pools.len() == config.pools_limit as usize {
+            break;
+        }
+
+        // The pool might have been deregistered since the votes were cast
+        let pair_info = match query_lp_pair(deps.as_ref(), &config, &lp_token) {
+            Ok(pair_info) => pair_info,
+            Err(_) => continue,
+        };
+        let is_blocked = pair_info
+            .asset_infos
+            .iter()
+            .any(|asset_info| blocked_tokens.contains(asset_info));
+        if is_blocked || blacklisted_pair_types.contains(&pair_info.pair_type) {
+            continue;
+        }
+
+        eligible_pools.push((lp_token, votes));
+    }
+
+    let pools = apply_pool_cap(eligible_pools, config.pool_cap)?;
+    ensure!(!pools.is_empty(), ContractError::NoVotes {});
+
+    TUNE_INFO.save(
+        deps.storage,
+        &TuneInfo {
+            epoch,
+            pools: pools.clone(),
+        },
+    )?;
+
+    let setup_pools_msg = wasm_execute(
+        &config.incentives,
+        &incentives::ExecuteMsg::SetupPools { pools },
+        vec![],
+    )?;
+
+    Ok(Response::new()
+        .add_message(setup_pools_msg)
+        .add_attributes(vec![
+            attr("action", "tune_pools"),
+            attr("epoch", epoch.to_string()),
+        ]))
+}
+
+/// Updates the pools limit and the pool cap.
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    pools_limit: Option<u32>,
+    pool_cap: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attrs = vec![attr("action", "update_config")];
+
+    if let Some(pools_limit) = pools_limit {
+        validate_pools_limit(pools_limit)?;
+        config.pools_limit = pools_limit;
+        attrs.push(attr("pools_limit", pools_limit.to_string()));
+    }
+
+    if let Some(pool_cap) = pool_cap {
+        validate_pool_cap(pool_cap)?;
+        config.pool_cap = pool_cap;
+        attrs.push(attr("pool_cap", pool_cap.to_string()));
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+fn validate_pools_limit(pools_limit: u32) -> Result<(), ContractError> {
+    ensure!(
+        (1..=MAX_POOLS_LIMIT).contains(&pools_limit),
+        ContractError::InvalidPoolsLimit {}
+    );
+
+    Ok(())
+}
+
+fn validate_pool_cap(pool_cap: Decimal) -> Result<(), ContractError> {
+    ensure!(
+        !pool_cap.is_zero() && pool_cap <= Decimal::one(),
+        ContractError::InvalidPoolCap {}
+    );
+
+    Ok(())
+}
+
+/// Exposes all the queries available in the contract.
+///
+/// * **QueryMsg::Config {}** Returns the generator controller contract configuration
+///
+/// * **QueryMsg::UserVote { address }** Returns the last votes of the given address
+///
+/// * **QueryMsg::PoolVotes { lp_token, epoch }** Returns the voting power given to an LP token in the given epoch
+///
+/// * **QueryMsg::TuneInfo {}** Returns the result of the last pool tuning
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::UserVote { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_json_binary(&USER_VOTES.may_load(deps.storage, &address)?)
+        }
+        QueryMsg::PoolVotes { lp_token, epoch } => {
+            let epoch = epoch.unwrap_or_else(|| epoch_start(env.block.time.seconds()));
+            to_json_binary(
+                &POOL_VOTES
+                    .may_load(deps.storage, (epoch, &lp_token))?
+                    .unwrap_or_default(),
+            )
+        }
+        QueryMsg::TuneInfo {} => to_json_binary(&TUNE_INFO.may_load(deps.storage)?),
+    }
+}
@@ -0,0 +1,44 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+use oroswap::generator_controller::{MAX_POOLS_LIMIT, VOTE_WEIGHTS_SUM};
+
+/// This enum describes generator controller contract errors
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Pools limit must be between 1 and {MAX_POOLS_LIMIT}")]
+    InvalidPoolsLimit {},
+
+    #[error("Pool cap must be greater than 0 and not exceed 1")]
+    InvalidPoolCap {},
+
+    #[error("Vote weights must be positive and must not exceed {VOTE_WEIGHTS_SUM} in total")]
+    InvalidVoteWeights {},
+
+    #[error("Duplicated pool found")]
+    DuplicatedPool {},
+
+    #[error("LP token {lp_token} doesn't belong to a pool registered in the factory")]
+    InvalidLpToken { lp_token: String },
+
+    #[error("You don't have any voting power")]
+    ZeroVotingPower {},
+
+    #[error("Pools have already been tuned in this epoch")]
+    AlreadyTuned {},
+
+    #[error("There are no votes for pools eligible for ORO emissions")]
+    NoVotes {},
+}
+
+impl From<OverflowError> for ContractError {
+    fn from(o: OverflowError) -> Self {
+        StdError::from(o).into()
+    }
+}
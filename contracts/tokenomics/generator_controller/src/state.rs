@@ -0,0 +1,20 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use oroswap::common::OwnershipProposal;
+use oroswap::generator_controller::{Config, TuneInfo, UserVote};
+
+/// Stores the contract config at the given key
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Contains a proposal to change contract ownership.
+pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
+
+/// The last votes of each address
+pub const USER_VOTES: Map<&Addr, UserVote> = Map::new("user_votes");
+
+/// The voting power given to an LP token. The key is the epoch start timestamp and the LP token.
+pub const POOL_VOTES: Map<(u64, &str), Uint128> = Map::new("pool_votes");
+
+/// The result of the last pool tuning
+pub const TUNE_INFO: Item<TuneInfo> = Item::new("tune_info");
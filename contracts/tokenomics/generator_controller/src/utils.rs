@@ -0,0 +1,154 @@
+use cosmwasm_std::{Decimal, Deps, StdResult, Uint128};
+
+use oroswap::asset::{AssetInfo, PairInfo};
+use oroswap::generator_controller::Config;
+use oroswap::incentives::{QueryMsg as IncentivesQueryMsg, MAX_PAGE_LIMIT};
+use oroswap::{factory, pair, DecimalCheckedOps};
+
+use crate::error::ContractError;
+
+/// Returns the pair which minted **lp_token** if the pair is registered in the factory.
+/// LP tokens must follow the token factory format: coin.{lp_minter}.{token_name}
+pub fn query_lp_pair(
+    deps: Deps,
+    config: &Config,
+    lp_token: &str,
+) -> Result<PairInfo, ContractError> {
+    let invalid_lp_token = || ContractError::InvalidLpToken {
+        lp_token: lp_token.to_string(),
+    };
+
+    let parts: Vec<_> = lp_token.split('.').collect();
+    if !lp_token.starts_with("coin.") || parts.len() < 3 {
+        return Err(invalid_lp_token());
+    }
+    let lp_minter = deps
+        .api
+        .addr_validate(parts[1])
+        .map_err(|_| invalid_lp_token())?;
+
+    let pair_info: PairInfo = deps
+        .querier
+        .query_wasm_smart(lp_minter, &pair::QueryMsg::Pair {})
+        .map_err(|_| invalid_lp_token())?;
+
+    let registered: PairInfo = deps
+        .querier
+        .query_wasm_smart(
+            &config.factory,
+            &factory::QueryMsg::Pair {
+                asset_infos: pair_info.asset_infos.clone(),
+                pair_type: pair_info.pair_type.clone(),
+            },
+        )
+        .map_err(|_| invalid_lp_token())?;
+
+    if registered.liquidity_token != lp_token {
+        return Err(invalid_lp_token());
+    }
+
+    Ok(pair_info)
+}
+
+/// Returns all tokens blocked in the incentives contract.
+pub fn query_blocked_tokens(deps: Deps, config: &Config) -> StdResult<Vec<AssetInfo>> {
+    let mut blocked_tokens = vec![];
+    let mut start_after = None;
+
+    loop {
+        let page: Vec<AssetInfo> = deps.querier.query_wasm_smart(
+            &config.incentives,
+            &IncentivesQueryMsg::BlockedTokensList {
+                start_after,
+                limit: Some(MAX_PAGE_LIMIT),
+            },
+        )?;
+        let page_len = page.len();
+        start_after = page.last().cloned();
+        blocked_tokens.extend(page);
+
+        if page_len < MAX_PAGE_LIMIT as usize {
+            return Ok(blocked_tokens);
+        }
+    }
+}
+
+/// Converts pool votes into alloc points so that no pool receives more than **pool_cap** of the
+/// total. Alloc points above the cap are redistributed among the other pools pro-rata to their votes.
+/// If the cap is too low for the number of pools, every pool receives the capped amount.
+pub fn apply_pool_cap(
+    pools: Vec<(String, Uint128)>,
+    pool_cap: Decimal,
+) -> StdResult<Vec<(String, Uint128)>> {
+    let total: Uint128 = pools.iter().map(|(_, votes)| votes).sum();
+    let max_points = pool_cap.checked_mul_uint128(total)?;
+    let mut capped = vec![false; pools.len()];
+
+    loop {
+        let mut rest_points = total;
+        let mut rest_votes = Uint128::zero();
+        for ((_, votes), is_capped) in pools.iter().zip(&capped) {
+            if *is_capped {
+                rest_points = rest_points.checked_sub(max_points)?;
+            } else {
+                rest_votes += votes;
+            }
+        }
+
+        let mut changed = false;
+        for ((_, votes), is_capped) in pools.iter().zip(capped.iter_mut()) {
+            if !*is_capped && votes.multiply_ratio(rest_points, rest_votes) > max_points {
+                *is_capped = true;
+                changed = true;
+            }
+        }
+
+        if !changed {
+            return Ok(pools
+                .into_iter()
+                .zip(capped)
+                .map(|((lp_token, votes), is_capped)| {
+                    let alloc_points = if is_capped {
+                        max_points
+                    } else {
+                        votes.multiply_ratio(rest_points, rest_votes)
+                    };
+                    (lp_token, alloc_points)
+                })
+                .filter(|(_, alloc_points)| !alloc_points.is_zero())
+                .collect());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pools(votes: &[u128]) -> Vec<(String, Uint128)> {
+        votes
+            .iter()
+            .enumerate()
+            .map(|(i, votes)| (format!("lp{i}"), Uint128::new(*votes)))
+            .collect()
+    }
+
+    #[test]
+    fn pool_cap_redistributes_excess() {
+        let result = apply_pool_cap(pools(&[900, 60, 40]), Decimal::percent(50)).unwrap();
+        assert_eq!(result, pools(&[500, 300, 200]));
+
+        // The second pool exceeds the cap only after redistribution
+        let result = apply_pool_cap(pools(&[700, 250, 50]), Decimal::percent(40)).unwrap();
+        assert_eq!(result, pools(&[400, 400, 200]));
+
+        let result = apply_pool_cap(pools(&[300, 300, 400]), Decimal::percent(50)).unwrap();
+        assert_eq!(result, pools(&[300, 300, 400]));
+    }
+
+    #[test]
+    fn pool_cap_lower_than_equal_share() {
+        let result = apply_pool_cap(pools(&[900, 100]), Decimal::percent(10)).unwrap();
+        assert_eq!(result, pools(&[100, 100]));
+    }
+}
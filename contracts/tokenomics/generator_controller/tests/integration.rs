@@ -0,0 +1,625 @@
+#![cfg(not(tarpaulin_include))]
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, to_json_binary, Addr, Binary, Decimal, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdError, StdResult, Timestamp, Uint128,
+};
+use cw_multi_test::{App, AppResponse, ContractWrapper, Executor};
+use cw_storage_plus::{Item, Map};
+
+use oroswap::asset::{AssetInfo, PairInfo};
+use oroswap::factory::PairType;
+use oroswap::generator_controller::{
+    Config, ExecuteMsg, InstantiateMsg, QueryMsg, TuneInfo, UserVote,
+};
+use oroswap::incentives::{epoch_start, EPOCHS_START, EPOCH_LENGTH};
+use oroswap::{pair, voting_escrow};
+use oroswap_generator_controller::error::ContractError;
+
+const OWNER: &str = "owner";
+const USER1: &str = "user1";
+const USER2: &str = "user2";
+const USER3: &str = "user3";
+const XORO: &str = "xoro";
+/// A day into the first epoch used in tests
+const START_TIME: u64 = EPOCHS_START + 10 * EPOCH_LENGTH + 86400;
+
+#[cw_serde]
+pub struct Empty {}
+
+/// A pair which mints the LP token coin.{pair}.lp
+mod mock_pair {
+    use super::*;
+
+    const PAIR_INFO: Item<PairInfo> = Item::new("pair_info");
+
+    pub fn instantiate(
+        deps: DepsMut,
+        env: Env,
+        _info: MessageInfo,
+        asset_infos: Vec<AssetInfo>,
+    ) -> StdResult<Response> {
+        PAIR_INFO.save(
+            deps.storage,
+            &PairInfo {
+                asset_infos,
+                liquidity_token: format!("coin.{}.lp", env.contract.address),
+                contract_addr: env.contract.address,
+                pair_type: PairType::Xyk {},
+            },
+        )?;
+
+        Ok(Response::new())
+    }
+
+    pub fn execute(_: DepsMut, _: Env, _: MessageInfo, _: Empty) -> StdResult<Response> {
+        unimplemented!()
+    }
+
+    pub fn query(deps: Deps, _env: Env, msg: pair::QueryMsg) -> StdResult<Binary> {
+        match msg {
+            pair::QueryMsg::Pair {} => to_json_binary(&PAIR_INFO.load(deps.storage)?),
+            _ => unimplemented!(),
+        }
+    }
+}
+
+/// Plays the roles of the factory and the incentives contracts
+mod mock_hub {
+    use super::*;
+
+    const PAIRS: Map<&str, PairInfo> = Map::new("pairs");
+    const BLOCKED_TOKENS: Item<Vec<AssetInfo>> = Item::new("blocked_tokens");
+    const POOLS: Item<Vec<(String, Uint128)>> = Item::new("pools");
+
+    #[cw_serde]
+    pub enum ExecuteMsg {
+        RegisterPair { pair_info: PairInfo },
+        BlockToken { token: AssetInfo },
+        SetupPools { pools: Vec<(String, Uint128)> },
+    }
+
+    #[cw_serde]
+    pub enum QueryMsg {
+        Pair {
+            asset_infos: Vec<AssetInfo>,
+            pair_type: PairType,
+        },
+        BlacklistedPairTypes {},
+        BlockedTokensList {
+            start_after: Option<AssetInfo>,
+            limit: Option<u8>,
+        },
+        Pools {},
+    }
+
+    pub fn instantiate(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        _msg: Empty,
+    ) -> StdResult<Response> {
+        BLOCKED_TOKENS.save(deps.storage, &vec![])?;
+        POOLS.save(deps.storage, &vec![])?;
+
+        Ok(Response::new())
+    }
+
+    pub fn execute(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: ExecuteMsg,
+    ) -> StdResult<Response> {
+        match msg {
+            ExecuteMsg::RegisterPair { pair_info } => {
+                PAIRS.save(deps.storage, &pair_info.liquidity_token.clone(), &pair_info)?
+            }
+            ExecuteMsg::BlockToken { token } => {
+                BLOCKED_TOKENS.update(deps.storage, |mut tokens| -> StdResult<_> {
+                    tokens.push(token);
+                    Ok(tokens)
+                })?;
+            }
+            ExecuteMsg::SetupPools { pools } => POOLS.save(deps.storage, &pools)?,
+        }
+
+        Ok(Response::new())
+    }
+
+    pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+        match msg {
+            QueryMsg::Pair {
+                asset_infos,
+                pair_type,
+            } => {
+                let pair_info = PAIRS
+                    .range(deps.storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, pair_info)| pair_info))
+                    .collect::<StdResult<Vec<_>>>()?
+                    .into_iter()
+                    .find(|pair_info| {
+                        pair_info.asset_infos == asset_infos && pair_info.pair_type == pair_type
+                    })
+                    .ok_or_else(|| StdError::not_found("pair"))?;
+                to_json_binary(&pair_info)
+            }
+            QueryMsg::BlacklistedPairTypes {} => to_json_binary(&Vec::<PairType>::new()),
+            QueryMsg::BlockedTokensList { start_after, .. } => {
+                let tokens = if start_after.is_none() {
+                    BLOCKED_TOKENS.load(deps.storage)?
+                } else {
+                    vec![]
+                };
+                to_json_binary(&tokens)
+            }
+            QueryMsg::Pools {} => to_json_binary(&POOLS.load(deps.storage)?),
+        }
+    }
+}
+
+struct Helper {
+    app: App,
+    escrow: Addr,
+    hub: Addr,
+    controller: Addr,
+    pair_code_id: u64,
+}
+
+impl Helper {
+    fn new() -> Self {
+        let mut app = App::new(|app, _, storage| {
+            for user in [USER1, USER2, USER3] {
+                app.bank
+                    .init_balance(storage, &Addr::unchecked(user), coins(10_000, XORO))
+                    .unwrap();
+            }
+        });
+        app.update_block(|block| block.time = Timestamp::from_seconds(START_TIME));
+
+        let escrow_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            oroswap_voting_escrow::contract::execute,
+            oroswap_voting_escrow::contract::instantiate,
+            oroswap_voting_escrow::contract::query,
+        )));
+        let escrow = app
+            .instantiate_contract(
+                escrow_code_id,
+                Addr::unchecked(OWNER),
+                &voting_escrow::InstantiateMsg {
+                    owner: OWNER.to_string(),
+                    deposit_denom: XORO.to_string(),
+                    maker: OWNER.to_string(),
+                    max_lock_weeks: 104,
+                    max_exit_penalty: Decimal::percent(50),
+                },
+                &[],
+                "vxORO",
+                None,
+            )
+            .unwrap();
+
+        let hub_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            mock_hub::execute,
+            mock_hub::instantiate,
+            mock_hub::query,
+        )));
+        let hub = app
+            .instantiate_contract(
+                hub_code_id,
+                Addr::unchecked(OWNER),
+                &Empty {},
+                &[],
+                "Factory and incentives",
+                None,
+            )
+            .unwrap();
+
+        let pair_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            mock_pair::execute,
+            mock_pair::instantiate,
+            mock_pair::query,
+        )));
+
+        let controller_code_id = app.store_code(Box::new(ContractWrapper::new_with_empty(
+            oroswap_generator_controller::contract::execute,
+            oroswap_generator_controller::contract::instantiate,
+            oroswap_generator_controller::contract::query,
+        )));
+        let controller = app
+            .instantiate_contract(
+                controller_code_id,
+                Addr::unchecked(OWNER),
+                &InstantiateMsg {
+                    owner: OWNER.to_string(),
+                    voting_escrow: escrow.to_string(),
+                    factory: hub.to_string(),
+                    incentives: hub.to_string(),
+                    pools_limit: 10,
+                    pool_cap: Decimal::percent(60),
+                },
+                &[],
+                "Generator controller",
+                None,
+            )
+            .unwrap();
+
+        Self {
+            app,
+            escrow,
+            hub,
+            controller,
+            pair_code_id,
+        }
+    }
+
+    /// Creates a pair and returns its LP token. The pair is registered in the factory if **register** is set.
+    fn create_pair(&mut self, denoms: [&str; 2], register: bool) -> String {
+        let asset_infos: Vec<_> = denoms
+            .iter()
+            .map(|denom| AssetInfo::native(*denom))
+            .collect();
+        let pair = self
+            .app
+            .instantiate_contract(
+                self.pair_code_id,
+                Addr::unchecked(OWNER),
+                &asset_infos,
+                &[],
+                "Pair",
+                None,
+            )
+            .unwrap();
+        let pair_info: PairInfo = self
+            .app
+            .wrap()
+            .query_wasm_smart(&pair, &pair::QueryMsg::Pair {})
+            .unwrap();
+
+        if register {
+            self.app
+                .execute_contract(
+                    Addr::unchecked(OWNER),
+                    self.hub.clone(),
+                    &mock_hub::ExecuteMsg::RegisterPair {
+                        pair_info: pair_info.clone(),
+                    },
+                    &[],
+                )
+                .unwrap();
+        }
+
+        pair_info.liquidity_token
+    }
+
+    fn create_lock(&mut self, user: &str, amount: u128) {
+        self.app
+            .execute_contract(
+                Addr::unchecked(user),
+                self.escrow.clone(),
+                &voting_escrow::ExecuteMsg::CreateLock { weeks: 104 },
+                &coins(amount, XORO),
+            )
+            .unwrap();
+    }
+
+    /// Returns the voting power of **user** at the start of the current epoch
+    fn epoch_voting_power(&self, user: &str) -> Uint128 {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.escrow,
+                &voting_escrow::QueryMsg::BalanceAt {
+                    address: user.to_string(),
+                    timestamp: Some(epoch_start(self.app.block_info().time.seconds())),
+                },
+            )
+            .unwrap()
+    }
+
+    fn vote(&mut self, user: &str, votes: &[(&str, u16)]) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(user),
+            self.controller.clone(),
+            &ExecuteMsg::Vote {
+                votes: votes
+                    .iter()
+                    .map(|(lp_token, weight)| (lp_token.to_string(), *weight))
+                    .collect(),
+            },
+            &[],
+        )
+    }
+
+    fn tune_pools(&mut self) -> anyhow::Result<AppResponse> {
+        self.app.execute_contract(
+            Addr::unchecked(USER3),
+            self.controller.clone(),
+            &ExecuteMsg::TunePools {},
+            &[],
+        )
+    }
+
+    fn pool_votes(&self, lp_token: &str) -> Uint128 {
+        self.app
+            .wrap()
+            .query_wasm_smart(
+                &self.controller,
+                &QueryMsg::PoolVotes {
+                    lp_token: lp_token.to_string(),
+                    epoch: None,
+                },
+            )
+            .unwrap()
+    }
+
+    fn user_vote(&self, user: &str) -> UserVote {
+        self.app
+            .wrap()
+            .query_wasm_smart::<Option<UserVote>>(
+                &self.controller,
+                &QueryMsg::UserVote {
+                    address: user.to_string(),
+                },
+            )
+            .unwrap()
+            .unwrap()
+    }
+
+    fn incentive_pools(&self) -> Vec<(String, Uint128)> {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.hub, &mock_hub::QueryMsg::Pools {})
+            .unwrap()
+    }
+
+    fn next_epoch(&mut self) {
+        self.app
+            .update_block(|block| block.time = block.time.plus_seconds(EPOCH_LENGTH));
+    }
+}
+
+#[test]
+fn votes_are_tallied_into_alloc_points() {
+    let mut helper = Helper::new();
+    let lp_a = helper.create_pair(["uzig", "uusdc"], true);
+    let lp_b = helper.create_pair(["uzig", "uoro"], true);
+    let unregistered_lp = helper.create_pair(["uoro", "uusdc"], false);
+
+    helper.create_lock(USER1, 1000);
+    helper.create_lock(USER2, 3000);
+
+    // Locks created during an epoch have no voting power until the next one
+    let err = helper.vote(USER1, &[(&lp_a, 10000)]).unwrap_err();
+    assert_eq!(ContractError::ZeroVotingPower {}, err.downcast().unwrap());
+
+    helper.next_epoch();
+    let power1 = helper.epoch_voting_power(USER1);
+    let power2 = helper.epoch_voting_power(USER2);
+
+    let err = helper
+        .vote(USER1, &[(&unregistered_lp, 10000)])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidLpToken {
+            lp_token: unregistered_lp
+        },
+        err.downcast().unwrap()
+    );
+    let err = helper.vote(USER1, &[("uzig", 10000)]).unwrap_err();
+    assert_eq!(
+        ContractError::InvalidLpToken {
+            lp_token: "uzig".to_string()
+        },
+        err.downcast().unwrap()
+    );
+    let err = helper
+        .vote(USER1, &[(&lp_a, 5000), (&lp_b, 5001)])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::InvalidVoteWeights {},
+        err.downcast().unwrap()
+    );
+    let err = helper
+        .vote(USER1, &[(&lp_a, 5000), (&lp_a, 5000)])
+        .unwrap_err();
+    assert_eq!(ContractError::DuplicatedPool {}, err.downcast().unwrap());
+    let err = helper.vote(USER3, &[(&lp_a, 10000)]).unwrap_err();
+    assert_eq!(ContractError::ZeroVotingPower {}, err.downcast().unwrap());
+
+    helper.vote(USER1, &[(&lp_a, 5000), (&lp_b, 5000)]).unwrap();
+    helper.vote(USER2, &[(&lp_b, 10000)]).unwrap();
+    assert_eq!(helper.pool_votes(&lp_a), power1.multiply_ratio(1u8, 2u8));
+    assert_eq!(
+        helper.pool_votes(&lp_b),
+        power1.multiply_ratio(1u8, 2u8) + power2
+    );
+
+    // Voting again replaces the previous votes
+    helper.vote(USER1, &[(&lp_a, 10000)]).unwrap();
+    assert_eq!(helper.pool_votes(&lp_a), power1);
+    assert_eq!(helper.pool_votes(&lp_b), power2);
+
+    assert_eq!(
+        helper.user_vote(USER1),
+        UserVote {
+            epoch: START_TIME - 86400 + EPOCH_LENGTH,
+            voting_power: power1,
+            votes: vec![(lp_a.clone(), 10000)],
+        }
+    );
+
+    // Votes of the current epoch are applied in the next one
+    let err = helper.tune_pools().unwrap_err();
+    assert_eq!(ContractError::NoVotes {}, err.downcast().unwrap());
+
+    helper.next_epoch();
+    helper.tune_pools().unwrap();
+
+    // The second pool is capped at 60% and the rest goes to the first one
+    let total = power1 + power2;
+    let capped = total * Decimal::percent(60);
+    let expected_pools = vec![(lp_b, capped), (lp_a, total - capped)];
+    assert_eq!(helper.incentive_pools(), expected_pools);
+
+    let tune_info: Option<TuneInfo> = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&helper.controller, &QueryMsg::TuneInfo {})
+        .unwrap();
+    assert_eq!(
+        tune_info,
+        Some(TuneInfo {
+            epoch: START_TIME - 86400 + 2 * EPOCH_LENGTH,
+            pools: expected_pools,
+        })
+    );
+
+    let err = helper.tune_pools().unwrap_err();
+    assert_eq!(ContractError::AlreadyTuned {}, err.downcast().unwrap());
+}
+
+#[test]
+fn tune_skips_blocked_pools() {
+    let mut helper = Helper::new();
+    let lp_a = helper.create_pair(["uzig", "uusdc"], true);
+    let lp_b = helper.create_pair(["uzig", "uoro"], true);
+    let lp_scam = helper.create_pair(["uoro", "uscam"], true);
+
+    helper.create_lock(USER1, 5000);
+    helper.create_lock(USER2, 3000);
+    helper.create_lock(USER3, 1000);
+    helper.next_epoch();
+    let power2 = helper.epoch_voting_power(USER2);
+
+    helper.vote(USER1, &[(&lp_scam, 10000)]).unwrap();
+    helper.vote(USER2, &[(&lp_a, 10000)]).unwrap();
+    helper.vote(USER3, &[(&lp_b, 10000)]).unwrap();
+
+    helper
+        .app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            helper.hub.clone(),
+            &mock_hub::ExecuteMsg::BlockToken {
+                token: AssetInfo::native("uscam"),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let update_msg = ExecuteMsg::UpdateConfig {
+        pools_limit: Some(1),
+        pool_cap: Some(Decimal::one()),
+    };
+    let err = helper
+        .app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            helper.controller.clone(),
+            &update_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+    let err = helper
+        .app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            helper.controller.clone(),
+            &ExecuteMsg::UpdateConfig {
+                pools_limit: Some(0),
+                pool_cap: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::InvalidPoolsLimit {}, err.downcast().unwrap());
+    helper
+        .app
+        .execute_contract(
+            Addr::unchecked(OWNER),
+            helper.controller.clone(),
+            &update_msg,
+            &[],
+        )
+        .unwrap();
+
+    let config: Config = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&helper.controller, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.pools_limit, 1);
+    assert_eq!(config.pool_cap, Decimal::one());
+
+    helper.next_epoch();
+    helper.tune_pools().unwrap();
+
+    // The most voted pool is blocked and only one pool fits the limit
+    assert_eq!(helper.incentive_pools(), vec![(lp_a.clone(), power2)]);
+
+    // Votes don't carry over to the next epoch
+    helper.vote(USER3, &[(&lp_b, 10000)]).unwrap();
+    let power3 = helper.user_vote(USER3).voting_power;
+    helper.next_epoch();
+    helper.tune_pools().unwrap();
+    assert_eq!(helper.incentive_pools(), vec![(lp_b, power3)]);
+}
+
+#[test]
+fn relocked_xoro_cannot_vote_twice() {
+    let mut helper = Helper::new();
+    let lp_a = helper.create_pair(["uzig", "uusdc"], true);
+
+    helper.create_lock(USER1, 1000);
+    helper.next_epoch();
+    let power1 = helper.epoch_voting_power(USER1);
+
+    helper.vote(USER1, &[(&lp_a, 10000)]).unwrap();
+    assert_eq!(helper.pool_votes(&lp_a), power1);
+
+    // USER1 exits the lock and moves the xORO to USER2 who locks it again
+    helper
+        .app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            helper.escrow.clone(),
+            &voting_escrow::ExecuteMsg::WithdrawEarly {},
+            &[],
+        )
+        .unwrap();
+    let withdrawn = helper
+        .app
+        .wrap()
+        .query_balance(USER1, XORO)
+        .unwrap()
+        .amount
+        .u128()
+        - 9000;
+    helper
+        .app
+        .send_tokens(
+            Addr::unchecked(USER1),
+            Addr::unchecked(USER2),
+            &coins(withdrawn, XORO),
+        )
+        .unwrap();
+    helper.create_lock(USER2, withdrawn);
+
+    // The new lock has no voting power in the current epoch
+    let err = helper.vote(USER2, &[(&lp_a, 10000)]).unwrap_err();
+    assert_eq!(ContractError::ZeroVotingPower {}, err.downcast().unwrap());
+
+    // USER1 can only replace the vote cast with the power at the start of the epoch
+    helper.vote(USER1, &[(&lp_a, 10000)]).unwrap();
+    assert_eq!(helper.pool_votes(&lp_a), power1);
+
+    // In the next epoch only the new lock votes
+    helper.next_epoch();
+    let err = helper.vote(USER1, &[(&lp_a, 10000)]).unwrap_err();
+    assert_eq!(ContractError::ZeroVotingPower {}, err.downcast().unwrap());
+    helper.vote(USER2, &[(&lp_a, 10000)]).unwrap();
+    assert_eq!(helper.pool_votes(&lp_a), helper.epoch_voting_power(USER2));
+}
@@ -313,6 +313,7 @@ impl Helper {
                             is_generator_disabled: false,
                             permissioned: false,
                             pool_creation_fee: Uint128::new(1000),
+                            pool_creation_fee_denom: "uzig".to_string(),
                         },
                         // PairConfig {
                         //     code_id: pair_stable_code,
@@ -399,6 +400,8 @@ impl Helper {
                             amount: oro_for_vesting.amount,
                         },
                         end_point: None,
+                        shape: None,
+                        revocable: false,
                     }],
                 }],
             },
@@ -1010,6 +1013,7 @@ impl Helper {
                     pair_type: PairType::Xyk {},
                     asset_infos: asset_infos.clone(),
                     init_params: None,
+                    fee_tier: None,
                 },
                 &[coin(1000, "uzig")],
             )
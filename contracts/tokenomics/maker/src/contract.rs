@@ -4,8 +4,8 @@ use std::str::FromStr;
 
 use cosmwasm_std::{
     attr, ensure, ensure_eq, entry_point, to_json_binary, to_json_string, Addr, Attribute, Binary,
-    Decimal, Deps, DepsMut, Env, MessageInfo, Order, ReplyOn, Response, StdError, StdResult,
-    SubMsg, Uint128, Uint64,
+    Decimal, Deps, DepsMut, Env, MessageInfo, Order, QuerierWrapper, ReplyOn, Response, StdError,
+    StdResult, Storage, SubMsg, Uint128, Uint64,
 };
 use cw2::set_contract_version;
 
@@ -13,20 +13,24 @@ use oroswap::asset::{addr_opt_validate, Asset, AssetInfo, AssetInfoExt};
 use oroswap::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
 use oroswap::factory::UpdateAddr;
 use oroswap::maker::{
-    AssetWithLimit, BalancesResponse, Config, ConfigResponse, ExecuteMsg, InstantiateMsg,
-    MigrateMsg, QueryMsg, SecondReceiverConfig, SecondReceiverParams, SeizeConfig,
-    UpdateDevFundConfig,
+    AssetWithLimit, BalancesResponse, Config, ConfigResponse, DistributionRecord, ExecuteMsg,
+    InstantiateMsg, MigrateMsg, QueryMsg, SecondReceiverConfig, SecondReceiverParams, SeizeConfig,
+    UpdateDevFundConfig, UpdatePriceGuard,
 };
 use oroswap::pair::MAX_ALLOWED_SLIPPAGE;
 
 use crate::error::ContractError;
 // Migration function is simplified for new codebase
-use crate::reply::PROCESS_DEV_FUND_REPLY_ID;
-use crate::state::{BRIDGES, CONFIG, LAST_COLLECT_TS, OWNERSHIP_PROPOSAL, SEIZE_CONFIG};
+use crate::reply::{COLLECT_SWAP_REPLY_ID, PROCESS_DEV_FUND_REPLY_ID};
+use crate::state::{
+    next_record_id, read_distribution_ledger, read_swap_ledger, PendingSwap, BRIDGES, CONFIG,
+    DISTRIBUTION_LEDGER, LAST_COLLECT_TS, OWNERSHIP_PROPOSAL, PENDING_SWAPS, SEIZE_CONFIG,
+};
 use crate::utils::{
-    build_distribute_msg, build_send_msg, build_swap_msg, get_pool, is_critical_token,
-    try_build_swap_msg, update_second_receiver_cfg, validate_bridge, validate_cooldown, BRIDGES_EXECUTION_MAX_DEPTH,
-    BRIDGES_INITIAL_DEPTH,
+    build_distribute_msg, build_guarded_swap_msg, build_send_msg, build_swap_msg, check_pool,
+    get_pool, is_critical_token, try_build_router_swap_msg, try_build_swap_msg,
+    update_second_receiver_cfg, validate_bridge, validate_cooldown, validate_price_guard, SwapMsg,
+    BRIDGES_EXECUTION_MAX_DEPTH, BRIDGES_INITIAL_DEPTH,
 };
 
 /// Contract name that is used for migration.
@@ -84,6 +88,7 @@ pub fn instantiate(
     }
 
     validate_cooldown(msg.collect_cooldown)?;
+    validate_price_guard(msg.price_guard.as_ref())?;
     LAST_COLLECT_TS.save(deps.storage, &env.block.time.seconds())?;
 
     let mut cfg = Config {
@@ -105,6 +110,8 @@ pub fn instantiate(
         dev_fund_conf: None,
         authorized_keepers: vec![],  // Initialize with empty list
         critical_tokens: msg.critical_tokens.unwrap_or_default(),
+        router_contract: addr_opt_validate(deps.api, &msg.router_contract)?,
+        price_guard: msg.price_guard,
     };
 
     update_second_receiver_cfg(deps.as_ref(), &mut cfg, &msg.second_receiver_params)?;
@@ -173,6 +180,11 @@ pub fn instantiate(
         attr("second_fee_receiver", second_fee_receiver),
         attr("second_receiver_cut", second_receiver_cut),
         attr("critical_tokens_count", cfg.critical_tokens.len().to_string()),
+        attr(
+            "router_contract",
+            msg.router_contract.unwrap_or_else(|| String::from("none")),
+        ),
+        attr("price_guard", cfg.price_guard.is_some().to_string()),
     ]))
 }
 
@@ -189,6 +201,8 @@ pub fn instantiate(
 ///             governance_percent,
 ///             max_spread,
 ///             second_receiver_params,
+///             router_contract,
+///             price_guard,
 ///         }** Updates general contract settings stores in the [`Config`].
 ///
 /// * **ExecuteMsg::UpdateBridges { add, remove }** Adds or removes bridge assets used to swap fee tokens to ORO.
@@ -225,6 +239,8 @@ pub fn execute(
             oro_token,
             dev_fund_config,
             critical_tokens,
+            router_contract,
+            price_guard,
         } => update_config(
             deps,
             info,
@@ -239,6 +255,8 @@ pub fn execute(
             oro_token,
             dev_fund_config,
             critical_tokens,
+            router_contract,
+            price_guard,
         ),
         ExecuteMsg::UpdateBridges { add, remove } => update_bridges(deps, info, add, remove),
         ExecuteMsg::SwapBridgeAssets { assets, depth } => {
@@ -365,12 +383,18 @@ fn collect(
     }
 
     // Swap all non ORO tokens
-    let (mut response, bridge_assets) = swap_assets(
+    let (mut response, bridge_assets, pending_swaps) = swap_assets(
         deps.as_ref(),
         &env.contract.address,
         &cfg,
         assets.into_iter().filter(|a| a.info.ne(&oro)).collect(),
     )?;
+    save_pending_swaps(
+        deps.storage,
+        &deps.querier,
+        &env.contract.address,
+        pending_swaps,
+    )?;
 
     // If no swap messages - send ORO directly to x/vxORO stakers
     if response.messages.is_empty() {
@@ -392,8 +416,8 @@ fn collect(
 
 /// This enum describes available token types that can be used as a SwapTarget.
 enum SwapTarget {
-    Oro(SubMsg),
-    Bridge { asset: AssetInfo, msg: SubMsg },
+    Oro(SwapMsg),
+    Bridge { asset: AssetInfo, swap: SwapMsg },
 }
 
 /// Swap all non ORO tokens to ORO.
@@ -402,15 +426,16 @@ enum SwapTarget {
 ///
 /// * **assets** array with assets to swap to ORO.
 ///
-/// * **with_validation** whether the swap operation should be validated or not.
+/// Returns the swaps to record in the ledger after they are executed along with the response.
 fn swap_assets(
     deps: Deps,
     contract_addr: &Addr,
     cfg: &Config,
     assets: Vec<AssetWithLimit>,
-) -> Result<(Response, Vec<AssetInfo>), ContractError> {
+) -> Result<(Response, Vec<AssetInfo>, Vec<PendingSwap>), ContractError> {
     let mut response = Response::default();
     let mut bridge_assets = HashMap::new();
+    let mut pending_swaps = vec![];
 
     for a in assets {
         // Get balance
@@ -422,22 +447,65 @@ fn swap_assets(
         }
 
         if !balance.is_zero() {
-            match swap(deps, cfg, a.info, balance)? {
-                SwapTarget::Oro(msg) => {
-                    response.messages.push(msg);
+            let (asset_out, swap) = match swap(deps, cfg, a.info.clone(), balance)? {
+                Some(SwapTarget::Oro(swap)) => (cfg.oro_token.clone(), swap),
+                Some(SwapTarget::Bridge { asset, swap }) => {
+                    bridge_assets.insert(asset.to_string(), asset.clone());
+                    (asset, swap)
                 }
-                SwapTarget::Bridge { asset, msg } => {
-                    response.messages.push(msg);
-                    bridge_assets.insert(asset.to_string(), asset);
+                None => {
+                    response = response.add_attribute("skipped_swap", a.info.to_string());
+                    continue;
                 }
+            };
+
+            if swap.amount < balance {
+                response =
+                    response.add_attribute("partial_swap", format!("{}:{}", a.info, swap.amount));
             }
+
+            // The swap result is recorded in the ledger on reply
+            let mut swap_msg = swap.msg;
+            swap_msg.reply_on = ReplyOn::Success;
+            swap_msg.id = COLLECT_SWAP_REPLY_ID;
+            response.messages.push(swap_msg);
+            pending_swaps.push(PendingSwap {
+                asset_in: a.info.with_balance(swap.amount),
+                asset_out,
+                pool: swap.pool,
+                balance_before: Uint128::zero(),
+            });
         }
     }
 
-    Ok((response, bridge_assets.into_values().collect()))
+    Ok((
+        response,
+        bridge_assets.into_values().collect(),
+        pending_swaps,
+    ))
+}
+
+/// Saves swaps which are recorded in the ledger on reply. Swaps are executed in order,
+/// so the balance of the first swap's output asset is taken right now and the balance for
+/// every next swap is taken on reply of the previous one.
+fn save_pending_swaps(
+    storage: &mut dyn Storage,
+    querier: &QuerierWrapper,
+    contract_addr: &Addr,
+    mut pending_swaps: Vec<PendingSwap>,
+) -> StdResult<()> {
+    if let Some(first_swap) = pending_swaps.first_mut() {
+        first_swap.balance_before = first_swap.asset_out.query_pool(querier, contract_addr)?;
+        PENDING_SWAPS.save(storage, &pending_swaps)?;
+    }
+
+    Ok(())
 }
 
-/// Checks if all required pools and bridges exists and performs a swap operation to ORO.
+/// Swaps through the best router route if there is one. Otherwise checks if all required pools
+/// and bridges exists and performs a swap operation to ORO. If the price guard is set, only the
+/// part of the amount which swaps close enough to the pairs' SMA prices is swapped.
+/// Returns None if the price guard skipped the swap.
 ///
 /// * **from_token** token to swap to ORO.
 ///
@@ -447,11 +515,20 @@ fn swap(
     cfg: &Config,
     from_token: AssetInfo,
     amount_in: Uint128,
-) -> Result<SwapTarget, ContractError> {
-    // 1. Check if bridge tokens exist
+) -> Result<Option<SwapTarget>, ContractError> {
+    // 1. Swap through the best router route if the router is set
+    if let Some(router_contract) = &cfg.router_contract {
+        let swap_via_router =
+            try_build_router_swap_msg(&deps.querier, cfg, router_contract, &from_token, amount_in);
+        if let Ok(swap) = swap_via_router {
+            return Ok(swap.map(SwapTarget::Oro));
+        }
+    }
+
+    // 2. Check if bridge tokens exist
     let bridge_token = BRIDGES.load(deps.storage, from_token.to_string());
     if let Ok(bridge_token) = bridge_token {
-        let bridge_pool = validate_bridge(
+        validate_bridge(
             deps,
             &cfg.factory_contract,
             &from_token,
@@ -459,45 +536,54 @@ fn swap(
             &cfg.oro_token,
             BRIDGES_INITIAL_DEPTH,
         )?;
+        let bridge_pool = get_pool(
+            &deps.querier,
+            &cfg.factory_contract,
+            &from_token,
+            &bridge_token,
+            amount_in,
+        )?;
 
-        let msg = build_swap_msg(
-            cfg.max_spread,
+        let swap = build_guarded_swap_msg(
+            &deps.querier,
+            cfg,
             &bridge_pool,
             &from_token,
-            Some(&bridge_token),
+            &bridge_token,
             amount_in,
         )?;
 
-        let swap_msg = if bridge_token == cfg.oro_token {
-            SwapTarget::Oro(msg)
-        } else {
-            SwapTarget::Bridge {
-                asset: bridge_token,
-                msg,
+        return Ok(swap.map(|swap| {
+            if bridge_token == cfg.oro_token {
+                SwapTarget::Oro(swap)
+            } else {
+                SwapTarget::Bridge {
+                    asset: bridge_token,
+                    swap,
+                }
             }
-        };
-        return Ok(swap_msg);
+        }));
     }
 
-    // 2. Check for a pair with a default bridge
+    // 3. Check for a pair with a default bridge
     if let Some(default_bridge) = &cfg.default_bridge {
         if from_token.ne(default_bridge) {
             let swap_to_default =
                 try_build_swap_msg(&deps.querier, cfg, &from_token, default_bridge, amount_in);
-            if let Ok(msg) = swap_to_default {
-                return Ok(SwapTarget::Bridge {
+            if let Ok(swap) = swap_to_default {
+                return Ok(swap.map(|swap| SwapTarget::Bridge {
                     asset: default_bridge.clone(),
-                    msg,
-                });
+                    swap,
+                }));
             }
         }
     }
 
-    // 3. Check for a direct pair with ORO
+    // 4. Check for a direct pair with ORO
     let swap_to_oro =
         try_build_swap_msg(&deps.querier, cfg, &from_token, &cfg.oro_token, amount_in);
-    if let Ok(msg) = swap_to_oro {
-        return Ok(SwapTarget::Oro(msg));
+    if let Ok(swap) = swap_to_oro {
+        return Ok(swap.map(SwapTarget::Oro));
     }
 
     Err(ContractError::CannotSwap(from_token))
@@ -541,11 +627,18 @@ fn swap_bridge_assets(
         })
         .collect();
 
-    let (response, bridge_assets) =
+    let (response, bridge_assets, pending_swaps) =
         swap_assets(deps.as_ref(), &env.contract.address, &cfg, bridges)?;
+    save_pending_swaps(
+        deps.storage,
+        &deps.querier,
+        &env.contract.address,
+        pending_swaps,
+    )?;
 
-    // There should always be some messages, if there are none - something went wrong
-    if response.messages.is_empty() {
+    // There should always be some messages unless the price guard skipped the swaps,
+    // otherwise something went wrong
+    if response.messages.is_empty() && response.attributes.is_empty() {
         return Err(ContractError::Std(StdError::generic_err(
             "Empty swap messages",
         )));
@@ -689,6 +782,7 @@ fn distribute(
                 &cfg.factory_contract,
                 &cfg.oro_token,
                 &dev_fund_conf.asset_info,
+                dev_share,
             )?;
             let mut swap_msg = build_swap_msg(
                 cfg.max_spread,
@@ -708,13 +802,32 @@ fn distribute(
         Uint128::zero()
     };
 
-    if let Some(staking_contract) = &cfg.staking_contract {
+    let staking_amount = if let Some(staking_contract) = &cfg.staking_contract {
         let amount = amount.checked_sub(governance_amount + second_receiver_amount + dev_amount)?;
         if !amount.is_zero() {
             let to_staking_asset = cfg.oro_token.with_balance(amount);
             result.push(SubMsg::new(to_staking_asset.into_msg(staking_contract)?));
         }
-    }
+
+        amount
+    } else {
+        Uint128::zero()
+    };
+
+    let id = next_record_id(deps.storage, &DISTRIBUTION_LEDGER)?;
+    DISTRIBUTION_LEDGER.save(
+        deps.storage,
+        id,
+        &DistributionRecord {
+            id,
+            timestamp: env.block.time.seconds(),
+            oro_amount: amount,
+            staking_amount,
+            governance_amount,
+            second_receiver_amount,
+            dev_fund_amount: dev_amount,
+        },
+    )?;
 
     attributes = vec![
         attr("action", "distribute_oro"),
@@ -746,6 +859,10 @@ fn distribute(
 ///
 /// * **second_receiver_params** describes the second receiver of fees
 ///
+/// * **router_contract** router contract used to find swap routes to ORO.
+///
+/// * **price_guard** bounds the deviation of fee swaps from the pairs' SMA prices.
+///
 /// ## Executor
 /// Only the owner can execute this.
 #[allow(clippy::too_many_arguments)]
@@ -763,6 +880,8 @@ fn update_config(
     oro_token: Option<AssetInfo>,
     dev_fund_conf: Option<Box<UpdateDevFundConfig>>,
     critical_tokens: Option<Vec<AssetInfo>>,
+    router_contract: Option<UpdateAddr>,
+    price_guard: Option<UpdatePriceGuard>,
 ) -> Result<Response, ContractError> {
     let mut attributes = vec![attr("action", "set_config")];
 
@@ -875,7 +994,7 @@ fn update_config(
             );
             
             // Ensure we can swap ORO into dev fund asset
-            get_pool(
+            check_pool(
                 &deps.querier,
                 &config.factory_contract,
                 &config.oro_token,
@@ -897,6 +1016,25 @@ fn update_config(
         attributes.push(attr("critical_tokens_count", config.critical_tokens.len().to_string()));
     }
 
+    if let Some(action) = router_contract {
+        match action {
+            UpdateAddr::Set(router) => {
+                config.router_contract = Some(deps.api.addr_validate(&router)?);
+                attributes.push(attr("router_contract", &router));
+            }
+            UpdateAddr::Remove {} => {
+                config.router_contract = None;
+                attributes.push(attr("router_contract", "removed"));
+            }
+        }
+    }
+
+    if let Some(price_guard) = price_guard {
+        validate_price_guard(price_guard.set.as_ref())?;
+        attributes.push(attr("price_guard", price_guard.set.is_some().to_string()));
+        config.price_guard = price_guard.set;
+    }
+
     CONFIG.save(deps.storage, &config)?;
 
     Ok(Response::new().add_attributes(attributes))
@@ -1114,6 +1252,12 @@ fn remove_keeper(
 ///
 /// * **QueryMsg::Bridges {}** Returns the bridges used for swapping fee tokens
 /// using a vector of [`(String, String)`] denoting Asset -> Bridge connections.
+///
+/// * **QueryMsg::SwapHistory { start_time, end_time, asset, start_after, limit }** Returns fee
+/// swaps recorded by the Maker using a vector of [`SwapRecord`](oroswap::maker::SwapRecord) objects.
+///
+/// * **QueryMsg::DistributionHistory { start_time, end_time, start_after, limit }** Returns ORO
+/// distributions recorded by the Maker using a vector of [`DistributionRecord`] objects.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -1121,6 +1265,32 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::Balances { assets } => to_json_binary(&query_get_balances(deps, env, assets)?),
         QueryMsg::Bridges {} => to_json_binary(&query_bridges(deps)?),
         QueryMsg::QuerySeizeConfig {} => to_json_binary(&SEIZE_CONFIG.load(deps.storage)?),
+        QueryMsg::SwapHistory {
+            start_time,
+            end_time,
+            asset,
+            start_after,
+            limit,
+        } => to_json_binary(&read_swap_ledger(
+            deps.storage,
+            start_time,
+            end_time,
+            asset,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::DistributionHistory {
+            start_time,
+            end_time,
+            start_after,
+            limit,
+        } => to_json_binary(&read_distribution_ledger(
+            deps.storage,
+            start_time,
+            end_time,
+            start_after,
+            limit,
+        )?),
     }
 }
 
@@ -1142,6 +1312,8 @@ fn query_get_config(deps: Deps) -> StdResult<ConfigResponse> {
         second_receiver_cfg: config.second_receiver_cfg,
         authorized_keepers: config.authorized_keepers,
         critical_tokens: config.critical_tokens,
+        router_contract: config.router_contract,
+        price_guard: config.price_guard,
     })
 }
 
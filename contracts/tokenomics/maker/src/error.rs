@@ -52,6 +52,9 @@ pub enum ContractError {
 
     #[error("Pool not found")]
     PoolNotFound {},
+
+    #[error("Incorrect price guard. Max deviation should be between 0 and 1 exclusive")]
+    IncorrectPriceGuard {},
 }
 
 impl From<OverflowError> for ContractError {
@@ -1,10 +1,12 @@
-use crate::state::CONFIG;
+use crate::state::{next_record_id, CONFIG, PENDING_SWAPS, SWAP_LEDGER};
 use oroswap::asset::AssetInfoExt;
+use oroswap::maker::SwapRecord;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{attr, DepsMut, Env, Reply, Response, StdError, StdResult};
 
 pub const PROCESS_DEV_FUND_REPLY_ID: u64 = 1;
+pub const COLLECT_SWAP_REPLY_ID: u64 = 2;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
@@ -25,6 +27,46 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> StdResult<Response> {
                 ])
                 .add_message(dev_fee.into_msg(&dev_fund_conf.address)?))
         }
+        COLLECT_SWAP_REPLY_ID => {
+            let mut pending_swaps = PENDING_SWAPS.load(deps.storage)?;
+            if pending_swaps.is_empty() {
+                return Err(StdError::generic_err("No pending swaps"));
+            }
+            let swap = pending_swaps.remove(0);
+
+            let balance_after = swap
+                .asset_out
+                .query_pool(&deps.querier, &env.contract.address)?;
+            let asset_out = swap
+                .asset_out
+                .with_balance(balance_after.checked_sub(swap.balance_before)?);
+
+            let id = next_record_id(deps.storage, &SWAP_LEDGER)?;
+            SWAP_LEDGER.save(
+                deps.storage,
+                id,
+                &SwapRecord {
+                    id,
+                    timestamp: env.block.time.seconds(),
+                    asset_in: swap.asset_in,
+                    asset_out,
+                    pool: swap.pool,
+                },
+            )?;
+
+            // The next swap is executed right after this reply
+            match pending_swaps.first_mut() {
+                Some(next_swap) => {
+                    next_swap.balance_before = next_swap
+                        .asset_out
+                        .query_pool(&deps.querier, &env.contract.address)?;
+                    PENDING_SWAPS.save(deps.storage, &pending_swaps)?;
+                }
+                None => PENDING_SWAPS.remove(deps.storage),
+            }
+
+            Ok(Response::new())
+        }
         _ => Err(StdError::generic_err("Invalid reply id")),
     }
 }
@@ -1,7 +1,11 @@
-use oroswap::asset::AssetInfo;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{Addr, Order, StdResult, Storage, Uint128};
+use oroswap::asset::{Asset, AssetInfo};
 use oroswap::common::OwnershipProposal;
-use oroswap::maker::{Config, SeizeConfig};
-use cw_storage_plus::{Item, Map};
+use oroswap::maker::{Config, DistributionRecord, SeizeConfig, SwapRecord};
+use cw_storage_plus::{Bound, Item, Map};
 
 /// Stores the contract configuration at the given key
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -15,3 +19,181 @@ pub const BRIDGES: Map<String, AssetInfo> = Map::new("bridges");
 pub const LAST_COLLECT_TS: Item<u64> = Item::new("last_collect_ts");
 /// Stores seize config
 pub const SEIZE_CONFIG: Item<SeizeConfig> = Item::new("seize_config");
+
+/// Stores swaps waiting to be recorded in the swap ledger in execution order
+pub const PENDING_SWAPS: Item<Vec<PendingSwap>> = Item::new("pending_swaps");
+/// Append-only ledger of fee swaps. The key is the record id
+pub const SWAP_LEDGER: Map<u64, SwapRecord> = Map::new("swap_ledger");
+/// Append-only ledger of ORO distributions. The key is the record id
+pub const DISTRIBUTION_LEDGER: Map<u64, DistributionRecord> = Map::new("distribution_ledger");
+
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
+/// This structure describes a swap which result is not known yet.
+#[cw_serde]
+pub struct PendingSwap {
+    /// The fee token and amount swapped
+    pub asset_in: Asset,
+    /// The asset received from the swap
+    pub asset_out: AssetInfo,
+    /// The pair or router contract used for the swap
+    pub pool: Addr,
+    /// The Maker balance of `asset_out` right before the swap
+    pub balance_before: Uint128,
+}
+
+/// Returns the id of the next record in the ledger.
+pub fn next_record_id<T>(storage: &dyn Storage, ledger: &Map<u64, T>) -> StdResult<u64>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let last_id = ledger
+        .keys(storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+
+    Ok(last_id.map_or(0, |id| id + 1))
+}
+
+/// Returns the id of the first record made at or after `start_time`. Record ids and timestamps
+/// grow together, so the id is found with a binary search.
+fn first_record_since<T>(
+    storage: &dyn Storage,
+    ledger: &Map<u64, T>,
+    timestamp: impl Fn(&T) -> u64,
+    start_time: u64,
+) -> StdResult<u64>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let (mut low, mut high) = (0, next_record_id(storage, ledger)?);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if timestamp(&ledger.load(storage, mid)?) < start_time {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ok(low)
+}
+
+/// Reads ledger records in ascending order.
+/// ## Params
+///
+/// * **timestamp** returns the timestamp of a record.
+///
+/// * **start_time** the earliest record timestamp to read.
+///
+/// * **end_time** the latest record timestamp to read.
+///
+/// * **start_after** record id from which to start reading.
+///
+/// * **limit** amount of records to read.
+///
+/// * **filter** returns whether a record should be read.
+#[allow(clippy::too_many_arguments)]
+fn read_ledger<T>(
+    storage: &dyn Storage,
+    ledger: &Map<u64, T>,
+    timestamp: impl Fn(&T) -> u64,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    filter: impl Fn(&T) -> bool,
+) -> StdResult<Vec<T>>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let mut start = start_after.map_or(0, |id| id + 1);
+    if let Some(start_time) = start_time {
+        start = start.max(first_record_since(storage, ledger, &timestamp, start_time)?);
+    }
+    let end_time = end_time.unwrap_or(u64::MAX);
+
+    ledger
+        .range(
+            storage,
+            Some(Bound::inclusive(start)),
+            None,
+            Order::Ascending,
+        )
+        .map(|item| item.map(|(_, record)| record))
+        .take_while(|item| {
+            item.as_ref()
+                .map_or(true, |record| timestamp(record) <= end_time)
+        })
+        .filter(|item| item.as_ref().map_or(true, &filter))
+        .take(limit)
+        .collect()
+}
+
+/// Returns fee swaps from the swap ledger in ascending order.
+/// ## Params
+///
+/// * **start_time** the earliest swap timestamp to read.
+///
+/// * **end_time** the latest swap timestamp to read.
+///
+/// * **asset** if set, only swaps from or to this asset are read.
+///
+/// * **start_after** swap record id from which to start reading.
+///
+/// * **limit** amount of swaps to read.
+pub fn read_swap_ledger(
+    storage: &dyn Storage,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    asset: Option<AssetInfo>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<SwapRecord>> {
+    read_ledger(
+        storage,
+        &SWAP_LEDGER,
+        |record| record.timestamp,
+        start_time,
+        end_time,
+        start_after,
+        limit,
+        |record| {
+            asset.as_ref().map_or(true, |asset| {
+                record.asset_in.info.eq(asset) || record.asset_out.info.eq(asset)
+            })
+        },
+    )
+}
+
+/// Returns ORO distributions from the distribution ledger in ascending order.
+/// ## Params
+///
+/// * **start_time** the earliest distribution timestamp to read.
+///
+/// * **end_time** the latest distribution timestamp to read.
+///
+/// * **start_after** distribution record id from which to start reading.
+///
+/// * **limit** amount of distributions to read.
+pub fn read_distribution_ledger(
+    storage: &dyn Storage,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<DistributionRecord>> {
+    read_ledger(
+        storage,
+        &DISTRIBUTION_LEDGER,
+        |record| record.timestamp,
+        start_time,
+        end_time,
+        start_after,
+        limit,
+        |_| true,
+    )
+}
@@ -32,6 +32,8 @@ fn proper_initialization() {
         second_receiver_params: None,
         collect_cooldown: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
     let res = instantiate(deps.as_mut(), env, info, instantiate_msg).unwrap();
     assert_eq!(0, res.messages.len());
@@ -58,6 +60,8 @@ fn proper_initialization() {
             collect_cooldown: None,
             authorized_keepers: vec![],
             critical_tokens: vec![],
+            router_contract: None,
+            price_guard: None,
         }
     )
 }
@@ -86,6 +90,8 @@ fn update_owner() {
         second_receiver_params: None,
         collect_cooldown: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
 
     let env = mock_env();
@@ -183,6 +189,8 @@ fn test_keeper_bridge_management() {
         second_receiver_params: None,
         collect_cooldown: None,
         critical_tokens: Some(critical_tokens.clone()),
+        router_contract: None,
+        price_guard: None,
     };
 
     let info = mock_info("owner", &[]);
@@ -297,6 +305,8 @@ fn test_update_critical_tokens() {
         second_receiver_params: None,
         collect_cooldown: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
 
     let info = mock_info("owner", &[]);
@@ -341,6 +351,8 @@ fn test_update_critical_tokens() {
         oro_token: None,
         dev_fund_config: None,
         critical_tokens: Some(new_critical_tokens.clone()),
+        router_contract: None,
+        price_guard: None,
     };
     let info = mock_info("owner", &[]);
     execute(deps.as_mut(), env.clone(), info, update_config_msg).unwrap();
@@ -403,6 +415,8 @@ fn test_remove_keeper() {
         second_receiver_params: None,
         collect_cooldown: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
 
     let info = mock_info("owner", &[]);
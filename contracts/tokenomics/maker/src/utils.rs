@@ -1,17 +1,23 @@
 use cosmwasm_std::{
-    coins, to_json_binary, Addr, Binary, CosmosMsg, Decimal, Deps, Env,
+    coins, to_json_binary, Addr, Binary, CosmosMsg, Decimal, Deps, Env, Fraction,
     QuerierWrapper, StdError, StdResult, SubMsg, Uint128, Uint64, WasmMsg, BankMsg,
 };
 use std::str::FromStr;
 use cw20::Cw20ExecuteMsg;
 
-use oroswap::asset::{Asset, AssetInfo, PairInfo};
+use oroswap::asset::{native_asset_info, Asset, AssetInfo, PairInfo};
+use oroswap::factory::PairType;
 use oroswap::maker::{
-    Config, ExecuteMsg, SecondReceiverConfig, SecondReceiverParams, COOLDOWN_LIMITS,
+    Config, ExecuteMsg, PriceGuard, SecondReceiverConfig, SecondReceiverParams, COOLDOWN_LIMITS,
     MAX_SECOND_RECEIVER_CUT,
 };
-use oroswap::pair::Cw20HookMsg;
-use oroswap::querier::{query_pairs_by_assets, simulate};
+use oroswap::observation::OracleObservation;
+use oroswap::pair::{Cw20HookMsg, QueryMsg as PairQueryMsg};
+use oroswap::pair_xoro::XORO_PAIR_TYPE;
+use oroswap::querier::{query_best_pair, query_pair_info, query_unpaused_pairs, simulate};
+use oroswap::router::{
+    FindBestRouteResponse, SimulateSwapOperationsResponse, SwapOperation, MAX_ROUTE_HOPS,
+};
 
 use crate::error::ContractError;
 use crate::state::BRIDGES;
@@ -22,6 +28,20 @@ pub const BRIDGES_INITIAL_DEPTH: u64 = 0;
 pub const BRIDGES_MAX_DEPTH: u64 = 2;
 /// Swap execution depth limit
 pub const BRIDGES_EXECUTION_MAX_DEPTH: u64 = 5;
+/// How many times the swap amount can be halved when the price guard allows partial swaps
+pub const PRICE_GUARD_MAX_SPLITS: u32 = 4;
+
+/// This structure describes a fee token swap built by the Maker.
+pub struct SwapMsg {
+    pub msg: SubMsg,
+    /// The amount of the fee token to swap
+    pub amount: Uint128,
+    /// The pair or router contract used for the swap
+    pub pool: Addr,
+}
+
+/// None means that the price guard skipped the swap.
+pub type GuardedSwapMsg = Option<SwapMsg>;
 
 /// The function checks from<>to pool exists and creates swap message.
 ///
@@ -36,10 +56,281 @@ pub fn try_build_swap_msg(
     from: &AssetInfo,
     to: &AssetInfo,
     amount_in: Uint128,
-) -> Result<SubMsg, ContractError> {
-    let pool = get_pool(querier, &cfg.factory_contract, from, to)?;
-    let msg = build_swap_msg(cfg.max_spread, &pool, from, Some(to), amount_in)?;
-    Ok(msg)
+) -> Result<GuardedSwapMsg, ContractError> {
+    let pool = get_pool(querier, &cfg.factory_contract, from, to, amount_in)?;
+    build_guarded_swap_msg(querier, cfg, &pool, from, to, amount_in)
+}
+
+/// The function creates a swap message for the part of `amount_in` allowed by the price guard.
+///
+/// * **pool** pool's information.
+///
+/// * **from** asset we want to swap.
+///
+/// * **to** asset we want to swap to.
+///
+/// * **amount_in** amount of tokens to swap.
+pub fn build_guarded_swap_msg(
+    querier: &QuerierWrapper,
+    cfg: &Config,
+    pool: &PairInfo,
+    from: &AssetInfo,
+    to: &AssetInfo,
+    amount_in: Uint128,
+) -> Result<GuardedSwapMsg, ContractError> {
+    let amount = guarded_swap_amount(
+        cfg.price_guard.as_ref(),
+        amount_in,
+        |amount, max_deviation| {
+            Ok(check_swap_price(querier, pool, from, amount, max_deviation)?.is_some())
+        },
+    )?;
+
+    amount
+        .map(|amount| {
+            Ok(SwapMsg {
+                msg: build_swap_msg(cfg.max_spread, pool, from, Some(to), amount)?,
+                amount,
+                pool: pool.contract_addr.clone(),
+            })
+        })
+        .transpose()
+}
+
+/// The function asks the router for the best route from `from` to ORO and creates a message
+/// that swaps through this route. Each hop is checked by the price guard. The router doesn't
+/// check the spread of intermediate hops, so the whole route must return at least the simulated
+/// amount reduced by the configured max spread.
+///
+/// * **router_contract** address of the router contract.
+///
+/// * **from** asset we want to swap.
+///
+/// * **amount_in** amount of tokens to swap.
+pub fn try_build_router_swap_msg(
+    querier: &QuerierWrapper,
+    cfg: &Config,
+    router_contract: &Addr,
+    from: &AssetInfo,
+    amount_in: Uint128,
+) -> Result<GuardedSwapMsg, ContractError> {
+    let FindBestRouteResponse {
+        operations,
+        simulation,
+    } = querier.query_wasm_smart(
+        router_contract,
+        &oroswap::router::QueryMsg::FindBestRoute {
+            offer_asset: Asset {
+                info: from.clone(),
+                amount: amount_in,
+            },
+            ask_asset_info: cfg.oro_token.clone(),
+            max_hops: Some(MAX_ROUTE_HOPS),
+        },
+    )?;
+
+    let guarded_amount = match guarded_swap_amount(
+        cfg.price_guard.as_ref(),
+        amount_in,
+        |amount, max_deviation| check_route_price(querier, cfg, &operations, amount, max_deviation),
+    )? {
+        Some(amount) => amount,
+        None => return Ok(None),
+    };
+
+    let expected_return = if guarded_amount == amount_in {
+        simulation.amount
+    } else {
+        querier
+            .query_wasm_smart::<SimulateSwapOperationsResponse>(
+                router_contract,
+                &oroswap::router::QueryMsg::SimulateSwapOperations {
+                    offer_amount: guarded_amount,
+                    operations: operations.clone(),
+                },
+            )?
+            .amount
+    };
+    let minimum_receive = Some(expected_return * (Decimal::one() - cfg.max_spread));
+    let amount_in = guarded_amount;
+
+    let msg = match from {
+        AssetInfo::NativeToken { denom } => WasmMsg::Execute {
+            contract_addr: router_contract.to_string(),
+            msg: to_json_binary(&oroswap::router::ExecuteMsg::ExecuteSwapOperations {
+                operations,
+                minimum_receive,
+                to: None,
+                max_spread: Some(cfg.max_spread),
+            })?,
+            funds: coins(amount_in.u128(), denom),
+        },
+        AssetInfo::Token { contract_addr } => WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Send {
+                contract: router_contract.to_string(),
+                amount: amount_in,
+                msg: to_json_binary(&oroswap::router::Cw20HookMsg::ExecuteSwapOperations {
+                    operations,
+                    minimum_receive,
+                    to: None,
+                    max_spread: Some(cfg.max_spread),
+                })?,
+            })?,
+            funds: vec![],
+        },
+    };
+
+    Ok(Some(SwapMsg {
+        msg: SubMsg::new(msg),
+        amount: amount_in,
+        pool: router_contract.clone(),
+    }))
+}
+
+/// Returns the largest amount out of `amount_in` and its halves which passes `check`.
+/// Halves are tried only if the guard allows partial swaps. Returns the whole amount without
+/// any checks if the price guard is not set.
+///
+/// * **price_guard** the price guard configuration.
+///
+/// * **amount_in** amount of tokens to swap.
+///
+/// * **check** returns whether swapping the given amount respects the given max deviation.
+pub fn guarded_swap_amount(
+    price_guard: Option<&PriceGuard>,
+    amount_in: Uint128,
+    mut check: impl FnMut(Uint128, Decimal) -> Result<bool, ContractError>,
+) -> Result<Option<Uint128>, ContractError> {
+    let price_guard = match price_guard {
+        Some(price_guard) => price_guard,
+        None => return Ok(Some(amount_in)),
+    };
+
+    let splits = if price_guard.allow_partial {
+        PRICE_GUARD_MAX_SPLITS
+    } else {
+        0
+    };
+
+    let mut amount = amount_in;
+    for _ in 0..=splits {
+        if amount.is_zero() {
+            break;
+        }
+        if check(amount, price_guard.max_deviation)? {
+            return Ok(Some(amount));
+        }
+        amount = amount.multiply_ratio(1u8, 2u8);
+    }
+
+    Ok(None)
+}
+
+/// Simulates a swap and compares the return with the return at the pair's SMA price.
+/// Returns the simulated return amount if it is not worse than allowed by `max_deviation`.
+/// The ORO/xORO pair swaps at the staking rate and is not checked. Pairs without price
+/// observations can't confirm the price, so swaps through them are never allowed.
+///
+/// * **pool** pool's information.
+///
+/// * **from** asset we want to swap.
+///
+/// * **amount_in** amount of tokens to swap.
+///
+/// * **max_deviation** max allowed shortfall against the return at the SMA price.
+pub fn check_swap_price(
+    querier: &QuerierWrapper,
+    pool: &PairInfo,
+    from: &AssetInfo,
+    amount_in: Uint128,
+    max_deviation: Decimal,
+) -> Result<Option<Uint128>, ContractError> {
+    let return_amount = simulate(
+        querier,
+        &pool.contract_addr,
+        &Asset {
+            info: from.clone(),
+            amount: amount_in,
+        },
+    )?
+    .return_amount;
+
+    if pool.pair_type == PairType::Custom(XORO_PAIR_TYPE.to_string()) {
+        return Ok(Some(return_amount));
+    }
+
+    let observation: StdResult<OracleObservation> = querier.query_wasm_smart(
+        &pool.contract_addr,
+        &PairQueryMsg::Observe { seconds_ago: 0 },
+    );
+    let price = match observation {
+        Ok(observation) if !observation.price.is_zero() => observation.price,
+        _ => return Ok(None),
+    };
+
+    // Observed prices are amounts of the first pool asset per one second pool asset
+    let sma_return = if pool.asset_infos[0].eq(from) {
+        amount_in.multiply_ratio(price.denominator(), price.numerator())
+    } else {
+        amount_in.multiply_ratio(price.numerator(), price.denominator())
+    };
+    let min_return = sma_return * (Decimal::one() - max_deviation);
+
+    Ok((return_amount >= min_return).then_some(return_amount))
+}
+
+/// Checks every hop of a router route with [`check_swap_price`] using the simulated
+/// return of the previous hop as the offer amount.
+///
+/// * **operations** swap operations of the route.
+///
+/// * **amount_in** amount of tokens to swap.
+///
+/// * **max_deviation** max allowed shortfall against the return at the SMA price.
+fn check_route_price(
+    querier: &QuerierWrapper,
+    cfg: &Config,
+    operations: &[SwapOperation],
+    amount_in: Uint128,
+    max_deviation: Decimal,
+) -> Result<bool, ContractError> {
+    let mut amount = amount_in;
+    for operation in operations {
+        let (pool, from) = match operation {
+            SwapOperation::OroSwap {
+                offer_asset_info,
+                ask_asset_info,
+                pair_type,
+            } => {
+                let pool = query_pair_info(
+                    querier,
+                    &cfg.factory_contract,
+                    &[offer_asset_info.clone(), ask_asset_info.clone()],
+                    pair_type.clone(),
+                )?;
+                (pool, offer_asset_info.clone())
+            }
+            SwapOperation::NativeSwap {
+                offer_denom,
+                ask_denom,
+            } => {
+                let from = native_asset_info(offer_denom.clone());
+                let to = native_asset_info(ask_denom.clone());
+                (
+                    get_pool(querier, &cfg.factory_contract, &from, &to, amount)?,
+                    from,
+                )
+            }
+        };
+
+        match check_swap_price(querier, &pool, &from, amount, max_deviation)? {
+            Some(return_amount) => amount = return_amount,
+            None => return Ok(false),
+        }
+    }
+
+    Ok(true)
 }
 
 /// This function creates swap message.
@@ -148,14 +439,14 @@ pub fn validate_bridge(
     bridge_token: &AssetInfo,
     oro_token: &AssetInfo,
     depth: u64,
-) -> Result<PairInfo, ContractError> {
+) -> Result<(), ContractError> {
     // Check if the bridge pool exists
-    let bridge_pool = get_pool(&deps.querier, factory_contract, from_token, bridge_token)?;
+    check_pool(&deps.querier, factory_contract, from_token, bridge_token)?;
 
     // If bridge token is oro itself we don't need to check further
     if bridge_token != oro_token {
         // Check if the bridge token - ORO pool exists
-        let oro_pool = get_pool(&deps.querier, factory_contract, bridge_token, oro_token);
+        let oro_pool = check_pool(&deps.querier, factory_contract, bridge_token, oro_token);
         if oro_pool.is_err() {
             if depth >= BRIDGES_MAX_DEPTH {
                 return Err(ContractError::MaxBridgeDepth(depth));
@@ -177,76 +468,56 @@ pub fn validate_bridge(
         }
     }
 
-    Ok(bridge_pool)
+    Ok(())
 }
 
-/// Gets all available pairs for the given assets and selects the best one based on simulation result
-pub fn get_best_pool(
+/// Returns the unpaused pool between `from` and `to` which returns the most for `amount_in`.
+/// The router selects pools for native swaps with the same [`query_best_pair`] helper.
+///
+/// * **factory_contract** address of the factory contract.
+///
+/// * **from** source asset.
+///
+/// * **to** destination asset.
+///
+/// * **amount_in** amount of tokens to swap.
+pub fn get_pool(
     querier: &QuerierWrapper,
     factory_contract: &Addr,
     from: &AssetInfo,
     to: &AssetInfo,
+    amount_in: Uint128,
 ) -> Result<PairInfo, ContractError> {
-    // Query all pairs by assets using the querier
-    let pairs = query_pairs_by_assets(querier, factory_contract, &[from.clone(), to.clone()])?;
-
-    if pairs.pairs.is_empty() {
-        return Err(ContractError::PoolNotFound {});
-    }
-
-    // Get pool info for each pair to compare simulation results
-    let mut pool_infos = Vec::new();
-    for pair in pairs.pairs {
-        // Get the actual balance of the token to swap
-        let balance = from.query_pool(querier, &pair.contract_addr)?;
-        
-        // Skip if balance is zero
-        if balance.is_zero() {
-            continue;
-        }
-
-        // Simulate the swap with the actual balance using the querier's simulate function
-        let simulation_result = simulate(
-            querier,
-            &pair.contract_addr,
-            &Asset {
-                info: from.clone(),
-                amount: balance,
-            },
-        )?;
-
-        pool_infos.push((pair, simulation_result.return_amount));
-    }
-
-    if pool_infos.is_empty() {
-        return Err(ContractError::PoolNotFound {});
-    }
+    let offer_asset = Asset {
+        info: from.clone(),
+        amount: amount_in,
+    };
 
-    // Sort by return amount (highest first) and return the pair with highest return
-    pool_infos.sort_by(|a, b| b.1.cmp(&a.1));
-    
-    Ok(pool_infos[0].0.clone())
+    query_best_pair(querier, factory_contract, &offer_asset, to)?
+        .map(|(pair, _)| pair)
+        .ok_or(ContractError::PoolNotFound {})
 }
 
-/// This function checks that there is a pool to swap between `from` and `to`. In case of success
-/// returns [`PairInfo`] of selected pool.
+/// This function checks that there is an unpaused pool to swap between `from` and `to`.
 ///
 /// * **factory_contract** address of the factory contract.
 ///
 /// * **from** source asset.
 ///
 /// * **to** destination asset.
-pub fn get_pool(
+pub fn check_pool(
     querier: &QuerierWrapper,
     factory_contract: &Addr,
     from: &AssetInfo,
     to: &AssetInfo,
-) -> Result<PairInfo, ContractError> {
-    get_best_pool(querier, factory_contract, from, to)
+) -> Result<(), ContractError> {
+    if query_unpaused_pairs(querier, factory_contract, &[from.clone(), to.clone()])?.is_empty() {
+        return Err(ContractError::PoolNotFound {});
+    }
+
+    Ok(())
 }
 
-/// For native tokens of type [`AssetInfo`] uses regular bank transfer.
-///
 /// For a token of type [`AssetInfo`] we use the default method [`Cw20ExecuteMsg::Send`]
 pub fn build_send_msg(
     asset: &Asset,
@@ -324,6 +595,17 @@ pub fn validate_cooldown(maybe_cooldown: Option<u64>) -> Result<(), ContractErro
     Ok(())
 }
 
+/// Validate the price guard max deviation is within (0, 1)
+pub fn validate_price_guard(maybe_price_guard: Option<&PriceGuard>) -> Result<(), ContractError> {
+    if let Some(price_guard) = maybe_price_guard {
+        if price_guard.max_deviation.is_zero() || price_guard.max_deviation >= Decimal::one() {
+            return Err(ContractError::IncorrectPriceGuard {});
+        }
+    }
+
+    Ok(())
+}
+
 /// Check if a token is in the critical tokens list
 pub fn is_critical_token(token: &AssetInfo, critical_tokens: &[AssetInfo]) -> bool {
     critical_tokens.contains(token)
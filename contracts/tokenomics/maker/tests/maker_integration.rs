@@ -16,9 +16,9 @@ use oroswap::asset::{
 };
 use oroswap::factory::{PairConfig, PairType, UpdateAddr};
 use oroswap::maker::{
-    AssetWithLimit, BalancesResponse, ConfigResponse, DevFundConfig, ExecuteMsg, InstantiateMsg,
-    QueryMsg, SecondReceiverConfig, SecondReceiverParams, SeizeConfig, UpdateDevFundConfig,
-    COOLDOWN_LIMITS,
+    AssetWithLimit, BalancesResponse, ConfigResponse, DevFundConfig, DistributionRecord,
+    ExecuteMsg, InstantiateMsg, PriceGuard, QueryMsg, SecondReceiverConfig, SecondReceiverParams,
+    SeizeConfig, SwapRecord, UpdateDevFundConfig, UpdatePriceGuard, COOLDOWN_LIMITS,
 };
 use oroswap_maker::error::ContractError;
 use oroswap_test::cw_multi_test::{
@@ -190,6 +190,7 @@ fn instantiate_contracts(
             is_generator_disabled: false,
             permissioned: false,
             pool_creation_fee: Uint128::new(1000),
+            pool_creation_fee_denom: "uzig".to_string(),
         }],
         token_code_id: 1u64,
         fee_address: Some("maker".to_string()), // Set fee address to maker
@@ -247,6 +248,8 @@ fn instantiate_contracts(
         second_receiver_params,
         collect_cooldown,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
     let maker_instance = router
         .instantiate_contract(
@@ -423,6 +426,7 @@ fn create_pair(
                 pair_type: pair_type.unwrap_or(PairType::Xyk {}),
                 asset_infos: asset_infos.clone(),
                 init_params: None,
+                fee_tier: None,
             },
             &[coin(1000, "uzig")], // Add pool creation fee
         )
@@ -540,6 +544,8 @@ fn update_config() {
         oro_token: None,
         dev_fund_config: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
 
     // Assert cannot update with improper owner
@@ -585,6 +591,8 @@ fn update_config() {
         oro_token: None,
         dev_fund_config: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
 
     let err = router
@@ -607,6 +615,8 @@ fn update_config() {
         oro_token: None,
         dev_fund_config: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
 
     router
@@ -639,6 +649,8 @@ fn update_config() {
         oro_token: None,
         dev_fund_config: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
 
     let err = router
@@ -664,6 +676,8 @@ fn update_config() {
         oro_token: None,
         dev_fund_config: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
     let err = router
         .execute_contract(owner.clone(), maker_instance.clone(), &msg, &[])
@@ -688,6 +702,8 @@ fn update_config() {
         oro_token: None,
         dev_fund_config: None,
         critical_tokens: None,
+        router_contract: None,
+        price_guard: None,
     };
     router
         .execute_contract(owner.clone(), maker_instance.clone(), &msg, &[])
@@ -1271,6 +1287,782 @@ fn collect_err_no_swap_pair() {
     );
 }
 
+#[test]
+fn collect_via_router() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            coin(100_000_000_000u128, "uluna"),
+            coin(100_000_000_000u128, "uabc"),
+            coin(100_000_000_000u128, "ukrt"),
+            coin(100_000_000_000u128, "uusd"),
+        ],
+    );
+    let user = Addr::unchecked("user0000");
+    let staking = Addr::unchecked("staking");
+    let governance_percent = Uint64::new(50);
+
+    let (oro_token_instance, factory_instance, maker_instance, governance_instance) =
+        instantiate_contracts(
+            &mut router,
+            owner.clone(),
+            staking.clone(),
+            governance_percent,
+            None,
+            None,
+            None,
+            None,
+        );
+
+    // uabc can reach ORO only through ukrt which is not a bridge
+    for assets in vec![
+        vec![
+            native_asset("ukrt".to_string(), Uint128::from(100_000_u128)),
+            token_asset(oro_token_instance.clone(), Uint128::from(100_000_u128)),
+        ],
+        vec![
+            native_asset("ukrt".to_string(), Uint128::from(100_000_u128)),
+            native_asset("uabc".to_string(), Uint128::from(100_000_u128)),
+        ],
+    ] {
+        create_pair(
+            &mut router,
+            owner.clone(),
+            user.clone(),
+            &factory_instance,
+            assets,
+            None,
+        );
+    }
+
+    let router_code_id = router.store_code(Box::new(
+        ContractWrapper::new_with_empty(
+            oroswap_router::contract::execute,
+            oroswap_router::contract::instantiate,
+            oroswap_router::contract::query,
+        )
+        .with_reply_empty(oroswap_router::contract::reply),
+    ));
+    let router_instance = router
+        .instantiate_contract(
+            router_code_id,
+            owner.clone(),
+            &oroswap::router::InstantiateMsg {
+                oroswap_factory: factory_instance.to_string(),
+            },
+            &[],
+            "Router",
+            None,
+        )
+        .unwrap();
+
+    // The router searches routes through ukrt
+    router
+        .execute_contract(
+            owner.clone(),
+            router_instance.clone(),
+            &oroswap::router::ExecuteMsg::UpdateHubAssets {
+                hub_assets: vec![native_asset_info("ukrt".to_string())],
+            },
+            &[],
+        )
+        .unwrap();
+
+    // enable rewards distribution
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::EnableRewards { blocks: 1 },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(2000, "ukrt"), coin(3000, "uabc")],
+        )
+        .unwrap();
+
+    let msg = ExecuteMsg::Collect {
+        assets: vec![
+            AssetWithLimit {
+                info: native_asset_info("ukrt".to_string()),
+                limit: None,
+            },
+            AssetWithLimit {
+                info: native_asset_info("uabc".to_string()),
+                limit: None,
+            },
+        ],
+    };
+
+    let e = router
+        .execute_contract(owner.clone(), maker_instance.clone(), &msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        e.root_cause().to_string(),
+        "Cannot swap uabc. No swap destinations",
+    );
+
+    let update_router_msg = |router_contract| ExecuteMsg::UpdateConfig {
+        factory_contract: None,
+        staking_contract: None,
+        governance_contract: None,
+        governance_percent: None,
+        basic_asset: None,
+        max_spread: None,
+        second_receiver_params: None,
+        collect_cooldown: None,
+        oro_token: None,
+        dev_fund_config: None,
+        critical_tokens: None,
+        router_contract,
+        price_guard: None,
+    };
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_router_msg(Some(UpdateAddr::Set(router_instance.to_string()))),
+            &[],
+        )
+        .unwrap();
+
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.router_contract, Some(router_instance.clone()));
+
+    router
+        .execute_contract(owner.clone(), maker_instance.clone(), &msg, &[])
+        .unwrap();
+
+    // All fee tokens were swapped to ORO and distributed
+    let res: BalancesResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &maker_instance,
+            &QueryMsg::Balances {
+                assets: vec![
+                    native_asset_info("ukrt".to_string()),
+                    native_asset_info("uabc".to_string()),
+                    token_asset_info(oro_token_instance.clone()),
+                ],
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balances, vec![]);
+
+    let staking_balance: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &oro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: staking.to_string(),
+            },
+        )
+        .unwrap();
+    let governance_balance: BalanceResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &oro_token_instance,
+            &Cw20QueryMsg::Balance {
+                address: governance_instance.to_string(),
+            },
+        )
+        .unwrap();
+    assert!(!staking_balance.balance.is_zero());
+    assert_eq!(staking_balance.balance, governance_balance.balance);
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_router_msg(Some(UpdateAddr::Remove {})),
+            &[],
+        )
+        .unwrap();
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.router_contract, None);
+}
+
+#[test]
+fn collect_via_router_enforces_max_spread() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            coin(100_000_000_000u128, "uabc"),
+            coin(100_000_000_000u128, "ukrt"),
+        ],
+    );
+    let user = Addr::unchecked("user0000");
+    let staking = Addr::unchecked("staking");
+
+    let (oro_token_instance, factory_instance, maker_instance, _) = instantiate_contracts(
+        &mut router,
+        owner.clone(),
+        staking.clone(),
+        Uint64::new(50),
+        None,
+        None,
+        None,
+        None,
+    );
+
+    // uabc reaches ORO through the ukrt/ORO pair
+    for assets in vec![
+        vec![
+            native_asset("ukrt".to_string(), Uint128::from(100_000_u128)),
+            token_asset(oro_token_instance.clone(), Uint128::from(100_000_u128)),
+        ],
+        vec![
+            native_asset("ukrt".to_string(), Uint128::from(100_000_u128)),
+            native_asset("uabc".to_string(), Uint128::from(100_000_u128)),
+        ],
+    ] {
+        create_pair(
+            &mut router,
+            owner.clone(),
+            user.clone(),
+            &factory_instance,
+            assets,
+            None,
+        );
+    }
+
+    let router_code_id = router.store_code(Box::new(
+        ContractWrapper::new_with_empty(
+            oroswap_router::contract::execute,
+            oroswap_router::contract::instantiate,
+            oroswap_router::contract::query,
+        )
+        .with_reply_empty(oroswap_router::contract::reply),
+    ));
+    let router_instance = router
+        .instantiate_contract(
+            router_code_id,
+            owner.clone(),
+            &oroswap::router::InstantiateMsg {
+                oroswap_factory: factory_instance.to_string(),
+            },
+            &[],
+            "Router",
+            None,
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            owner.clone(),
+            router_instance.clone(),
+            &oroswap::router::ExecuteMsg::UpdateHubAssets {
+                hub_assets: vec![native_asset_info("ukrt".to_string())],
+            },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::EnableRewards { blocks: 1 },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateConfig {
+                factory_contract: None,
+                staking_contract: None,
+                governance_contract: None,
+                governance_percent: None,
+                basic_asset: None,
+                max_spread: None,
+                second_receiver_params: None,
+                collect_cooldown: None,
+                oro_token: None,
+                dev_fund_config: None,
+                critical_tokens: None,
+                router_contract: Some(UpdateAddr::Set(router_instance.to_string())),
+                price_guard: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(4000, "ukrt"), coin(3000, "uabc")],
+        )
+        .unwrap();
+
+    // The ukrt swap moves the ukrt/ORO pair after the uabc route was simulated,
+    // so the uabc route returns less than allowed by the max spread
+    let e = router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::Collect {
+                assets: vec![
+                    AssetWithLimit {
+                        info: native_asset_info("ukrt".to_string()),
+                        limit: None,
+                    },
+                    AssetWithLimit {
+                        info: native_asset_info("uabc".to_string()),
+                        limit: None,
+                    },
+                ],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert!(e
+        .root_cause()
+        .to_string()
+        .starts_with("Assertion failed; minimum receive amount"));
+
+    // The route is swapped when the intermediate pair is not moved
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::Collect {
+                assets: vec![AssetWithLimit {
+                    info: native_asset_info("uabc".to_string()),
+                    limit: None,
+                }],
+            },
+            &[],
+        )
+        .unwrap();
+    let res: BalancesResponse = router
+        .wrap()
+        .query_wasm_smart(
+            &maker_instance,
+            &QueryMsg::Balances {
+                assets: vec![native_asset_info("uabc".to_string())],
+            },
+        )
+        .unwrap();
+    assert_eq!(res.balances, vec![]);
+}
+
+#[test]
+fn collect_with_price_guard() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(owner.clone(), vec![coin(100_000_000_000u128, "ukrt")]);
+    let user = Addr::unchecked("user0000");
+    let staking = Addr::unchecked("staking");
+
+    let (oro_token_instance, factory_instance, maker_instance, _) = instantiate_contracts(
+        &mut router,
+        owner.clone(),
+        staking.clone(),
+        Uint64::new(50),
+        Some(Decimal::percent(20)),
+        None,
+        None,
+        None,
+    );
+
+    let pair_info = create_pair(
+        &mut router,
+        owner.clone(),
+        user.clone(),
+        &factory_instance,
+        vec![
+            native_asset("ukrt".to_string(), Uint128::from(100_000_u128)),
+            token_asset(oro_token_instance.clone(), Uint128::from(100_000_u128)),
+        ],
+        None,
+    );
+
+    let update_price_guard_msg = |set| ExecuteMsg::UpdateConfig {
+        factory_contract: None,
+        staking_contract: None,
+        governance_contract: None,
+        governance_percent: None,
+        basic_asset: None,
+        max_spread: None,
+        second_receiver_params: None,
+        collect_cooldown: None,
+        oro_token: None,
+        dev_fund_config: None,
+        critical_tokens: None,
+        router_contract: None,
+        price_guard: Some(UpdatePriceGuard { set }),
+    };
+
+    let err = router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_price_guard_msg(Some(PriceGuard {
+                max_deviation: Decimal::one(),
+                allow_partial: false,
+            })),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::IncorrectPriceGuard {}
+    );
+
+    let price_guard = PriceGuard {
+        max_deviation: Decimal::percent(15),
+        allow_partial: false,
+    };
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_price_guard_msg(Some(price_guard.clone())),
+            &[],
+        )
+        .unwrap();
+    let res: ConfigResponse = router
+        .wrap()
+        .query_wasm_smart(&maker_instance, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(res.price_guard, Some(price_guard));
+
+    // Record the SMA price of ukrt (0.98 ORO) in the pair
+    router
+        .execute_contract(
+            owner.clone(),
+            pair_info.contract_addr.clone(),
+            &oroswap::pair::ExecuteMsg::Swap {
+                offer_asset: native_asset("ukrt".to_string(), Uint128::new(2000)),
+                ask_asset_info: None,
+                belief_price: None,
+                max_spread: Some(Decimal::percent(50)),
+                to: None,
+            },
+            &[coin(2000, "ukrt")],
+        )
+        .unwrap();
+    router.update_block(next_block);
+
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(20_000, "ukrt")],
+        )
+        .unwrap();
+
+    let collect_msg = ExecuteMsg::Collect {
+        assets: vec![AssetWithLimit {
+            info: native_asset_info("ukrt".to_string()),
+            limit: None,
+        }],
+    };
+    let has_attr = |res: &AppResponse, key: &str, value: &str| {
+        res.events
+            .iter()
+            .flat_map(|event| &event.attributes)
+            .any(|attr| attr.key == key && attr.value == value)
+    };
+
+    // Swapping 20000 ukrt returns 18% less than at the SMA price
+    let res = router
+        .execute_contract(owner.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+    assert!(has_attr(&res, "skipped_swap", "ukrt"));
+    assert_eq!(
+        router
+            .wrap()
+            .query_balance(&maker_instance, "ukrt")
+            .unwrap()
+            .amount,
+        Uint128::new(20_000)
+    );
+
+    // Swapping 10000 ukrt returns 11% less than at the SMA price
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_price_guard_msg(Some(PriceGuard {
+                max_deviation: Decimal::percent(15),
+                allow_partial: true,
+            })),
+            &[],
+        )
+        .unwrap();
+    let res = router
+        .execute_contract(owner.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+    assert!(has_attr(&res, "partial_swap", "ukrt:10000"));
+    assert_eq!(
+        router
+            .wrap()
+            .query_balance(&maker_instance, "ukrt")
+            .unwrap()
+            .amount,
+        Uint128::new(10_000)
+    );
+
+    // Without the guard the rest is swapped regardless of the price
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &update_price_guard_msg(None),
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(owner.clone(), maker_instance.clone(), &collect_msg, &[])
+        .unwrap();
+    assert_eq!(
+        router
+            .wrap()
+            .query_balance(&maker_instance, "ukrt")
+            .unwrap()
+            .amount,
+        Uint128::new(0)
+    );
+}
+
+#[test]
+fn collect_records_ledger() {
+    let owner = Addr::unchecked("owner");
+    let mut router = mock_app(
+        owner.clone(),
+        vec![
+            coin(100_000_000_000u128, "uluna"),
+            coin(100_000_000_000u128, "uusd"),
+        ],
+    );
+    let user = Addr::unchecked("user0000");
+    let staking = Addr::unchecked("staking");
+
+    let (oro_token_instance, factory_instance, maker_instance, governance_instance) =
+        instantiate_contracts(
+            &mut router,
+            owner.clone(),
+            staking.clone(),
+            Uint64::new(50),
+            None,
+            None,
+            None,
+            None,
+        );
+
+    let usd_oro_pair = create_pair(
+        &mut router,
+        owner.clone(),
+        user.clone(),
+        &factory_instance,
+        vec![
+            native_asset("uusd".to_string(), Uint128::from(100_000_u128)),
+            token_asset(oro_token_instance.clone(), Uint128::from(100_000_u128)),
+        ],
+        None,
+    );
+    let luna_usd_pair = create_pair(
+        &mut router,
+        owner.clone(),
+        user.clone(),
+        &factory_instance,
+        vec![
+            native_asset("uluna".to_string(), Uint128::from(100_000_u128)),
+            native_asset("uusd".to_string(), Uint128::from(100_000_u128)),
+        ],
+        None,
+    );
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::UpdateBridges {
+                add: Some(vec![
+                    (
+                        native_asset_info("uluna".to_string()),
+                        native_asset_info("uusd".to_string()),
+                    ),
+                    (
+                        native_asset_info("uusd".to_string()),
+                        token_asset_info(oro_token_instance.clone()),
+                    ),
+                ]),
+                remove: None,
+            },
+            &[],
+        )
+        .unwrap();
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::EnableRewards { blocks: 1 },
+            &[],
+        )
+        .unwrap();
+
+    router
+        .send_tokens(
+            owner.clone(),
+            maker_instance.clone(),
+            &[coin(10_000, "uluna"), coin(10_000, "uusd")],
+        )
+        .unwrap();
+
+    router
+        .execute_contract(
+            owner.clone(),
+            maker_instance.clone(),
+            &ExecuteMsg::Collect {
+                assets: vec![
+                    AssetWithLimit {
+                        info: native_asset_info("uluna".to_string()),
+                        limit: None,
+                    },
+                    AssetWithLimit {
+                        info: native_asset_info("uusd".to_string()),
+                        limit: None,
+                    },
+                ],
+            },
+            &[],
+        )
+        .unwrap();
+
+    let swap_history = |router: &TestApp, asset, start_after| {
+        router
+            .wrap()
+            .query_wasm_smart::<Vec<SwapRecord>>(
+                &maker_instance,
+                &QueryMsg::SwapHistory {
+                    start_time: None,
+                    end_time: None,
+                    asset,
+                    start_after,
+                    limit: None,
+                },
+            )
+            .unwrap()
+    };
+
+    // uluna -> uusd, uusd -> ORO and then the bridged uusd -> ORO
+    let swaps = swap_history(&router, None, None);
+    assert_eq!(swaps.len(), 3);
+    assert_eq!(
+        swaps.iter().map(|swap| swap.id).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    assert_eq!(
+        swaps[0].asset_in,
+        native_asset("uluna".to_string(), Uint128::new(10_000))
+    );
+    assert_eq!(
+        swaps[0].asset_out.info,
+        native_asset_info("uusd".to_string())
+    );
+    assert_eq!(swaps[0].pool, luna_usd_pair.contract_addr);
+    assert_eq!(
+        swaps[1].asset_in,
+        native_asset("uusd".to_string(), Uint128::new(10_000))
+    );
+    assert_eq!(
+        swaps[1].asset_out.info,
+        token_asset_info(oro_token_instance.clone())
+    );
+    assert_eq!(swaps[1].pool, usd_oro_pair.contract_addr);
+    assert_eq!(swaps[2].asset_in, swaps[0].asset_out);
+    assert_eq!(swaps[2].pool, usd_oro_pair.contract_addr);
+
+    let luna_swaps = swap_history(&router, Some(native_asset_info("uluna".to_string())), None);
+    assert_eq!(luna_swaps, vec![swaps[0].clone()]);
+    assert_eq!(swap_history(&router, None, Some(1)), vec![swaps[2].clone()]);
+
+    let distributions: Vec<DistributionRecord> = router
+        .wrap()
+        .query_wasm_smart(
+            &maker_instance,
+            &QueryMsg::DistributionHistory {
+                start_time: None,
+                end_time: None,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(distributions.len(), 1);
+    let distribution = &distributions[0];
+    assert_eq!(
+        distribution.oro_amount,
+        swaps[1].asset_out.amount + swaps[2].asset_out.amount
+    );
+    assert_eq!(
+        distribution.governance_amount,
+        distribution.oro_amount.multiply_ratio(50u128, 100u128)
+    );
+    assert_eq!(
+        distribution.staking_amount,
+        distribution.oro_amount - distribution.governance_amount
+    );
+    check_balance(
+        &mut router,
+        staking.clone(),
+        oro_token_instance.clone(),
+        distribution.staking_amount,
+    );
+    check_balance(
+        &mut router,
+        governance_instance.clone(),
+        oro_token_instance.clone(),
+        distribution.governance_amount,
+    );
+
+    // Filter by time range
+    let now = router.block_info().time.seconds();
+    let distributions: Vec<DistributionRecord> = router
+        .wrap()
+        .query_wasm_smart(
+            &maker_instance,
+            &QueryMsg::DistributionHistory {
+                start_time: Some(now + 1),
+                end_time: None,
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert!(distributions.is_empty());
+    let swaps: Vec<SwapRecord> = router
+        .wrap()
+        .query_wasm_smart(
+            &maker_instance,
+            &QueryMsg::SwapHistory {
+                start_time: Some(now),
+                end_time: Some(now),
+                asset: None,
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+    assert_eq!(swaps.len(), 2);
+}
+
 #[test]
 fn update_bridges() {
     let owner = Addr::unchecked("owner");
@@ -2050,6 +2842,7 @@ fn test_collect_cooldown() {
                 pair_type: PairType::Xyk {},
                 asset_infos: asset_infos.to_vec(),
                 init_params: None,
+                fee_tier: None,
             },
             &[coin(1000, "uzig")], // Add pool creation fee
         )
@@ -2128,6 +2921,8 @@ fn set_dev_fund_config(
             oro_token: None,
             dev_fund_config: Some(Box::new(dev_fund_config)),
             critical_tokens: None,
+            router_contract: None,
+            price_guard: None,
         },
         &[],
     )
@@ -3795,6 +4590,8 @@ fn test_keeper_bridge_management_integration() {
             oro_token: None,
             dev_fund_config: None,
             critical_tokens: Some(critical_tokens.clone()),
+            router_contract: None,
+            price_guard: None,
         },
         &[],
     )
@@ -4070,6 +4867,8 @@ fn test_update_critical_tokens_integration() {
             oro_token: None,
             dev_fund_config: None,
             critical_tokens: Some(new_critical_tokens.clone()),
+            router_contract: None,
+            price_guard: None,
         },
         &[],
     )
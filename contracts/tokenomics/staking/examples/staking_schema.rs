@@ -1,11 +1,12 @@
 use cosmwasm_schema::write_api;
 
-use oroswap::staking::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use oroswap::staking::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         query: QueryMsg,
         execute: ExecuteMsg,
+        migrate: MigrateMsg,
     }
 }
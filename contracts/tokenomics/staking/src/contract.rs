@@ -1,10 +1,11 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, coin, ensure, to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Env,
-    MessageInfo, Reply, Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
+    attr, coin, ensure, to_json_binary, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut,
+    Env, MessageInfo, Order, Reply, Response, StdError, StdResult, SubMsg, Uint128, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 use cw_utils::{must_pay, parse_reply_instantiate_data, MsgInstantiateContractResponse};
 use osmosis_std::types::cosmos::bank::v1beta1::{DenomUnit, Metadata};
 use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
@@ -12,12 +13,15 @@ use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
     MsgSetDenomMetadata,
 };
 
+use oroswap::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
 use oroswap::staking::{
-    Config, ExecuteMsg, InstantiateMsg, QueryMsg, StakingResponse, TrackerData,
+    Config, ExecuteMsg, InstantiateMsg, QueryMsg, StakingResponse, TrackerData, UnbondingEntry,
+    MAX_INSTANT_EXIT_FEE, MAX_UNBONDING_PERIOD,
 };
+use oroswap::tokenfactory_tracker;
 
 use crate::error::ContractError;
-use crate::state::{CONFIG, TRACKER_DATA};
+use crate::state::{CONFIG, OWNERSHIP_PROPOSAL, TOTAL_UNBONDING, TRACKER_DATA, UNBONDINGS};
 
 /// Contract name that is used for migration.
 pub const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -49,6 +53,12 @@ impl TryFrom<u64> for ReplyIds {
 /// Minimum initial xoro share
 pub(crate) const MINIMUM_STAKE_AMOUNT: Uint128 = Uint128::new(1_000);
 
+/// The maximum number of unbonding entries processed in one claim
+const MAX_CLAIM_ENTRIES: usize = 50;
+/// Pagination limits for the unbondings query
+const MAX_LIMIT: u32 = 30;
+const DEFAULT_LIMIT: u32 = 10;
+
 /// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
@@ -69,6 +79,7 @@ pub fn instantiate(
     );
 
     // Validate addresses
+    let owner = deps.api.addr_validate(&msg.owner)?;
     deps.api.addr_validate(&msg.token_factory_addr)?;
     deps.api.addr_validate(&msg.tracking_admin)?;
 
@@ -94,14 +105,22 @@ pub fn instantiate(
         )));
     }
 
+    let unbonding_period = msg.unbonding_period.unwrap_or_default();
+    let instant_exit_fee = msg.instant_exit_fee.unwrap_or_default();
+    validate_exit_params(unbonding_period, instant_exit_fee)?;
+
     let deposit_token_denom = msg.deposit_token_denom.clone();
 
     CONFIG.save(
         deps.storage,
         &Config {
+            owner,
             oro_denom: msg.deposit_token_denom,
             xoro_denom: "".to_string(),
             bootstrap_amount,
+            unbonding_period,
+            instant_exit_fee,
+            paused: false,
         },
     )?;
 
@@ -140,6 +159,15 @@ pub fn instantiate(
 /// ## Variants
 /// * **ExecuteMsg::Enter** Stake the provided ORO tokens for xORO
 /// * **ExecuteMsg::Leave** Unstake the provided xORO tokens for ORO
+/// * **ExecuteMsg::InstantLeave** Unstake the provided xORO tokens for ORO bypassing the unbonding period
+/// * **ExecuteMsg::Claim** Claim ORO which finished unbonding
+/// * **ExecuteMsg::UpdateConfig** Update the unbonding period and the instant exit fee
+/// * **ExecuteMsg::UpdateTracker** Point xORO balance tracking to another tracker contract
+/// * **ExecuteMsg::Pause** Pause staking and unstaking
+/// * **ExecuteMsg::Unpause** Unpause staking and unstaking
+/// * **ExecuteMsg::ProposeNewOwner** Create a request to change contract ownership
+/// * **ExecuteMsg::DropOwnershipProposal** Remove a request to change contract ownership
+/// * **ExecuteMsg::ClaimOwnership** Claim contract ownership
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
@@ -176,7 +204,50 @@ pub fn execute(
         ExecuteMsg::Leave { receiver } => {
             // ORO is returned to the receiver if provided or to the sender.
             let recipient = receiver.unwrap_or_else(|| info.sender.to_string());
-            execute_leave(deps, env, info, recipient)
+            execute_leave(deps, env, info, recipient, false)
+        }
+        ExecuteMsg::InstantLeave { receiver } => {
+            let recipient = receiver.unwrap_or_else(|| info.sender.to_string());
+            execute_leave(deps, env, info, recipient, true)
+        }
+        ExecuteMsg::Claim {} => execute_claim(deps, env, info),
+        ExecuteMsg::UpdateConfig {
+            unbonding_period,
+            instant_exit_fee,
+        } => update_config(deps, info, unbonding_period, instant_exit_fee),
+        ExecuteMsg::UpdateTracker { tracker_addr } => update_tracker(deps, env, info, tracker_addr),
+        ExecuteMsg::Pause {} => set_paused(deps, info, true),
+        ExecuteMsg::Unpause {} => set_paused(deps, info, false),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            propose_new_owner(
+                deps,
+                info,
+                env,
+                owner,
+                expires_in,
+                config.owner,
+                OWNERSHIP_PROPOSAL,
+            )
+            .map_err(Into::into)
+        }
+        ExecuteMsg::DropOwnershipProposal {} => {
+            let config = CONFIG.load(deps.storage)?;
+
+            drop_ownership_proposal(deps, info, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(Into::into)
+        }
+        ExecuteMsg::ClaimOwnership {} => {
+            claim_ownership(deps, info, env, OWNERSHIP_PROPOSAL, |deps, new_owner| {
+                CONFIG.update::<_, StdError>(deps.storage, |mut v| {
+                    v.owner = new_owner;
+                    Ok(v)
+                })?;
+
+                Ok(())
+            })
+            .map_err(Into::into)
         }
     }
 }
@@ -292,17 +363,14 @@ fn execute_enter(
     info: MessageInfo,
 ) -> Result<(Response, Coin), ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    ensure!(!config.paused, ContractError::Paused {});
 
     // Ensure that the correct denom is sent. Sending zero tokens is prohibited on chain level
     let amount = must_pay(&info, &config.oro_denom)?;
 
     // Get the current deposits and shares held in the contract.
     // Amount sent along with the message already included. Subtract it from the total deposit
-    let total_deposit = deps
-        .querier
-        .query_balance(&env.contract.address, &config.oro_denom)?
-        .amount
-        - amount;
+    let total_deposit = query_total_deposit(deps.as_ref(), &env, &config)? - amount;
     let total_shares = deps.querier.query_supply(&config.xoro_denom)?.amount;
 
     let mut messages: Vec<CosmosMsg> = vec![];
@@ -349,24 +417,26 @@ fn execute_enter(
     ))
 }
 
-/// Leave unstakes TokenFactory xORO for ORO. xORO is burned and ORO
-/// returned to the sender
+/// Leave unstakes TokenFactory xORO for ORO. xORO is burned right away so xORO snapshots
+/// in the tracker never include unstaked shares.
+/// If the unbonding period is set, ORO is locked for the recipient until the period has passed.
+/// Instant leave returns ORO right away, minus the instant exit fee which stays in the pool.
 fn execute_leave(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     recipient: String,
+    instant: bool,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    ensure!(!config.paused, ContractError::Paused {});
+    let recipient = deps.api.addr_validate(&recipient)?;
 
     // Ensure that the correct denom is sent. Sending zero tokens is prohibited on chain level
     let amount = must_pay(&info, &config.xoro_denom)?;
 
     // Get the current deposits and shares held in the contract
-    let total_deposit = deps
-        .querier
-        .query_balance(&env.contract.address, &config.oro_denom)?
-        .amount;
+    let total_deposit = query_total_deposit(deps.as_ref(), &env, &config)?;
     let total_shares = deps.querier.query_supply(&config.xoro_denom)?.amount;
 
     // Calculate the amount of ORO to return based on the ratios of
@@ -379,7 +449,7 @@ fn execute_leave(
         return Err(ContractError::StakeAmountTooSmall {});
     }
 
-    let messages: Vec<CosmosMsg> = vec![
+    let mut messages: Vec<CosmosMsg> = vec![
         // Burn the received xORO tokens
         MsgBurn {
             sender: env.contract.address.to_string(),
@@ -387,39 +457,273 @@ fn execute_leave(
             burn_from_address: "".to_string(), // This needs to be "" for now
         }
         .into(),
-        // Send ORO to the sender
-        BankMsg::Send {
-            to_address: recipient.clone(),
-            amount: vec![coin(return_amount.u128(), config.oro_denom)],
-        }
-        .into(),
-        // Send xORO to itself to trigger total supply snapshot in tracker contract
+    ];
+    let mut attrs = vec![
+        attr("action", if instant { "instant_leave" } else { "leave" }),
+        attr("recipient", &recipient),
+        attr("xoro_amount", amount),
+    ];
+
+    // Without an unbonding period every leave is instant, so the fee is waived
+    let oro_amount = if instant && config.unbonding_period > 0 {
+        // The fee is left in the contract and increases the ORO per xORO rate
+        let fee_amount = return_amount * config.instant_exit_fee;
+        let oro_amount = return_amount - fee_amount;
+
+        messages.push(
+            BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![coin(oro_amount.u128(), &config.oro_denom)],
+            }
+            .into(),
+        );
+        attrs.push(attr("fee_amount", fee_amount));
+
+        oro_amount
+    } else if config.unbonding_period > 0 {
+        let release_time = env.block.time.seconds() + config.unbonding_period;
+        UNBONDINGS.update::<_, StdError>(
+            deps.storage,
+            (&recipient, release_time),
+            |unbonding| Ok(unbonding.unwrap_or_default() + return_amount),
+        )?;
+        let total_unbonding = TOTAL_UNBONDING.may_load(deps.storage)?.unwrap_or_default();
+        TOTAL_UNBONDING.save(deps.storage, &(total_unbonding + return_amount))?;
+        attrs.push(attr("release_time", release_time.to_string()));
+
+        return_amount
+    } else {
+        messages.push(
+            BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![coin(return_amount.u128(), &config.oro_denom)],
+            }
+            .into(),
+        );
+
+        return_amount
+    };
+    attrs.push(attr("oro_amount", oro_amount));
+
+    // Send xORO to itself to trigger total supply snapshot in tracker contract
+    messages.push(
         BankMsg::Send {
             to_address: env.contract.address.to_string(),
             amount: vec![coin(1, &config.xoro_denom)],
         }
         .into(),
-    ];
+    );
 
     // Set the data to be returned in set_data to easy integration with
     // other contracts
     let staking_response = to_json_binary(&StakingResponse {
-        oro_amount: return_amount,
+        oro_amount,
         xoro_amount: amount,
     })?;
 
     Ok(Response::new()
         .add_messages(messages)
         .set_data(staking_response)
+        .add_attributes(attrs))
+}
+
+/// Sends ORO which finished unbonding to the sender.
+/// At most [`MAX_CLAIM_ENTRIES`] entries are processed per call.
+fn execute_claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let released = UNBONDINGS
+        .prefix(&info.sender)
+        .range(
+            deps.storage,
+            None,
+            Some(Bound::inclusive(env.block.time.seconds())),
+            Order::Ascending,
+        )
+        .take(MAX_CLAIM_ENTRIES)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut claim_amount = Uint128::zero();
+    for (release_time, amount) in released {
+        UNBONDINGS.remove(deps.storage, (&info.sender, release_time));
+        claim_amount += amount;
+    }
+
+    if claim_amount.is_zero() {
+        return Err(ContractError::NothingToClaim {});
+    }
+
+    let total_unbonding = TOTAL_UNBONDING.load(deps.storage)?;
+    TOTAL_UNBONDING.save(deps.storage, &total_unbonding.checked_sub(claim_amount)?)?;
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(claim_amount.u128(), config.oro_denom)],
+        })
         .add_attributes([
-            attr("action", "leave"),
-            attr("recipient", recipient),
-            attr("xoro_amount", amount),
-            attr("oro_amount", return_amount),
+            attr("action", "claim"),
+            attr("recipient", info.sender),
+            attr("oro_amount", claim_amount),
         ]))
 }
 
+/// Updates the unbonding period and the instant exit fee.
+/// Existing unbonding entries keep their release time.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    unbonding_period: Option<u64>,
+    instant_exit_fee: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    ensure!(info.sender == config.owner, ContractError::Unauthorized {});
+
+    let mut attrs = vec![attr("action", "update_config")];
+
+    if let Some(unbonding_period) = unbonding_period {
+        config.unbonding_period = unbonding_period;
+        attrs.push(attr("unbonding_period", unbonding_period.to_string()));
+    }
+
+    if let Some(instant_exit_fee) = instant_exit_fee {
+        config.instant_exit_fee = instant_exit_fee;
+        attrs.push(attr("instant_exit_fee", instant_exit_fee.to_string()));
+    }
+
+    validate_exit_params(config.unbonding_period, config.instant_exit_fee)?;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Sets the xORO before send hook to the given tracker contract.
+/// Historical balances recorded by the previous tracker are not carried over.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn update_tracker(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tracker_addr: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    ensure!(info.sender == config.owner, ContractError::Unauthorized {});
+
+    let tracker_addr = deps.api.addr_validate(&tracker_addr)?;
+    let tracker_config: tokenfactory_tracker::ConfigResponse = deps
+        .querier
+        .query_wasm_smart(&tracker_addr, &tokenfactory_tracker::QueryMsg::Config {})?;
+    ensure!(
+        tracker_config.tracked_denom == config.xoro_denom && tracker_config.track_over_seconds,
+        ContractError::InvalidTracker {}
+    );
+    let contract_info = deps.querier.query_wasm_contract_info(&tracker_addr)?;
+
+    TRACKER_DATA.save(
+        deps.storage,
+        &TrackerData {
+            code_id: contract_info.code_id,
+            admin: contract_info.admin.unwrap_or_default(),
+            token_factory_addr: tracker_config.token_factory_module,
+            tracker_addr: tracker_addr.to_string(),
+        },
+    )?;
+
+    let set_hook_msg = MsgSetBeforeSendHook {
+        sender: env.contract.address.to_string(),
+        denom: config.xoro_denom,
+        cosmwasm_address: tracker_addr.to_string(),
+    };
+
+    Ok(Response::new().add_message(set_hook_msg).add_attributes([
+        attr("action", "update_tracker"),
+        attr("tracker_contract", tracker_addr),
+    ]))
+}
+
+/// Pauses or unpauses staking and unstaking. Claiming unbonded ORO is always available.
+///
+/// ## Executor
+/// Only the owner can execute this.
+fn set_paused(deps: DepsMut, info: MessageInfo, paused: bool) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    ensure!(info.sender == config.owner, ContractError::Unauthorized {});
+
+    if config.paused == paused {
+        return Err(if paused {
+            ContractError::AlreadyPaused {}
+        } else {
+            ContractError::NotPaused {}
+        });
+    }
 
+    config.paused = paused;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attribute("action", if paused { "pause" } else { "unpause" }))
+}
+
+/// Checks the unbonding period and the instant exit fee against their limits.
+fn validate_exit_params(unbonding_period: u64, instant_exit_fee: Decimal) -> StdResult<()> {
+    if unbonding_period > MAX_UNBONDING_PERIOD {
+        return Err(StdError::generic_err(format!(
+            "Unbonding period must not exceed {MAX_UNBONDING_PERIOD} seconds"
+        )));
+    }
+
+    if instant_exit_fee > MAX_INSTANT_EXIT_FEE {
+        return Err(StdError::generic_err(format!(
+            "Instant exit fee must not exceed {MAX_INSTANT_EXIT_FEE}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Returns the amount of ORO in the staking pool.
+/// ORO in unbonding is held by the contract but doesn't belong to the pool.
+fn query_total_deposit(deps: Deps, env: &Env, config: &Config) -> StdResult<Uint128> {
+    let balance = deps
+        .querier
+        .query_balance(&env.contract.address, &config.oro_denom)?
+        .amount;
+    let total_unbonding = TOTAL_UNBONDING.may_load(deps.storage)?.unwrap_or_default();
+
+    Ok(balance.checked_sub(total_unbonding)?)
+}
+
+/// Returns the pending unbonding entries of the given address.
+fn query_unbondings(
+    deps: Deps,
+    address: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<UnbondingEntry>> {
+    let address = deps.api.addr_validate(&address)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    UNBONDINGS
+        .prefix(&address)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| {
+            item.map(|(release_time, amount)| UnbondingEntry {
+                amount,
+                release_time,
+            })
+        })
+        .collect()
+}
 
 /// Exposes all the queries available in the contract.
 ///
@@ -427,13 +731,18 @@ fn execute_leave(
 ///
 /// * **QueryMsg::TotalShares {}** Returns the total xORO supply
 ///
-/// * **QueryMsg::TotalDeposit {}** Returns the amount of ORO that's currently in the staking pool
+/// * **QueryMsg::TotalDeposit {}** Returns the amount of ORO that's currently in the staking pool.
+/// ORO in unbonding is not included
 ///
 /// * **QueryMsg::TrackerConfig {}** Returns the tracker contract configuration
 ///
 /// * **QueryMsg::BalanceAt { address, timestamp }** Returns the xORO balance of the given address at the given timestamp
 ///
 /// * **QueryMsg::TotalSupplyAt { timestamp }** Returns xORO total supply at the given timestamp
+///
+/// * **QueryMsg::Unbondings { address, start_after, limit }** Returns the pending unbonding entries of the given address
+///
+/// * **QueryMsg::TotalUnbonding {}** Returns the total amount of ORO in unbonding
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -447,10 +756,7 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::TotalDeposit {} => {
             let config = CONFIG.load(deps.storage)?;
 
-            let total_deposit = deps
-                .querier
-                .query_balance(env.contract.address, config.oro_denom)?
-                .amount;
+            let total_deposit = query_total_deposit(deps, &env, &config)?;
             to_json_binary(&total_deposit)
         }
         QueryMsg::TrackerConfig {} => to_json_binary(&TRACKER_DATA.load(deps.storage)?),
@@ -484,5 +790,13 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
 
             to_json_binary(&amount)
         }
+        QueryMsg::Unbondings {
+            address,
+            start_after,
+            limit,
+        } => to_json_binary(&query_unbondings(deps, address, start_after, limit)?),
+        QueryMsg::TotalUnbonding {} => {
+            to_json_binary(&TOTAL_UNBONDING.may_load(deps.storage)?.unwrap_or_default())
+        }
     }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError};
 use cw_utils::{ParseReplyError, PaymentError};
 use thiserror::Error;
 
@@ -28,5 +28,27 @@ pub enum ContractError {
     #[error("Contract can't be migrated!")]
     MigrationError {},
 
+    #[error("Nothing to claim")]
+    NothingToClaim {},
 
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Staking is paused")]
+    Paused {},
+
+    #[error("Staking is already paused")]
+    AlreadyPaused {},
+
+    #[error("Staking is not paused")]
+    NotPaused {},
+
+    #[error("Tracker must track xORO over seconds")]
+    InvalidTracker {},
+}
+
+impl From<OverflowError> for ContractError {
+    fn from(o: OverflowError) -> Self {
+        StdError::from(o).into()
+    }
 }
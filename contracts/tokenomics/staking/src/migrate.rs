@@ -1,20 +1,51 @@
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{DepsMut, Env, Response};
+use cosmwasm_std::{Decimal, DepsMut, Env, Response, StdError, Uint128};
 use cw2::{get_contract_version, set_contract_version};
+use cw_storage_plus::Item;
 
 use crate::contract::{CONTRACT_NAME, CONTRACT_VERSION};
-use oroswap::pair::MigrateMsg;
+use crate::state::CONFIG;
+use oroswap::staking::{Config, MigrateMsg};
 
 use crate::error::ContractError;
 
+/// The config stored by versions before the contract owner was introduced
+#[cw_serde]
+struct ConfigV110 {
+    oro_denom: String,
+    xoro_denom: String,
+    bootstrap_amount: Uint128,
+}
+
+const CONFIG_V110: Item<ConfigV110> = Item::new("config");
+
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     let contract_version = get_contract_version(deps.storage)?;
 
     match contract_version.contract.as_ref() {
         "oroswap-staking" => match contract_version.version.as_ref() {
-            "2.0.0" | "2.1.0" => {}
+            "1.1.0" | "2.0.0" | "2.1.0" => {
+                let owner = msg
+                    .owner
+                    .ok_or_else(|| StdError::generic_err("owner is required"))?;
+                let config = CONFIG_V110.load(deps.storage)?;
+
+                CONFIG.save(
+                    deps.storage,
+                    &Config {
+                        owner: deps.api.addr_validate(&owner)?,
+                        oro_denom: config.oro_denom,
+                        xoro_denom: config.xoro_denom,
+                        bootstrap_amount: config.bootstrap_amount,
+                        unbonding_period: 0,
+                        instant_exit_fee: Decimal::zero(),
+                        paused: false,
+                    },
+                )?;
+            }
             _ => return Err(ContractError::MigrationError {}),
         },
         _ => return Err(ContractError::MigrationError {}),
@@ -28,3 +59,54 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
         .add_attribute("new_contract_name", CONTRACT_NAME)
         .add_attribute("new_contract_version", CONTRACT_VERSION))
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::{Addr, Storage};
+
+    #[test]
+    fn legacy_config_is_migrated() {
+        let mut deps = mock_dependencies();
+        set_contract_version(deps.as_mut().storage, "oroswap-staking", "1.1.0").unwrap();
+
+        // Config stored before the owner, pause and unbonding parameters were introduced
+        let legacy_config =
+            br#"{"oro_denom":"uoro","xoro_denom":"factory/staking/xoro","bootstrap_amount":"1000"}"#;
+        deps.storage.set(b"config", legacy_config.as_slice());
+        CONFIG.load(deps.as_ref().storage).unwrap_err();
+
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg { owner: None }).unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::Std(StdError::generic_err("owner is required"))
+        );
+
+        migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg {
+                owner: Some("owner".to_string()),
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            CONFIG.load(deps.as_ref().storage).unwrap(),
+            Config {
+                owner: Addr::unchecked("owner"),
+                oro_denom: "uoro".to_string(),
+                xoro_denom: "factory/staking/xoro".to_string(),
+                bootstrap_amount: Uint128::new(1000),
+                unbonding_period: 0,
+                instant_exit_fee: Decimal::zero(),
+                paused: false,
+            }
+        );
+        assert_eq!(
+            get_contract_version(deps.as_ref().storage).unwrap().version,
+            CONTRACT_VERSION
+        );
+    }
+}
@@ -1,11 +1,20 @@
-use cw_storage_plus::Item;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
 
+use oroswap::common::OwnershipProposal;
 use oroswap::staking::{Config, TrackerData};
 
 /// Stores the contract config at the given key
 pub const CONFIG: Item<Config> = Item::new("config");
 
+/// Contains a proposal to change contract ownership
+pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
+
 /// Stores the tracker contract instantiate data at the given key
 pub const TRACKER_DATA: Item<TrackerData> = Item::new("tracker_data");
 
+/// Stores ORO in unbonding per receiver and release timestamp
+pub const UNBONDINGS: Map<(&Addr, u64), Uint128> = Map::new("unbondings");
 
+/// Stores the total amount of ORO in unbonding. This ORO is excluded from the staking pool
+pub const TOTAL_UNBONDING: Item<Uint128> = Item::new("total_unbonding");
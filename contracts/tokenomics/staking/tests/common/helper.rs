@@ -4,7 +4,7 @@ use anyhow::Result as AnyResult;
 use cosmwasm_schema::serde::Serialize;
 use cosmwasm_std::testing::MockApi;
 use cosmwasm_std::{
-    coins, to_json_binary, Addr, Coin, DepsMut, Empty, Env, GovMsg, IbcMsg, IbcQuery,
+    coins, to_json_binary, Addr, Coin, Decimal, DepsMut, Empty, Env, GovMsg, IbcMsg, IbcQuery,
     MemoryStorage, MessageInfo, Response, StdResult, Uint128,
 };
 use cw_multi_test::{
@@ -12,7 +12,7 @@ use cw_multi_test::{
     Executor, FailingModule, StakeKeeper, WasmKeeper, TOKEN_FACTORY_MODULE,
 };
 
-use oroswap::staking::{Config, ExecuteMsg, InstantiateMsg, QueryMsg, TrackerData};
+use oroswap::staking::{Config, ExecuteMsg, InstantiateMsg, QueryMsg, TrackerData, UnbondingEntry};
 
 use crate::common::stargate::StargateKeeper;
 
@@ -65,6 +65,14 @@ pub const ORO_DENOM: &str = "coin.assembly.oro";
 
 impl Helper {
     pub fn new(owner: &Addr) -> AnyResult<Self> {
+        Self::new_with_unbonding(owner, None, None)
+    }
+
+    pub fn new_with_unbonding(
+        owner: &Addr,
+        unbonding_period: Option<u64>,
+        instant_exit_fee: Option<Decimal>,
+    ) -> AnyResult<Self> {
         let mut app = BasicAppBuilder::new()
             .with_stargate(StargateKeeper::default())
             .build(|router, _, storage| {
@@ -78,22 +86,23 @@ impl Helper {
         let tracker_code_id = app.store_code(tracker_contract());
 
         let msg = InstantiateMsg {
+            owner: owner.to_string(),
             deposit_token_denom: ORO_DENOM.to_string(),
             tracking_admin: owner.to_string(),
             tracking_code_id: tracker_code_id,
             token_factory_addr: TOKEN_FACTORY_MODULE.to_string(),
             bootstrap_amount: None, // Use default MINIMUM_STAKE_AMOUNT
+            unbonding_period,
+            instant_exit_fee,
         };
-        let staking = app
-            .instantiate_contract(
-                staking_code_id,
-                owner.clone(),
-                &msg,
-                &coins(1000, ORO_DENOM), // Bootstrap amount
-                String::from("Oroswap Staking"),
-                None,
-            )
-            .unwrap();
+        let staking = app.instantiate_contract(
+            staking_code_id,
+            owner.clone(),
+            &msg,
+            &coins(1000, ORO_DENOM), // Bootstrap amount
+            String::from("Oroswap Staking"),
+            None,
+        )?;
 
         let TrackerData { tracker_addr, .. } = app
             .wrap()
@@ -159,6 +168,41 @@ impl Helper {
         )
     }
 
+    pub fn instant_unstake(&mut self, sender: &Addr, amount: u128) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.staking.clone(),
+            &ExecuteMsg::InstantLeave { receiver: None },
+            &coins(amount, &self.xoro_denom),
+        )
+    }
+
+    pub fn claim(&mut self, sender: &Addr) -> AnyResult<AppResponse> {
+        self.app.execute_contract(
+            sender.clone(),
+            self.staking.clone(),
+            &ExecuteMsg::Claim {},
+            &[],
+        )
+    }
+
+    pub fn query_unbondings(&self, address: &Addr) -> StdResult<Vec<UnbondingEntry>> {
+        self.app.wrap().query_wasm_smart(
+            &self.staking,
+            &QueryMsg::Unbondings {
+                address: address.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+    }
+
+    pub fn query_total_deposit(&self) -> StdResult<Uint128> {
+        self.app
+            .wrap()
+            .query_wasm_smart(&self.staking, &QueryMsg::TotalDeposit {})
+    }
+
     pub fn query_balance(&self, sender: &Addr, denom: &str) -> StdResult<Uint128> {
         self.app
             .wrap()
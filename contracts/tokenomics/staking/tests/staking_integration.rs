@@ -4,17 +4,21 @@ use std::collections::HashMap;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    coin, coins, from_json, Addr, BankMsg, Binary, BlockInfo, Deps, DepsMut, Empty, Env,
+    coin, coins, from_json, Addr, BankMsg, Binary, BlockInfo, Decimal, Deps, DepsMut, Empty, Env,
     MessageInfo, Response, StdResult, Timestamp, Uint128,
 };
 use cw_multi_test::{Contract, ContractWrapper, Executor, TOKEN_FACTORY_MODULE};
 use cw_utils::PaymentError;
 use itertools::Itertools;
 
-use oroswap::staking::{Config, ExecuteMsg, QueryMsg, StakingResponse, TrackerData};
+use oroswap::staking::{
+    Config, ExecuteMsg, QueryMsg, StakingResponse, TrackerData, UnbondingEntry,
+    MAX_INSTANT_EXIT_FEE, MAX_UNBONDING_PERIOD,
+};
+use oroswap::tokenfactory_tracker;
 use oroswap_staking::error::ContractError;
 
-use crate::common::helper::{Helper, ORO_DENOM};
+use crate::common::helper::{CustomizedApp, Helper, ORO_DENOM};
 
 mod common;
 
@@ -32,9 +36,13 @@ fn test_instantiate() {
     assert_eq!(
         response,
         Config {
+            owner: owner.clone(),
             oro_denom: ORO_DENOM.to_string(),
             xoro_denom: format!("coin.{}.xORO", &helper.staking),
             bootstrap_amount: Uint128::new(1000),
+            unbonding_period: 0,
+            instant_exit_fee: Decimal::zero(),
+            paused: false,
         }
     );
 
@@ -630,3 +638,509 @@ fn test_zero_return_amount_prevention() {
     println!("✅ Contract compiles successfully with the new check");
     println!("✅ Normal staking/unstaking operations continue to work");
 }
+
+#[test]
+fn test_unbonding_validation() {
+    let owner = Addr::unchecked("owner");
+
+    let err = Helper::new_with_unbonding(&owner, Some(MAX_UNBONDING_PERIOD + 1), None).unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("Generic error: Unbonding period must not exceed {MAX_UNBONDING_PERIOD} seconds")
+    );
+
+    let err = Helper::new_with_unbonding(
+        &owner,
+        Some(MAX_UNBONDING_PERIOD),
+        Some(MAX_INSTANT_EXIT_FEE + Decimal::percent(1)),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("Generic error: Instant exit fee must not exceed {MAX_INSTANT_EXIT_FEE}")
+    );
+}
+
+#[test]
+fn test_leave_with_unbonding() {
+    let owner = Addr::unchecked("owner");
+    let week = 86400 * 7;
+
+    let mut helper =
+        Helper::new_with_unbonding(&owner, Some(week), Some(Decimal::percent(10))).unwrap();
+    helper.app.set_block(BlockInfo {
+        height: 1000,
+        time: Timestamp::from_seconds(1700000000),
+        chain_id: "".to_string(),
+    });
+    let xoro_denom = helper.xoro_denom.clone();
+    let staking = helper.staking.clone();
+
+    let alice = Addr::unchecked("alice");
+    helper.give_oro(10000, &alice);
+    helper.stake(&alice, 1000).unwrap();
+
+    let start_time = helper.app.block_info().time.seconds();
+    let resp_data = helper.unstake(&alice, 500).unwrap().data.unwrap();
+    let staking_resp: StakingResponse = from_json(&resp_data).unwrap();
+    assert_eq!(
+        staking_resp,
+        StakingResponse {
+            oro_amount: 500u128.into(),
+            xoro_amount: 500u128.into(),
+        }
+    );
+
+    // xORO is burned right away while ORO stays in the contract
+    assert_eq!(
+        helper.query_balance(&alice, &xoro_denom).unwrap().u128(),
+        500
+    );
+    assert_eq!(
+        helper.query_balance(&alice, ORO_DENOM).unwrap().u128(),
+        9000
+    );
+    assert_eq!(
+        helper.query_balance(&staking, ORO_DENOM).unwrap().u128(),
+        2000
+    );
+    assert_eq!(helper.query_total_deposit().unwrap().u128(), 1500);
+    assert_eq!(
+        helper.query_unbondings(&alice).unwrap(),
+        [UnbondingEntry {
+            amount: 500u128.into(),
+            release_time: start_time + week,
+        }]
+    );
+
+    // Historical xORO snapshots don't include unbonding shares
+    helper.next_block(100);
+    assert_eq!(
+        helper
+            .query_xoro_balance_at(&alice, Some(start_time + 1))
+            .unwrap()
+            .u128(),
+        500
+    );
+    assert_eq!(
+        helper
+            .query_xoro_supply_at(Some(start_time + 1))
+            .unwrap()
+            .u128(),
+        1500
+    );
+
+    let err = helper.claim(&alice).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NothingToClaim {}
+    );
+
+    // Rewards arrive. ORO in unbonding doesn't earn them
+    helper.give_oro(1500, &staking);
+    helper.next_block(86400);
+
+    let resp_data = helper.unstake(&alice, 100).unwrap().data.unwrap();
+    let staking_resp: StakingResponse = from_json(&resp_data).unwrap();
+    assert_eq!(staking_resp.oro_amount.u128(), 200);
+
+    let unbondings = helper.query_unbondings(&alice).unwrap();
+    assert_eq!(
+        unbondings
+            .iter()
+            .map(|entry| entry.amount.u128())
+            .collect::<Vec<_>>(),
+        [500, 200]
+    );
+    let total_unbonding: Uint128 = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&staking, &QueryMsg::TotalUnbonding {})
+        .unwrap();
+    assert_eq!(total_unbonding.u128(), 700);
+
+    helper.next_block(week - 86400);
+    helper.claim(&alice).unwrap();
+    assert_eq!(
+        helper.query_balance(&alice, ORO_DENOM).unwrap().u128(),
+        9500
+    );
+    assert_eq!(helper.query_unbondings(&alice).unwrap(), unbondings[1..]);
+
+    let err = helper.claim(&alice).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NothingToClaim {}
+    );
+
+    helper.next_block(86400);
+    helper.claim(&alice).unwrap();
+    assert_eq!(
+        helper.query_balance(&alice, ORO_DENOM).unwrap().u128(),
+        9700
+    );
+    assert!(helper.query_unbondings(&alice).unwrap().is_empty());
+
+    // All ORO left in the contract belongs to the pool
+    assert_eq!(
+        helper.query_total_deposit().unwrap(),
+        helper.query_balance(&staking, ORO_DENOM).unwrap()
+    );
+}
+
+#[test]
+fn test_instant_leave() {
+    let owner = Addr::unchecked("owner");
+
+    let mut helper =
+        Helper::new_with_unbonding(&owner, Some(86400 * 7), Some(Decimal::percent(10))).unwrap();
+    let xoro_denom = helper.xoro_denom.clone();
+
+    let alice = Addr::unchecked("alice");
+    let bob = Addr::unchecked("bob");
+    helper.give_oro(10000, &alice);
+    helper.give_oro(10000, &bob);
+    helper.stake(&alice, 1000).unwrap();
+
+    let resp_data = helper.instant_unstake(&alice, 1000).unwrap().data.unwrap();
+    let staking_resp: StakingResponse = from_json(&resp_data).unwrap();
+    assert_eq!(
+        staking_resp,
+        StakingResponse {
+            oro_amount: 900u128.into(),
+            xoro_amount: 1000u128.into(),
+        }
+    );
+    assert_eq!(
+        helper.query_balance(&alice, ORO_DENOM).unwrap().u128(),
+        9900
+    );
+    assert!(helper.query_unbondings(&alice).unwrap().is_empty());
+
+    // The fee stays with the remaining stakers
+    assert_eq!(helper.query_total_deposit().unwrap().u128(), 1100);
+    helper.stake(&bob, 1100).unwrap();
+    assert_eq!(
+        helper.query_balance(&bob, &xoro_denom).unwrap().u128(),
+        1000
+    );
+}
+
+#[test]
+fn test_instant_leave_without_unbonding() {
+    let owner = Addr::unchecked("owner");
+
+    let mut helper = Helper::new_with_unbonding(&owner, None, Some(Decimal::percent(10))).unwrap();
+
+    let alice = Addr::unchecked("alice");
+    helper.give_oro(10000, &alice);
+    helper.stake(&alice, 1000).unwrap();
+
+    // Leave is already instant, so the instant exit fee is not charged
+    let resp_data = helper.instant_unstake(&alice, 1000).unwrap().data.unwrap();
+    let staking_resp: StakingResponse = from_json(&resp_data).unwrap();
+    assert_eq!(
+        staking_resp,
+        StakingResponse {
+            oro_amount: 1000u128.into(),
+            xoro_amount: 1000u128.into(),
+        }
+    );
+    assert_eq!(
+        helper.query_balance(&alice, ORO_DENOM).unwrap().u128(),
+        10000
+    );
+    assert_eq!(helper.query_total_deposit().unwrap().u128(), 1000);
+}
+
+#[test]
+fn test_update_config_and_ownership() {
+    let owner = Addr::unchecked("owner");
+    let new_owner = Addr::unchecked("new_owner");
+    let mut helper = Helper::new(&owner).unwrap();
+    let staking = helper.staking.clone();
+
+    let update_msg = ExecuteMsg::UpdateConfig {
+        unbonding_period: Some(86400),
+        instant_exit_fee: Some(Decimal::percent(5)),
+    };
+    let err = helper
+        .app
+        .execute_contract(new_owner.clone(), staking.clone(), &update_msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    let err = helper
+        .app
+        .execute_contract(
+            owner.clone(),
+            staking.clone(),
+            &ExecuteMsg::UpdateConfig {
+                unbonding_period: Some(MAX_UNBONDING_PERIOD + 1),
+                instant_exit_fee: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        format!("Generic error: Unbonding period must not exceed {MAX_UNBONDING_PERIOD} seconds")
+    );
+
+    helper
+        .app
+        .execute_contract(owner.clone(), staking.clone(), &update_msg, &[])
+        .unwrap();
+    let config: Config = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&staking, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.unbonding_period, 86400);
+    assert_eq!(config.instant_exit_fee, Decimal::percent(5));
+
+    // Two-step ownership transfer
+    helper
+        .app
+        .execute_contract(
+            owner.clone(),
+            staking.clone(),
+            &ExecuteMsg::ProposeNewOwner {
+                owner: new_owner.to_string(),
+                expires_in: 100,
+            },
+            &[],
+        )
+        .unwrap();
+    helper
+        .app
+        .execute_contract(
+            owner.clone(),
+            staking.clone(),
+            &ExecuteMsg::ClaimOwnership {},
+            &[],
+        )
+        .unwrap_err();
+    helper
+        .app
+        .execute_contract(
+            new_owner.clone(),
+            staking.clone(),
+            &ExecuteMsg::ClaimOwnership {},
+            &[],
+        )
+        .unwrap();
+
+    let config: Config = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&staking, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(config.owner, new_owner);
+
+    let err = helper
+        .app
+        .execute_contract(owner, staking.clone(), &ExecuteMsg::Pause {}, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+    helper
+        .app
+        .execute_contract(new_owner, staking, &ExecuteMsg::Pause {}, &[])
+        .unwrap();
+}
+
+#[test]
+fn test_pause() {
+    let owner = Addr::unchecked("owner");
+    let mut helper = Helper::new_with_unbonding(&owner, Some(86400), None).unwrap();
+    let staking = helper.staking.clone();
+
+    let alice = Addr::unchecked("alice");
+    helper.give_oro(10000, &alice);
+    helper.stake(&alice, 1000).unwrap();
+    helper.unstake(&alice, 500).unwrap();
+
+    let err = helper
+        .app
+        .execute_contract(owner.clone(), staking.clone(), &ExecuteMsg::Unpause {}, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::NotPaused {}
+    );
+
+    helper
+        .app
+        .execute_contract(owner.clone(), staking.clone(), &ExecuteMsg::Pause {}, &[])
+        .unwrap();
+    let err = helper
+        .app
+        .execute_contract(owner.clone(), staking.clone(), &ExecuteMsg::Pause {}, &[])
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::AlreadyPaused {}
+    );
+
+    let err = helper.stake(&alice, 1000).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Paused {}
+    );
+    let err = helper.unstake(&alice, 100).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Paused {}
+    );
+    let err = helper.instant_unstake(&alice, 100).unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Paused {}
+    );
+
+    // Unbonded ORO can still be claimed
+    helper.next_block(86400);
+    helper.claim(&alice).unwrap();
+    assert_eq!(
+        helper.query_balance(&alice, ORO_DENOM).unwrap().u128(),
+        9500
+    );
+
+    helper
+        .app
+        .execute_contract(owner, staking, &ExecuteMsg::Unpause {}, &[])
+        .unwrap();
+    helper.stake(&alice, 1000).unwrap();
+}
+
+#[test]
+fn test_update_tracker() {
+    let owner = Addr::unchecked("owner");
+    let mut helper = Helper::new(&owner).unwrap();
+    helper.app.set_block(BlockInfo {
+        height: 1000,
+        time: Timestamp::from_seconds(1700000000),
+        chain_id: "".to_string(),
+    });
+    let staking = helper.staking.clone();
+    let xoro_denom = helper.xoro_denom.clone();
+
+    let alice = Addr::unchecked("alice");
+    let bob = Addr::unchecked("bob");
+    helper.give_oro(10000, &alice);
+    helper.stake(&alice, 1000).unwrap();
+
+    let TrackerData { code_id, .. } = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&staking, &QueryMsg::TrackerConfig {})
+        .unwrap();
+    let instantiate_tracker = |app: &mut CustomizedApp, tracked_denom: &str| {
+        app.instantiate_contract(
+            code_id,
+            owner.clone(),
+            &tokenfactory_tracker::InstantiateMsg {
+                tokenfactory_module_address: TOKEN_FACTORY_MODULE.to_string(),
+                tracked_denom: tracked_denom.to_string(),
+                track_over_seconds: true,
+            },
+            &[],
+            "New tracker",
+            Some(owner.to_string()),
+        )
+        .unwrap()
+    };
+
+    let wrong_tracker = instantiate_tracker(&mut helper.app, ORO_DENOM);
+    let err = helper
+        .app
+        .execute_contract(
+            owner.clone(),
+            staking.clone(),
+            &ExecuteMsg::UpdateTracker {
+                tracker_addr: wrong_tracker.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::InvalidTracker {}
+    );
+
+    let new_tracker = instantiate_tracker(&mut helper.app, &xoro_denom);
+    let err = helper
+        .app
+        .execute_contract(
+            alice.clone(),
+            staking.clone(),
+            &ExecuteMsg::UpdateTracker {
+                tracker_addr: new_tracker.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast::<ContractError>().unwrap(),
+        ContractError::Unauthorized {}
+    );
+
+    helper
+        .app
+        .execute_contract(
+            owner.clone(),
+            staking.clone(),
+            &ExecuteMsg::UpdateTracker {
+                tracker_addr: new_tracker.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    let tracker_data: TrackerData = helper
+        .app
+        .wrap()
+        .query_wasm_smart(&staking, &QueryMsg::TrackerConfig {})
+        .unwrap();
+    assert_eq!(
+        tracker_data,
+        TrackerData {
+            code_id,
+            admin: owner.to_string(),
+            token_factory_addr: TOKEN_FACTORY_MODULE.to_string(),
+            tracker_addr: new_tracker.to_string(),
+        }
+    );
+
+    // xORO transfers are now recorded by the new tracker
+    helper.next_block(100);
+    helper
+        .app
+        .send_tokens(alice.clone(), bob.clone(), &coins(300, &xoro_denom))
+        .unwrap();
+    let time = helper.app.block_info().time.seconds();
+    let balance: Uint128 = helper
+        .app
+        .wrap()
+        .query_wasm_smart(
+            &new_tracker,
+            &tokenfactory_tracker::QueryMsg::BalanceAt {
+                address: bob.to_string(),
+                unit: Some(time + 1),
+            },
+        )
+        .unwrap();
+    assert_eq!(balance.u128(), 300);
+    assert_eq!(
+        helper
+            .query_xoro_balance_at(&alice, Some(time + 1))
+            .unwrap()
+            .u128(),
+        700
+    );
+}
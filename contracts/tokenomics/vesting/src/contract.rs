@@ -1,8 +1,10 @@
+use std::iter;
+
 use cosmwasm_std::{
     attr, entry_point, from_json, to_json_binary, Addr, Binary, Deps,
     DepsMut, Env, MessageInfo, Response, StdError, StdResult, SubMsg, Uint128,
 };
-use cw2::set_contract_version;
+use cw2::{get_contract_version, set_contract_version};
 use cw20::Cw20ReceiveMsg;
 use cw_utils::must_pay;
 
@@ -11,11 +13,14 @@ use oroswap::common::{claim_ownership, drop_ownership_proposal, propose_new_owne
 use oroswap::vesting::{
     ConfigResponse, Cw20HookMsg, ExecuteMsg, InstantiateMsg, MigrateMsg, OrderBy, QueryMsg,
     VestingAccount, VestingAccountResponse, VestingAccountsResponse, VestingInfo, VestingSchedule,
-    VestingSchedulePoint,
+    VestingSchedulePoint, VestingShape,
 };
 
 use crate::error::ContractError;
-use crate::state::{read_vesting_infos, Config, CONFIG, OWNERSHIP_PROPOSAL, VESTING_INFO};
+use crate::state::{
+    read_vesting_infos, Config, BENEFICIARY_PROPOSALS, CLAIM_OPERATORS, CONFIG, OWNERSHIP_PROPOSAL,
+    VESTING_INFO,
+};
 
 /// Contract name that is used for migration.
 const CONTRACT_NAME: &str = "oroswap-vesting";
@@ -23,6 +28,8 @@ const CONTRACT_NAME: &str = "oroswap-vesting";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Maximum limit of schedules per user
 const SCHEDULES_LIMIT: usize = 8;
+/// Maximum number of curve points or periodic unlocks in a vesting shape
+const SHAPE_POINTS_LIMIT: u64 = 256;
 
 /// Creates a new contract with the specified parameters in [`InstantiateMsg`].
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -58,6 +65,15 @@ pub fn instantiate(
 ///
 /// * **ExecuteMsg::Claim { recipient, amount }** Claims vested tokens and transfers them to the vesting recipient.
 ///
+/// * **ExecuteMsg::ClaimFor { beneficiary, amount }** Claims vested tokens on behalf of a beneficiary.
+///
+/// * **ExecuteMsg::SetClaimOperator { operator }** Sets or removes the claim operator of the sender.
+///
+/// * **ExecuteMsg::ProposeBeneficiaryChange { new_beneficiary }** Proposes to move the sender's
+/// vesting account to a new beneficiary.
+///
+/// * **ExecuteMsg::AcceptBeneficiaryChange { beneficiary }** Moves a vesting account to the proposed beneficiary.
+///
 /// * **ExecuteMsg::Receive(msg)** Receives a message of type [`Cw20ReceiveMsg`] and processes it
 /// depending on the received template.
 ///
@@ -67,6 +83,9 @@ pub fn instantiate(
 /// * **ExecuteMsg::WithdrawFromActiveSchedule { account, recipient, withdraw_amount }**
 /// Withdraws tokens from the only one active vesting schedule of the specified account.
 ///
+/// * **ExecuteMsg::RevokeVestingAccount { address, recipient }** Terminates revocable vesting schedules
+/// of the specified account.
+///
 /// * **ExecuteMsg::ProposeNewOwner { owner, expires_in }** Creates a new request to change contract ownership.
 ///
 /// * **ExecuteMsg::DropOwnershipProposal {}** Removes a request to change contract ownership.
@@ -81,7 +100,30 @@ pub fn execute(
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
     match msg {
-        ExecuteMsg::Claim { recipient, amount } => claim(deps, env, info, recipient, amount),
+        ExecuteMsg::Claim { recipient, amount } => {
+            let recipient =
+                addr_opt_validate(deps.api, &recipient)?.unwrap_or_else(|| info.sender.clone());
+            claim(deps, env, info.sender, recipient, amount)
+        }
+        ExecuteMsg::ClaimFor {
+            beneficiary,
+            amount,
+        } => {
+            let beneficiary = deps.api.addr_validate(&beneficiary)?;
+            let operator = CLAIM_OPERATORS.may_load(deps.storage, &beneficiary)?;
+            if operator.as_ref() != Some(&info.sender) {
+                return Err(ContractError::Unauthorized {});
+            }
+
+            claim(deps, env, beneficiary.clone(), beneficiary, amount)
+        }
+        ExecuteMsg::SetClaimOperator { operator } => set_claim_operator(deps, info, operator),
+        ExecuteMsg::ProposeBeneficiaryChange { new_beneficiary } => {
+            propose_beneficiary_change(deps, info, new_beneficiary)
+        }
+        ExecuteMsg::AcceptBeneficiaryChange { beneficiary } => {
+            accept_beneficiary_change(deps, info, beneficiary)
+        }
         ExecuteMsg::Receive(msg) => receive_cw20(deps, env, info, msg),
         ExecuteMsg::RegisterVestingAccounts { vesting_accounts } => {
             let config = CONFIG.load(deps.storage)?;
@@ -99,6 +141,9 @@ pub fn execute(
             recipient,
             withdraw_amount,
         } => withdraw_from_active_schedule(deps, env, info, account, recipient, withdraw_amount),
+        ExecuteMsg::RevokeVestingAccount { address, recipient } => {
+            revoke_vesting_account(deps, env, info, address, recipient)
+        }
         ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
             let config: Config = CONFIG.load(deps.storage)?;
 
@@ -244,6 +289,12 @@ fn assert_vesting_schedules(
             {
                 return Err(ContractError::VestingScheduleError(addr.to_string()));
             }
+
+            if let Some(shape) = &sch.shape {
+                assert_vesting_shape(addr, &sch.start_point, end_point, shape)?;
+            }
+        } else if sch.shape.is_some() {
+            return Err(ContractError::VestingShapeError(addr.to_string()));
         } else {
             // For schedules without end_point, enforce start_time >= block_time
             // This prevents immediate unlocking of tokens with past start times
@@ -256,20 +307,56 @@ fn assert_vesting_schedules(
     Ok(())
 }
 
+/// Asserts that a [`VestingShape`] fits between the start and end points of its schedule.
+fn assert_vesting_shape(
+    addr: &Addr,
+    start_point: &VestingSchedulePoint,
+    end_point: &VestingSchedulePoint,
+    shape: &VestingShape,
+) -> Result<(), ContractError> {
+    let is_valid = match shape {
+        VestingShape::Cliff { time } => start_point.time < *time && *time < end_point.time,
+        VestingShape::Periodic { period } => {
+            let duration = end_point.time - start_point.time;
+            *period > 0
+                && *period < duration
+                && duration.div_ceil(*period) <= SHAPE_POINTS_LIMIT
+        }
+        VestingShape::Piecewise { points } => {
+            !points.is_empty()
+                && points.len() as u64 <= SHAPE_POINTS_LIMIT
+                && iter::once(start_point)
+                    .chain(points)
+                    .chain(iter::once(end_point))
+                    .collect::<Vec<_>>()
+                    .windows(2)
+                    .all(|pair| pair[0].time < pair[1].time && pair[0].amount <= pair[1].amount)
+        }
+    };
+
+    if !is_valid {
+        return Err(ContractError::VestingShapeError(addr.to_string()));
+    }
+
+    Ok(())
+}
+
 /// Claims vested tokens and transfers them to the vesting recipient.
 ///
-/// * **recipient** vesting recipient for which to claim tokens.
+/// * **beneficiary** vesting account for which to claim tokens.
+///
+/// * **recipient** address that receives the claimed tokens.
 ///
 /// * **amount** amount of vested tokens to claim.
 pub fn claim(
     deps: DepsMut,
     env: Env,
-    info: MessageInfo,
-    recipient: Option<String>,
+    beneficiary: Addr,
+    recipient: Addr,
     amount: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
-    let mut vesting_info = VESTING_INFO.load(deps.storage, &info.sender)?;
+    let mut vesting_info = VESTING_INFO.load(deps.storage, &beneficiary)?;
 
     let available_amount = compute_available_amount(env.block.time.seconds(), &vesting_info)?;
 
@@ -285,23 +372,116 @@ pub fn claim(
     let mut response = Response::new();
 
     if !claim_amount.is_zero() {
-        let transfer_msg = config.vesting_token.with_balance(claim_amount).into_msg(
-            addr_opt_validate(deps.api, &recipient)?.unwrap_or_else(|| info.sender.clone()),
-        )?;
+        let transfer_msg = config
+            .vesting_token
+            .with_balance(claim_amount)
+            .into_msg(recipient)?;
         response = response.add_submessage(SubMsg::new(transfer_msg));
 
         vesting_info.released_amount = vesting_info.released_amount.checked_add(claim_amount)?;
-        VESTING_INFO.save(deps.storage, &info.sender, &vesting_info)?;
+        VESTING_INFO.save(deps.storage, &beneficiary, &vesting_info)?;
     };
 
     Ok(response.add_attributes(vec![
         attr("action", "claim"),
-        attr("address", &info.sender),
+        attr("address", &beneficiary),
         attr("available_amount", available_amount),
         attr("claimed_amount", claim_amount),
     ]))
 }
 
+/// Sets or removes the address allowed to claim vested tokens on behalf of the sender.
+///
+/// * **operator** new claim operator. The current operator is removed if not specified.
+fn set_claim_operator(
+    deps: DepsMut,
+    info: MessageInfo,
+    operator: Option<String>,
+) -> Result<Response, ContractError> {
+    if !VESTING_INFO.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let operator = addr_opt_validate(deps.api, &operator)?;
+    match &operator {
+        Some(operator) => CLAIM_OPERATORS.save(deps.storage, &info.sender, operator)?,
+        None => CLAIM_OPERATORS.remove(deps.storage, &info.sender),
+    }
+
+    Ok(Response::new().add_attributes([
+        attr("action", "set_claim_operator"),
+        attr("address", info.sender),
+        attr(
+            "operator",
+            operator
+                .map(String::from)
+                .unwrap_or_else(|| "none".to_string()),
+        ),
+    ]))
+}
+
+/// Proposes to move the sender's vesting account to a new beneficiary.
+///
+/// * **new_beneficiary** address that will be able to accept the vesting account.
+fn propose_beneficiary_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_beneficiary: String,
+) -> Result<Response, ContractError> {
+    if !VESTING_INFO.has(deps.storage, &info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_beneficiary = deps.api.addr_validate(&new_beneficiary)?;
+    if VESTING_INFO.has(deps.storage, &new_beneficiary) {
+        return Err(ContractError::BeneficiaryAlreadyVesting(
+            new_beneficiary.to_string(),
+        ));
+    }
+
+    BENEFICIARY_PROPOSALS.save(deps.storage, &info.sender, &new_beneficiary)?;
+
+    Ok(Response::new().add_attributes([
+        attr("action", "propose_beneficiary_change"),
+        attr("address", info.sender),
+        attr("new_beneficiary", new_beneficiary),
+    ]))
+}
+
+/// Moves the whole vesting account of the current beneficiary to the proposed one.
+/// The claim operator of the previous beneficiary is removed.
+///
+/// * **beneficiary** current beneficiary of the vesting account.
+fn accept_beneficiary_change(
+    deps: DepsMut,
+    info: MessageInfo,
+    beneficiary: String,
+) -> Result<Response, ContractError> {
+    let beneficiary = deps.api.addr_validate(&beneficiary)?;
+    let proposal = BENEFICIARY_PROPOSALS.may_load(deps.storage, &beneficiary)?;
+    if proposal.as_ref() != Some(&info.sender) {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if VESTING_INFO.has(deps.storage, &info.sender) {
+        return Err(ContractError::BeneficiaryAlreadyVesting(
+            info.sender.to_string(),
+        ));
+    }
+
+    let vesting_info = VESTING_INFO.load(deps.storage, &beneficiary)?;
+    VESTING_INFO.save(deps.storage, &info.sender, &vesting_info)?;
+    VESTING_INFO.remove(deps.storage, &beneficiary);
+    BENEFICIARY_PROPOSALS.remove(deps.storage, &beneficiary);
+    CLAIM_OPERATORS.remove(deps.storage, &beneficiary);
+
+    Ok(Response::new().add_attributes([
+        attr("action", "accept_beneficiary_change"),
+        attr("previous_beneficiary", beneficiary),
+        attr("new_beneficiary", info.sender),
+    ]))
+}
+
 /// Computes the amount of vested and yet unclaimed tokens for a specific vesting recipient.
 /// Returns the computed amount if the operation is successful.
 ///
@@ -332,23 +512,103 @@ fn calc_schedule_unlocked_amount(
     schedule: &VestingSchedule,
     current_time: u64,
 ) -> StdResult<Uint128> {
-    let mut available_amount = schedule.start_point.amount;
-
-    if let Some(end_point) = &schedule.end_point {
-        let passed_time = current_time.min(end_point.time) - schedule.start_point.time;
-        let time_period = end_point.time - schedule.start_point.time;
-        if passed_time != 0 {
-            let release_amount = Uint128::from(passed_time).multiply_ratio(
-                end_point.amount.checked_sub(schedule.start_point.amount)?,
-                time_period,
-            );
-            available_amount = available_amount.checked_add(release_amount)?;
+    let start_point = &schedule.start_point;
+    if current_time < start_point.time {
+        return Ok(Uint128::zero());
+    }
+
+    let end_point = match &schedule.end_point {
+        Some(end_point) => end_point,
+        None => return Ok(start_point.amount),
+    };
+
+    match &schedule.shape {
+        None => calc_linear_unlocked_amount(start_point, end_point, current_time),
+        Some(VestingShape::Cliff { time }) => {
+            if current_time < *time {
+                Ok(Uint128::zero())
+            } else {
+                calc_linear_unlocked_amount(start_point, end_point, current_time)
+            }
+        }
+        Some(VestingShape::Periodic { period }) => {
+            // Round the current time down to the last passed unlock
+            let step_time = if current_time < end_point.time {
+                current_time - (current_time - start_point.time) % period
+            } else {
+                end_point.time
+            };
+            calc_linear_unlocked_amount(start_point, end_point, step_time)
+        }
+        Some(VestingShape::Piecewise { points }) => {
+            let mut segment_start = start_point;
+            for point in points.iter().chain(iter::once(end_point)) {
+                if current_time < point.time {
+                    return calc_linear_unlocked_amount(segment_start, point, current_time);
+                }
+                segment_start = point;
+            }
+
+            Ok(end_point.amount)
         }
     }
+}
+
+/// Calculate unlocked amount on a linear segment between `start_point` and `end_point`.
+fn calc_linear_unlocked_amount(
+    start_point: &VestingSchedulePoint,
+    end_point: &VestingSchedulePoint,
+    current_time: u64,
+) -> StdResult<Uint128> {
+    let mut available_amount = start_point.amount;
+
+    let passed_time = current_time.min(end_point.time) - start_point.time;
+    let time_period = end_point.time - start_point.time;
+    if passed_time != 0 {
+        let release_amount = Uint128::from(passed_time).multiply_ratio(
+            end_point.amount.checked_sub(start_point.amount)?,
+            time_period,
+        );
+        available_amount = available_amount.checked_add(release_amount)?;
+    }
 
     Ok(available_amount)
 }
 
+/// Returns the moments at which the unlock curve of a [`VestingSchedule`] changes its slope.
+/// Moments at which tokens are unlocked at once are flagged with `true`.
+fn schedule_breakpoints(schedule: &VestingSchedule) -> Vec<(u64, bool)> {
+    let start_point = &schedule.start_point;
+    let end_point = match &schedule.end_point {
+        Some(end_point) => end_point,
+        None => return vec![(start_point.time, true)],
+    };
+
+    let mut breakpoints = vec![];
+    match &schedule.shape {
+        None => breakpoints.push((start_point.time, !start_point.amount.is_zero())),
+        Some(VestingShape::Cliff { time }) => breakpoints.push((*time, true)),
+        Some(VestingShape::Periodic { period }) => {
+            breakpoints.push((start_point.time, !start_point.amount.is_zero()));
+            breakpoints.extend(
+                (start_point.time + period..end_point.time)
+                    .step_by(*period as usize)
+                    .map(|time| (time, true)),
+            );
+        }
+        Some(VestingShape::Piecewise { points }) => {
+            breakpoints.push((start_point.time, !start_point.amount.is_zero()));
+            breakpoints.extend(points.iter().map(|point| (point.time, false)));
+        }
+    }
+    breakpoints.push((
+        end_point.time,
+        matches!(schedule.shape, Some(VestingShape::Periodic { .. })),
+    ));
+
+    breakpoints
+}
+
 /// Withdraw tokens from active vesting schedule.
 ///
 /// Withdraw is possible if there is only one active vesting schedule.
@@ -400,6 +660,15 @@ fn withdraw_from_active_schedule(
             return Err(ContractError::MultipleActiveSchedules(account));
         }
 
+        // Reshaping stepped and custom curves would change amounts that have already been unlocked
+        match schedule.shape {
+            Some(VestingShape::Periodic { .. }) | Some(VestingShape::Piecewise { .. }) => {
+                return Err(ContractError::ShapeNotWithdrawable(account));
+            }
+            Some(VestingShape::Cliff { time }) if time <= block_time => schedule.shape = None,
+            _ => {}
+        }
+
         // It's safe to unwrap here because we checked that there is an end_point
         let mut end_point = schedule.end_point.unwrap();
 
@@ -437,6 +706,96 @@ fn withdraw_from_active_schedule(
     ]))
 }
 
+/// Revokes all revocable vesting schedules of an account.
+///
+/// Already vested tokens from all schedules are sent to the account.
+/// Unvested tokens from revocable schedules are sent to the recipient.
+/// Revoked schedules are replaced with fully vested ones holding the vested amount.
+///
+/// * **address** account whose schedules to revoke.
+///
+/// * **recipient** who will receive the unvested amount.
+/// **info.sender** is used if it is not specified.
+fn revoke_vesting_account(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+    recipient: Option<String>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let account = deps.api.addr_validate(&address)?;
+    let mut vesting_info = VESTING_INFO.load(deps.storage, &account)?;
+    let block_time = env.block.time.seconds();
+
+    if !vesting_info.schedules.iter().any(|sch| sch.revocable) {
+        return Err(ContractError::NotRevocable(address));
+    }
+
+    let mut unvested_amount = Uint128::zero();
+    let mut schedules = Vec::with_capacity(vesting_info.schedules.len());
+    for schedule in vesting_info.schedules {
+        if !schedule.revocable {
+            schedules.push(schedule);
+            continue;
+        }
+
+        let total_amount = schedule.end_point.unwrap_or(schedule.start_point).amount;
+        let sch_unlocked_amount = calc_schedule_unlocked_amount(&schedule, block_time)?;
+        unvested_amount =
+            unvested_amount.checked_add(total_amount.checked_sub(sch_unlocked_amount)?)?;
+
+        if !sch_unlocked_amount.is_zero() {
+            schedules.push(VestingSchedule {
+                start_point: VestingSchedulePoint {
+                    time: block_time,
+                    amount: sch_unlocked_amount,
+                },
+                end_point: None,
+                shape: None,
+                revocable: false,
+            });
+        }
+    }
+    vesting_info.schedules = schedules;
+
+    let vested_amount = compute_available_amount(block_time, &vesting_info)?;
+    vesting_info.released_amount = vesting_info.released_amount.checked_add(vested_amount)?;
+    VESTING_INFO.save(deps.storage, &account, &vesting_info)?;
+
+    let recipient = addr_opt_validate(deps.api, &recipient)?.unwrap_or(info.sender);
+
+    let mut messages = vec![];
+    if !vested_amount.is_zero() {
+        messages.push(
+            config
+                .vesting_token
+                .with_balance(vested_amount)
+                .into_msg(account.to_string())?,
+        );
+    }
+    if !unvested_amount.is_zero() {
+        messages.push(
+            config
+                .vesting_token
+                .with_balance(unvested_amount)
+                .into_msg(recipient.to_string())?,
+        );
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes([
+        attr("action", "revoke_vesting_account"),
+        attr("address", account),
+        attr("vested_amount", vested_amount),
+        attr("unvested_amount", unvested_amount),
+        attr("recipient", recipient),
+    ]))
+}
+
 /// Exposes all the queries available in the contract.
 ///
 /// ## Queries
@@ -451,6 +810,13 @@ fn withdraw_from_active_schedule(
 ///         }** Returns a list of vesting schedules together with their vesting recipients.
 ///
 /// * **QueryMsg::AvailableAmount { address }** Returns the available amount of tokens that can be claimed by a specific vesting recipient.
+///
+/// * **QueryMsg::UnlockTimeline { address }** Returns the future unlock timeline of a specific vesting recipient.
+///
+/// * **QueryMsg::ClaimOperator { address }** Returns the claim operator of a specific vesting recipient.
+///
+/// * **QueryMsg::BeneficiaryChangeProposal { address }** Returns the proposed new beneficiary
+/// of a specific vesting account.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -472,6 +838,21 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             &query_vesting_available_amount(deps, env, address)?,
         )?),
         QueryMsg::Timestamp {} => Ok(to_json_binary(&query_timestamp(env)?)?),
+        QueryMsg::UnlockTimeline { address } => {
+            Ok(to_json_binary(&query_unlock_timeline(deps, env, address)?)?)
+        }
+        QueryMsg::ClaimOperator { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            Ok(to_json_binary(
+                &CLAIM_OPERATORS.may_load(deps.storage, &address)?,
+            )?)
+        }
+        QueryMsg::BeneficiaryChangeProposal { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            Ok(to_json_binary(
+                &BENEFICIARY_PROPOSALS.may_load(deps.storage, &address)?,
+            )?)
+        }
     }
 }
 
@@ -537,12 +918,90 @@ pub fn query_vesting_available_amount(deps: Deps, env: Env, address: String) ->
     Ok(available_amount)
 }
 
+/// Returns the future unlock timeline for a specific vesting recipient as a list of [`VestingSchedulePoint`].
+/// Each point holds the total amount unlocked across all schedules at that time.
+/// An instant unlock is described by two points with the same time.
+///
+/// * **address** vesting recipient for which to return the unlock timeline.
+pub fn query_unlock_timeline(
+    deps: Deps,
+    env: Env,
+    address: String,
+) -> StdResult<Vec<VestingSchedulePoint>> {
+    let address = deps.api.addr_validate(&address)?;
+    let info = VESTING_INFO.load(deps.storage, &address)?;
+    let current_time = env.block.time.seconds();
+
+    let breakpoints: Vec<_> = info.schedules.iter().map(schedule_breakpoints).collect();
+    let mut times: Vec<_> = breakpoints
+        .iter()
+        .flatten()
+        .map(|(time, _)| *time)
+        .filter(|time| *time > current_time)
+        .collect();
+    times.sort_unstable();
+    times.dedup();
+
+    let mut timeline = vec![VestingSchedulePoint {
+        time: current_time,
+        amount: info
+            .schedules
+            .iter()
+            .try_fold(Uint128::zero(), |acc, sch| {
+                acc.checked_add(calc_schedule_unlocked_amount(sch, current_time)?)
+                    .map_err(StdError::from)
+            })?,
+    }];
+
+    for time in times {
+        let mut amount_before = Uint128::zero();
+        let mut amount_after = Uint128::zero();
+        for (sch, sch_breakpoints) in info.schedules.iter().zip(&breakpoints) {
+            let unlocked_amount = calc_schedule_unlocked_amount(sch, time)?;
+            amount_after = amount_after.checked_add(unlocked_amount)?;
+            amount_before = if sch_breakpoints.contains(&(time, true)) {
+                amount_before.checked_add(calc_schedule_unlocked_amount(sch, time - 1)?)?
+            } else {
+                amount_before.checked_add(unlocked_amount)?
+            };
+        }
+
+        if amount_before != amount_after {
+            timeline.push(VestingSchedulePoint {
+                time,
+                amount: amount_before,
+            });
+        }
+        timeline.push(VestingSchedulePoint {
+            time,
+            amount: amount_after,
+        });
+    }
+
+    Ok(timeline)
+}
+
 /// Manages contract migration.
+///
+/// Schedules stored by 1.1.0 load without a shape and keep vesting linearly, and the beneficiary
+/// proposal and claim operator maps start out empty, so no storage has to be rewritten.
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    let contract_version = get_contract_version(deps.storage)?;
+
+    match contract_version.contract.as_ref() {
+        "oroswap-vesting" => match contract_version.version.as_ref() {
+            "1.1.0" => {}
+            _ => return Err(ContractError::MigrationError {}),
+        },
+        _ => return Err(ContractError::MigrationError {}),
+    }
+
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
-    Ok(Response::default()
+    Ok(Response::new()
+        .add_attribute("previous_contract_name", &contract_version.contract)
+        .add_attribute("previous_contract_version", &contract_version.version)
         .add_attribute("new_contract_name", CONTRACT_NAME)
         .add_attribute("new_contract_version", CONTRACT_VERSION))
 }
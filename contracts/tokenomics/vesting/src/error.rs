@@ -23,6 +23,9 @@ pub enum ContractError {
     #[error("Vesting schedule error on addr: {0}. Should satisfy: (start < end, end > current_time and start_amount < end_amount)")]
     VestingScheduleError(String),
 
+    #[error("Vesting shape error on addr: {0}. Shape requires an end point and must fit between the start and end points")]
+    VestingShapeError(String),
+
     #[error(
         "Vesting schedule amount error. The total amount should be equal to the received amount."
     )]
@@ -31,6 +34,15 @@ pub enum ContractError {
     #[error("Contract can't be migrated!")]
     MigrationError {},
 
+    #[error("Active schedule of account {0} has a vesting shape that does not allow withdrawals")]
+    ShapeNotWithdrawable(String),
+
+    #[error("Account {0} already has vesting schedules")]
+    BeneficiaryAlreadyVesting(String),
+
+    #[error("Account {0} has no revocable vesting schedules")]
+    NotRevocable(String),
+
     #[error("Failed to withdraw tokens due to multiple active schedules for account {0}")]
     MultipleActiveSchedules(String),
 
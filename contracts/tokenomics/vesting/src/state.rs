@@ -21,6 +21,12 @@ pub const CONFIG: Item<Config> = Item::new("config");
 /// The first key is the address of an account that's vesting, the second key is an object of type [`VestingInfo`].
 pub const VESTING_INFO: Map<&Addr, VestingInfo> = Map::new("vesting_info");
 
+/// The first key is the current beneficiary, the second key is the proposed beneficiary.
+pub const BENEFICIARY_PROPOSALS: Map<&Addr, Addr> = Map::new("beneficiary_proposals");
+
+/// The first key is the beneficiary, the second key is the address allowed to claim on its behalf.
+pub const CLAIM_OPERATORS: Map<&Addr, Addr> = Map::new("claim_operators");
+
 /// Contains a proposal to change contract ownership.
 pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
 
@@ -134,4 +140,37 @@ mod testing {
         .unwrap();
         assert_eq!(res, vec![(Addr::unchecked("address2"), vi_mock.clone())]);
     }
+
+    #[test]
+    fn legacy_vesting_infos_are_loaded() {
+        use cosmwasm_std::{testing::mock_dependencies, Storage, Uint128};
+        use oroswap::vesting::{VestingSchedule, VestingSchedulePoint};
+
+        let mut deps = mock_dependencies();
+        let addr = Addr::unchecked("address1");
+
+        // Schedules stored before vesting shapes were introduced
+        let legacy_info = br#"{"schedules":[{"start_point":{"time":100,"amount":"0"},"end_point":{"time":200,"amount":"100"}}],"released_amount":"10"}"#;
+        deps.storage
+            .set(&VESTING_INFO.key(&addr), legacy_info.as_slice());
+
+        assert_eq!(
+            VESTING_INFO.load(&deps.storage, &addr).unwrap(),
+            VestingInfo {
+                schedules: vec![VestingSchedule {
+                    start_point: VestingSchedulePoint {
+                        time: 100,
+                        amount: Uint128::zero(),
+                    },
+                    end_point: Some(VestingSchedulePoint {
+                        time: 200,
+                        amount: Uint128::new(100),
+                    }),
+                    shape: None,
+                    revocable: false,
+                }],
+                released_amount: Uint128::new(10),
+            }
+        );
+    }
 }
@@ -1,9 +1,11 @@
-use crate::contract::{execute, instantiate, query};
-use oroswap::vesting::{ConfigResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::contract::{execute, instantiate, migrate, query};
+use crate::error::ContractError;
+use oroswap::vesting::{ConfigResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 use oroswap::asset::{token_asset_info, AssetInfo};
 use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
 use cosmwasm_std::{from_json, Addr};
+use cw2::{get_contract_version, set_contract_version};
 
 #[test]
 fn proper_initialization() {
@@ -132,3 +134,30 @@ fn update_owner() {
         from_json(&query(deps.as_ref(), env.clone(), QueryMsg::Config {}).unwrap()).unwrap();
     assert_eq!(new_owner, config.owner);
 }
+
+#[test]
+fn migrate_from_v110() {
+    let mut deps = mock_dependencies();
+
+    let msg = InstantiateMsg {
+        owner: "owner".to_string(),
+        vesting_token: token_asset_info(Addr::unchecked("oro_token")),
+    };
+    instantiate(deps.as_mut(), mock_env(), mock_info("owner", &[]), msg).unwrap();
+
+    // Migrating from the current version is rejected
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert_eq!(err, ContractError::MigrationError {});
+
+    // So is migrating from another contract
+    set_contract_version(deps.as_mut().storage, "some-contract", "1.1.0").unwrap();
+    let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+    assert_eq!(err, ContractError::MigrationError {});
+
+    set_contract_version(deps.as_mut().storage, "oroswap-vesting", "1.1.0").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+
+    let version = get_contract_version(deps.as_ref().storage).unwrap();
+    assert_eq!(version.contract, "oroswap-vesting");
+    assert_eq!(version.version, env!("CARGO_PKG_VERSION"));
+}
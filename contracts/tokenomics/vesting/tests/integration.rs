@@ -1,6 +1,6 @@
 #![cfg(not(tarpaulin_include))]
 
-use cosmwasm_std::{coin, coins, to_json_binary, Addr, StdResult, Timestamp, Uint128};
+use cosmwasm_std::{coin, coins, to_json_binary, Addr, Event, StdResult, Timestamp, Uint128};
 use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, MinterResponse};
 use cw20_base::msg::InstantiateMsg as TokenInstantiateMsg;
 use cw_multi_test::{App, ContractWrapper, Executor};
@@ -9,8 +9,8 @@ use cw_utils::PaymentError;
 use oroswap::asset::{native_asset_info, token_asset_info, AssetInfo};
 use oroswap::querier::query_balance;
 use oroswap::vesting::{
-    Cw20HookMsg, ExecuteMsg, InstantiateMsg, VestingAccount, VestingSchedule,
-    VestingSchedulePoint,
+    Cw20HookMsg, ExecuteMsg, InstantiateMsg, VestingAccount, VestingSchedule, VestingSchedulePoint,
+    VestingShape,
 };
 use oroswap::vesting::{QueryMsg, VestingAccountResponse, VestingAccountsResponse, VestingInfo};
 use oroswap_vesting::error::ContractError;
@@ -53,6 +53,8 @@ fn claim() {
                             time: current_time + 101,
                             amount: Uint128::new(200),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -63,6 +65,8 @@ fn claim() {
                             time: current_time + 110,
                             amount: Uint128::new(100),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -73,6 +77,8 @@ fn claim() {
                             time: current_time + 200,
                             amount: Uint128::new(100),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                 ],
             }],
@@ -104,6 +110,8 @@ fn claim() {
                             time: current_time + 101,
                             amount: Uint128::new(100),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -114,6 +122,8 @@ fn claim() {
                             time: current_time + 110,
                             amount: Uint128::new(100),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -124,6 +134,8 @@ fn claim() {
                             time: current_time + 200,
                             amount: Uint128::new(100),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                 ],
             }],
@@ -248,6 +260,8 @@ fn claim_native() {
                         time: current_time + 101,
                         amount: Uint128::new(200),
                     }),
+                    shape: None,
+                    revocable: false,
                 }],
             }],
         })
@@ -273,6 +287,8 @@ fn claim_native() {
                         time: current_time + 101,
                         amount: Uint128::new(100),
                     }),
+                    shape: None,
+                    revocable: false,
                 },
                 VestingSchedule {
                     start_point: VestingSchedulePoint {
@@ -283,6 +299,8 @@ fn claim_native() {
                         time: current_time + 110,
                         amount: Uint128::new(100),
                     }),
+                    shape: None,
+                    revocable: false,
                 },
                 VestingSchedule {
                     start_point: VestingSchedulePoint {
@@ -293,6 +311,8 @@ fn claim_native() {
                         time: current_time + 200,
                         amount: Uint128::new(100),
                     }),
+                    shape: None,
+                    revocable: false,
                 },
             ],
         }],
@@ -427,6 +447,8 @@ fn register_vesting_accounts() {
                         time: current_time + 100,
                         amount: Uint128::new(100),
                     }),
+                    shape: None,
+                    revocable: false,
                 }],
             }],
         })
@@ -453,6 +475,8 @@ fn register_vesting_accounts() {
                         time: current_time + 150,
                         amount: Uint128::new(100),
                     }),
+                    shape: None,
+                    revocable: false,
                 }],
             }],
         })
@@ -491,6 +515,8 @@ fn register_vesting_accounts() {
                     time: current_time + 150,
                     amount: Uint128::new(100),
                 }),
+                shape: None,
+                revocable: false,
             }],
         }],
     };
@@ -554,6 +580,8 @@ fn register_vesting_accounts() {
                         time: current_time + 150,
                         amount: Uint128::new(200),
                     }),
+                    shape: None,
+                    revocable: false,
                 }],
             }],
         })
@@ -613,6 +641,8 @@ fn register_vesting_accounts() {
                         time: current_time + 200,
                         amount: Uint128::new(10),
                     }),
+                    shape: None,
+                    revocable: false,
                 }],
             }],
         })
@@ -710,6 +740,8 @@ fn register_vesting_accounts() {
                                 time: 1571797719,
                                 amount: Uint128::new(200),
                             }),
+                            shape: None,
+                            revocable: false,
                         }],
                         released_amount: Uint128::zero(),
                     }
@@ -727,6 +759,8 @@ fn register_vesting_accounts() {
                                     time: 1571797919,
                                     amount: Uint128::new(10),
                                 }),
+                                shape: None,
+                                revocable: false,
                             },
                             VestingSchedule {
                                 start_point: VestingSchedulePoint {
@@ -737,6 +771,8 @@ fn register_vesting_accounts() {
                                     time: 1571797569,
                                     amount: Uint128::new(100),
                                 })
+                                shape: None,
+                                revocable: false,
                             }
                         ],
                         released_amount: Uint128::new(110),
@@ -785,6 +821,8 @@ fn register_vesting_accounts_native() {
                         time: current_time + 150,
                         amount: Uint128::new(100),
                     }),
+                    shape: None,
+                    revocable: false,
                 }],
             }],
         })
@@ -810,6 +848,8 @@ fn register_vesting_accounts_native() {
                     time: current_time + 150,
                     amount: Uint128::new(100),
                 }),
+                shape: None,
+                revocable: false,
             }],
         }],
     };
@@ -875,6 +915,8 @@ fn register_vesting_accounts_native() {
                     time: current_time + 150,
                     amount: Uint128::new(200),
                 }),
+                shape: None,
+                revocable: false,
             }],
         }],
     };
@@ -930,6 +972,8 @@ fn register_vesting_accounts_native() {
                     time: current_time + 200,
                     amount: Uint128::new(10),
                 }),
+                shape: None,
+                revocable: false,
             }],
         }],
     };
@@ -1029,6 +1073,8 @@ fn withdraw_from_active_schedule() {
                         time: end_time,
                         amount: Uint128::new(100_000_000_000000),
                     }),
+                    shape: None,
+                    revocable: false,
                 }],
             }],
         })
@@ -1126,6 +1172,8 @@ fn withdraw_overlapping_schedules() {
                             time: end_time,
                             amount: Uint128::new(50_000_000_000000),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -1133,6 +1181,8 @@ fn withdraw_overlapping_schedules() {
                             amount: Uint128::new(50_000_000_000000),
                         },
                         end_point: None,
+                        shape: None,
+                        revocable: false,
                     },
                 ],
             }],
@@ -1223,6 +1273,8 @@ fn withdraw_overlapping_schedules2() {
                             time: end_time,
                             amount: Uint128::new(50_000_000_000000),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                     VestingSchedule {
                         start_point: VestingSchedulePoint {
@@ -1233,6 +1285,8 @@ fn withdraw_overlapping_schedules2() {
                             time: end_time + 86400 * 7,
                             amount: Uint128::new(50_000_000_000000),
                         }),
+                        shape: None,
+                        revocable: false,
                     },
                 ],
             }],
@@ -1521,6 +1575,8 @@ fn test_schedule_limit_vulnerability_fix() {
                 time: current_time + end_offset,
                 amount: Uint128::new(amount),
             }),
+            shape: None,
+            revocable: false,
         }
     };
 
@@ -1642,6 +1698,8 @@ fn test_immediate_unlock_vulnerability_fix() {
             amount: Uint128::new(100), // Set amount to match the sent tokens
         },
         end_point: None, // No end_point = immediate unlock vulnerability
+        shape: None,
+        revocable: false,
     };
 
     let msg = Cw20ExecuteMsg::Send {
@@ -1672,6 +1730,8 @@ fn test_immediate_unlock_vulnerability_fix() {
             amount: Uint128::new(100), // Set amount to match the sent tokens
         },
         end_point: None, // No end_point but future start time is valid
+        shape: None,
+        revocable: false,
     };
 
     let msg = Cw20ExecuteMsg::Send {
@@ -1701,6 +1761,8 @@ fn test_immediate_unlock_vulnerability_fix() {
             time: current_time + 150,
             amount: Uint128::new(200),
         }),
+        shape: None,
+        revocable: false,
     };
 
     let msg = Cw20ExecuteMsg::Send {
@@ -1747,6 +1809,8 @@ fn test_withdrawal_exact_amount_fix() {
             time: current_time + 150,
             amount: Uint128::new(100),
         }),
+        shape: None,
+        revocable: false,
     };
 
     // Register the vesting account
@@ -1807,3 +1871,709 @@ fn test_withdrawal_exact_amount_fix() {
     println!("✅ No more single token units left stuck in schedules");
     println!("✅ Excessive withdrawals are still properly rejected");
 }
+
+#[test]
+fn vesting_shapes() {
+    let user1 = Addr::unchecked(USER1);
+    let owner = Addr::unchecked(OWNER1);
+
+    let mut app = mock_app(&owner);
+    let vesting_instance = instantiate_vesting_remote_chain(&mut app);
+    let current_time = app.block_info().time.seconds();
+
+    let point = |offset: u64, amount: u128| VestingSchedulePoint {
+        time: current_time + offset,
+        amount: Uint128::new(amount),
+    };
+    let shaped_schedule = |shape: VestingShape| VestingSchedule {
+        start_point: point(100, 0),
+        end_point: Some(point(1100, 1000)),
+        shape: Some(shape),
+        revocable: false,
+    };
+
+    let msg = ExecuteMsg::RegisterVestingAccounts {
+        vesting_accounts: vec![VestingAccount {
+            address: user1.to_string(),
+            schedules: vec![
+                shaped_schedule(VestingShape::Cliff {
+                    time: current_time + 400,
+                }),
+                shaped_schedule(VestingShape::Periodic { period: 300 }),
+                shaped_schedule(VestingShape::Piecewise {
+                    points: vec![point(200, 500), point(600, 500)],
+                }),
+            ],
+        }],
+    };
+    app.execute_contract(
+        owner.clone(),
+        vesting_instance.clone(),
+        &msg,
+        &coins(3000, IBC_ORO),
+    )
+    .unwrap();
+
+    let timeline: Vec<VestingSchedulePoint> = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::UnlockTimeline {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        timeline,
+        vec![
+            point(0, 0),
+            point(100, 0),
+            point(200, 500),
+            point(400, 500),
+            point(400, 1100),
+            point(600, 1300),
+            point(700, 1500),
+            point(700, 1800),
+            point(1000, 2400),
+            point(1000, 2700),
+            point(1100, 2900),
+            point(1100, 3000),
+        ]
+    );
+
+    for (offset, expected) in [(300, 500), (400, 1100), (500, 1200), (1050, 2800)] {
+        app.update_block(|b| b.time = Timestamp::from_seconds(current_time + offset));
+
+        let available: Uint128 = app
+            .wrap()
+            .query_wasm_smart(
+                &vesting_instance,
+                &QueryMsg::AvailableAmount {
+                    address: user1.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(available.u128(), expected, "at offset {offset}");
+    }
+
+    // Claimed tokens do not affect the timeline
+    app.execute_contract(
+        user1.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::Claim {
+            recipient: None,
+            amount: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let bal = query_balance(&app.wrap(), &user1, IBC_ORO).unwrap();
+    assert_eq!(bal.u128(), 2800);
+
+    let timeline: Vec<VestingSchedulePoint> = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::UnlockTimeline {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        timeline,
+        vec![point(1050, 2800), point(1100, 2900), point(1100, 3000)]
+    );
+}
+
+#[test]
+fn vesting_shapes_validation() {
+    let user1 = Addr::unchecked(USER1);
+    let owner = Addr::unchecked(OWNER1);
+
+    let mut app = mock_app(&owner);
+    let vesting_instance = instantiate_vesting_remote_chain(&mut app);
+    let current_time = app.block_info().time.seconds();
+
+    let point = |offset: u64, amount: u128| VestingSchedulePoint {
+        time: current_time + offset,
+        amount: Uint128::new(amount),
+    };
+
+    let invalid_schedules = [
+        // Shapes require an end point
+        VestingSchedule {
+            start_point: point(100, 1000),
+            end_point: None,
+            shape: Some(VestingShape::Cliff {
+                time: current_time + 200,
+            }),
+            revocable: false,
+        },
+        // Cliff must be strictly between the start and end points
+        VestingSchedule {
+            start_point: point(100, 0),
+            end_point: Some(point(1100, 1000)),
+            shape: Some(VestingShape::Cliff {
+                time: current_time + 1100,
+            }),
+            revocable: false,
+        },
+        // Period must be positive
+        VestingSchedule {
+            start_point: point(100, 0),
+            end_point: Some(point(1100, 1000)),
+            shape: Some(VestingShape::Periodic { period: 0 }),
+            revocable: false,
+        },
+        // Too many periodic unlocks
+        VestingSchedule {
+            start_point: point(100, 0),
+            end_point: Some(point(1100, 1000)),
+            shape: Some(VestingShape::Periodic { period: 1 }),
+            revocable: false,
+        },
+        // Cumulative amounts can't decrease
+        VestingSchedule {
+            start_point: point(100, 0),
+            end_point: Some(point(1100, 1000)),
+            shape: Some(VestingShape::Piecewise {
+                points: vec![point(200, 600), point(300, 500)],
+            }),
+            revocable: false,
+        },
+        // Curve points must be before the end point
+        VestingSchedule {
+            start_point: point(100, 0),
+            end_point: Some(point(1100, 1000)),
+            shape: Some(VestingShape::Piecewise {
+                points: vec![point(1100, 600)],
+            }),
+            revocable: false,
+        },
+    ];
+
+    for schedule in invalid_schedules {
+        let amount = schedule.end_point.unwrap_or(schedule.start_point).amount;
+        let err = app
+            .execute_contract(
+                owner.clone(),
+                vesting_instance.clone(),
+                &ExecuteMsg::RegisterVestingAccounts {
+                    vesting_accounts: vec![VestingAccount {
+                        address: user1.to_string(),
+                        schedules: vec![schedule],
+                    }],
+                },
+                &coins(amount.u128(), IBC_ORO),
+            )
+            .unwrap_err();
+        assert_eq!(
+            ContractError::VestingShapeError(user1.to_string()),
+            err.downcast().unwrap()
+        );
+    }
+}
+
+#[test]
+fn withdraw_from_shaped_schedule() {
+    let user1 = Addr::unchecked(USER1);
+    let user2 = Addr::unchecked(USER2);
+    let owner = Addr::unchecked(OWNER1);
+
+    let mut app = mock_app(&owner);
+    let vesting_instance = instantiate_vesting_remote_chain(&mut app);
+    let current_time = app.block_info().time.seconds();
+
+    let point = |offset: u64, amount: u128| VestingSchedulePoint {
+        time: current_time + offset,
+        amount: Uint128::new(amount),
+    };
+
+    let msg = ExecuteMsg::RegisterVestingAccounts {
+        vesting_accounts: vec![
+            VestingAccount {
+                address: user1.to_string(),
+                schedules: vec![VestingSchedule {
+                    start_point: point(0, 0),
+                    end_point: Some(point(1000, 1000)),
+                    shape: Some(VestingShape::Cliff {
+                        time: current_time + 500,
+                    }),
+                    revocable: false,
+                }],
+            },
+            VestingAccount {
+                address: user2.to_string(),
+                schedules: vec![VestingSchedule {
+                    start_point: point(0, 0),
+                    end_point: Some(point(1000, 1000)),
+                    shape: Some(VestingShape::Periodic { period: 100 }),
+                    revocable: false,
+                }],
+            },
+        ],
+    };
+    app.execute_contract(
+        owner.clone(),
+        vesting_instance.clone(),
+        &msg,
+        &coins(2000, IBC_ORO),
+    )
+    .unwrap();
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(current_time + 100));
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            vesting_instance.clone(),
+            &ExecuteMsg::WithdrawFromActiveSchedule {
+                account: user2.to_string(),
+                recipient: None,
+                withdraw_amount: Uint128::new(100),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::ShapeNotWithdrawable(user2.to_string()),
+        err.downcast().unwrap()
+    );
+
+    // Withdrawing before the cliff keeps it in place
+    app.execute_contract(
+        owner.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::WithdrawFromActiveSchedule {
+            account: user1.to_string(),
+            recipient: None,
+            withdraw_amount: Uint128::new(100),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let vesting_account: VestingAccountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::VestingAccount {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        vesting_account.info.schedules,
+        vec![VestingSchedule {
+            start_point: point(100, 0),
+            end_point: Some(point(1000, 900)),
+            shape: Some(VestingShape::Cliff {
+                time: current_time + 500,
+            }),
+            revocable: false,
+        }]
+    );
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(current_time + 400));
+    let available: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::AvailableAmount {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(available, Uint128::zero());
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(current_time + 500));
+    let available: Uint128 = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::AvailableAmount {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(available.u128(), 400);
+}
+
+#[test]
+fn revoke_vesting_account() {
+    let user1 = Addr::unchecked(USER1);
+    let user2 = Addr::unchecked(USER2);
+    let owner = Addr::unchecked(OWNER1);
+    let recipient = Addr::unchecked("recipient");
+
+    let mut app = mock_app(&owner);
+    let vesting_instance = instantiate_vesting_remote_chain(&mut app);
+    let current_time = app.block_info().time.seconds();
+
+    let schedule = |revocable: bool| VestingSchedule {
+        start_point: VestingSchedulePoint {
+            time: current_time,
+            amount: Uint128::zero(),
+        },
+        end_point: Some(VestingSchedulePoint {
+            time: current_time + 1000,
+            amount: Uint128::new(1000),
+        }),
+        shape: None,
+        revocable,
+    };
+
+    let msg = ExecuteMsg::RegisterVestingAccounts {
+        vesting_accounts: vec![
+            VestingAccount {
+                address: user1.to_string(),
+                schedules: vec![schedule(true), schedule(false)],
+            },
+            VestingAccount {
+                address: user2.to_string(),
+                schedules: vec![schedule(false)],
+            },
+        ],
+    };
+    app.execute_contract(
+        owner.clone(),
+        vesting_instance.clone(),
+        &msg,
+        &coins(3000, IBC_ORO),
+    )
+    .unwrap();
+
+    let revoke_msg = |address: &Addr| ExecuteMsg::RevokeVestingAccount {
+        address: address.to_string(),
+        recipient: Some(recipient.to_string()),
+    };
+
+    let err = app
+        .execute_contract(
+            user1.clone(),
+            vesting_instance.clone(),
+            &revoke_msg(&user1),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            vesting_instance.clone(),
+            &revoke_msg(&user2),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::NotRevocable(user2.to_string()),
+        err.downcast().unwrap()
+    );
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(current_time + 200));
+    app.execute_contract(
+        user1.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::Claim {
+            recipient: None,
+            amount: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(current_time + 400));
+    let res = app
+        .execute_contract(
+            owner.clone(),
+            vesting_instance.clone(),
+            &revoke_msg(&user1),
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm")
+            .add_attribute("action", "revoke_vesting_account")
+            .add_attribute("vested_amount", "400")
+            .add_attribute("unvested_amount", "600")
+    ));
+
+    // Vested tokens from both schedules were paid out, unvested ones went to the recipient
+    let bal = query_balance(&app.wrap(), &user1, IBC_ORO).unwrap();
+    assert_eq!(bal.u128(), 800);
+    let bal = query_balance(&app.wrap(), &recipient, IBC_ORO).unwrap();
+    assert_eq!(bal.u128(), 600);
+
+    let vesting_account: VestingAccountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::VestingAccount {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        vesting_account.info,
+        VestingInfo {
+            schedules: vec![
+                schedule(false),
+                VestingSchedule {
+                    start_point: VestingSchedulePoint {
+                        time: current_time + 400,
+                        amount: Uint128::new(400),
+                    },
+                    end_point: None,
+                    shape: None,
+                    revocable: false,
+                },
+            ],
+            released_amount: Uint128::new(800),
+        }
+    );
+
+    let err = app
+        .execute_contract(
+            owner.clone(),
+            vesting_instance.clone(),
+            &revoke_msg(&user1),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::NotRevocable(user1.to_string()),
+        err.downcast().unwrap()
+    );
+
+    // The irrevocable schedule keeps vesting
+    app.update_block(|b| b.time = Timestamp::from_seconds(current_time + 1000));
+    app.execute_contract(
+        user1.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::Claim {
+            recipient: None,
+            amount: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let bal = query_balance(&app.wrap(), &user1, IBC_ORO).unwrap();
+    assert_eq!(bal.u128(), 1400);
+}
+
+#[test]
+fn beneficiary_change_and_claim_operator() {
+    let user1 = Addr::unchecked(USER1);
+    let user2 = Addr::unchecked(USER2);
+    let owner = Addr::unchecked(OWNER1);
+    let operator = Addr::unchecked("operator");
+    let new_beneficiary = Addr::unchecked("new_beneficiary");
+
+    let mut app = mock_app(&owner);
+    let vesting_instance = instantiate_vesting_remote_chain(&mut app);
+    let current_time = app.block_info().time.seconds();
+
+    let schedule = VestingSchedule {
+        start_point: VestingSchedulePoint {
+            time: current_time,
+            amount: Uint128::zero(),
+        },
+        end_point: Some(VestingSchedulePoint {
+            time: current_time + 1000,
+            amount: Uint128::new(1000),
+        }),
+        shape: None,
+        revocable: false,
+    };
+    let msg = ExecuteMsg::RegisterVestingAccounts {
+        vesting_accounts: vec![
+            VestingAccount {
+                address: user1.to_string(),
+                schedules: vec![schedule.clone()],
+            },
+            VestingAccount {
+                address: user2.to_string(),
+                schedules: vec![schedule.clone()],
+            },
+        ],
+    };
+    app.execute_contract(
+        owner.clone(),
+        vesting_instance.clone(),
+        &msg,
+        &coins(2000, IBC_ORO),
+    )
+    .unwrap();
+
+    let set_operator_msg = ExecuteMsg::SetClaimOperator {
+        operator: Some(operator.to_string()),
+    };
+    let err = app
+        .execute_contract(
+            operator.clone(),
+            vesting_instance.clone(),
+            &set_operator_msg,
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    app.execute_contract(
+        user1.clone(),
+        vesting_instance.clone(),
+        &set_operator_msg,
+        &[],
+    )
+    .unwrap();
+    let res: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::ClaimOperator {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Some(operator.clone()));
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(current_time + 300));
+
+    let claim_for_msg = |beneficiary: &Addr| ExecuteMsg::ClaimFor {
+        beneficiary: beneficiary.to_string(),
+        amount: None,
+    };
+    let err = app
+        .execute_contract(
+            operator.clone(),
+            vesting_instance.clone(),
+            &claim_for_msg(&user2),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    // Operator claims are always sent to the beneficiary
+    app.execute_contract(
+        operator.clone(),
+        vesting_instance.clone(),
+        &claim_for_msg(&user1),
+        &[],
+    )
+    .unwrap();
+    let bal = query_balance(&app.wrap(), &user1, IBC_ORO).unwrap();
+    assert_eq!(bal.u128(), 300);
+    let bal = query_balance(&app.wrap(), &operator, IBC_ORO).unwrap();
+    assert_eq!(bal.u128(), 0);
+
+    let err = app
+        .execute_contract(
+            user1.clone(),
+            vesting_instance.clone(),
+            &ExecuteMsg::ProposeBeneficiaryChange {
+                new_beneficiary: user2.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::BeneficiaryAlreadyVesting(user2.to_string()),
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        user1.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::ProposeBeneficiaryChange {
+            new_beneficiary: new_beneficiary.to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let res: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::BeneficiaryChangeProposal {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, Some(new_beneficiary.clone()));
+
+    let accept_msg = ExecuteMsg::AcceptBeneficiaryChange {
+        beneficiary: user1.to_string(),
+    };
+    let err = app
+        .execute_contract(user2.clone(), vesting_instance.clone(), &accept_msg, &[])
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    app.execute_contract(
+        new_beneficiary.clone(),
+        vesting_instance.clone(),
+        &accept_msg,
+        &[],
+    )
+    .unwrap();
+
+    // The whole vesting account was moved and the old operator was removed
+    let vesting_account: VestingAccountResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::VestingAccount {
+                address: new_beneficiary.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        vesting_account.info,
+        VestingInfo {
+            schedules: vec![schedule],
+            released_amount: Uint128::new(300),
+        }
+    );
+    app.wrap()
+        .query_wasm_smart::<VestingAccountResponse>(
+            &vesting_instance,
+            &QueryMsg::VestingAccount {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap_err();
+    let res: Option<Addr> = app
+        .wrap()
+        .query_wasm_smart(
+            &vesting_instance,
+            &QueryMsg::BeneficiaryChangeProposal {
+                address: user1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res, None);
+
+    let err = app
+        .execute_contract(
+            operator.clone(),
+            vesting_instance.clone(),
+            &claim_for_msg(&new_beneficiary),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    app.update_block(|b| b.time = Timestamp::from_seconds(current_time + 1000));
+    app.execute_contract(
+        new_beneficiary.clone(),
+        vesting_instance.clone(),
+        &ExecuteMsg::Claim {
+            recipient: None,
+            amount: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let bal = query_balance(&app.wrap(), &new_beneficiary, IBC_ORO).unwrap();
+    assert_eq!(bal.u128(), 700);
+}
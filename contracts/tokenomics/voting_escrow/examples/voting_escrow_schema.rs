@@ -0,0 +1,11 @@
+use cosmwasm_schema::write_api;
+
+use oroswap::voting_escrow::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        query: QueryMsg,
+        execute: ExecuteMsg,
+    }
+}
@@ -0,0 +1,441 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    attr, coins, ensure, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut,
+    Env, MessageInfo, Response, StdError, StdResult, Storage, Uint128,
+};
+use cw2::set_contract_version;
+use cw_utils::must_pay;
+
+use oroswap::common::{claim_ownership, drop_ownership_proposal, propose_new_owner};
+use oroswap::voting_escrow::{
+    Config, ExecuteMsg, InstantiateMsg, Lock, LockInfoResponse, QueryMsg, MAX_LOCK_WEEKS_LIMIT,
+    WEEK,
+};
+use oroswap::DecimalCheckedOps;
+
+use crate::error::ContractError;
+use crate::state::{CONFIG, LOCKED_BY_END, LOCKS, OWNERSHIP_PROPOSAL};
+
+/// Contract name that is used for migration.
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+/// Contract version that is used for migration.
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Creates a new contract with the specified parameters in the [`InstantiateMsg`].
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    ensure!(
+        (1..=MAX_LOCK_WEEKS_LIMIT).contains(&msg.max_lock_weeks),
+        ContractError::InvalidMaxLockWeeks {}
+    );
+    ensure!(
+        msg.max_exit_penalty <= Decimal::one(),
+        ContractError::InvalidMaxExitPenalty {}
+    );
+
+    CONFIG.save(
+        deps.storage,
+        &Config {
+            owner: deps.api.addr_validate(&msg.owner)?,
+            deposit_denom: msg.deposit_denom.clone(),
+            maker: deps.api.addr_validate(&msg.maker)?,
+            max_lock_weeks: msg.max_lock_weeks,
+            max_exit_penalty: msg.max_exit_penalty,
+        },
+    )?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "instantiate"),
+        attr("contract", CONTRACT_NAME),
+        attr("deposit_denom", msg.deposit_denom),
+    ]))
+}
+
+/// Exposes execute functions available in the contract.
+///
+/// * **ExecuteMsg::CreateLock { weeks }** Locks the attached xORO for the given number of weeks.
+///
+/// * **ExecuteMsg::IncreaseAmount {}** Adds the attached xORO to an existing lock.
+///
+/// * **ExecuteMsg::ExtendLockTime { weeks }** Extends an existing lock.
+///
+/// * **ExecuteMsg::Withdraw {}** Withdraws xORO from an expired lock.
+///
+/// * **ExecuteMsg::WithdrawEarly {}** Withdraws xORO from an active lock paying a penalty to the Maker.
+///
+/// * **ExecuteMsg::UpdateConfig { maker, max_exit_penalty }** Updates contract parameters.
+///
+/// * **ExecuteMsg::ProposeNewOwner { owner, expires_in }** Creates a new request to change contract ownership.
+///
+/// * **ExecuteMsg::DropOwnershipProposal {}** Removes a request to change contract ownership.
+///
+/// * **ExecuteMsg::ClaimOwnership {}** Claims contract ownership.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::CreateLock { weeks } => create_lock(deps, env, info, weeks),
+        ExecuteMsg::IncreaseAmount {} => increase_amount(deps, env, info),
+        ExecuteMsg::ExtendLockTime { weeks } => extend_lock_time(deps, env, info, weeks),
+        ExecuteMsg::Withdraw {} => withdraw(deps, env, info, false),
+        ExecuteMsg::WithdrawEarly {} => withdraw(deps, env, info, true),
+        ExecuteMsg::UpdateConfig {
+            maker,
+            max_exit_penalty,
+        } => update_config(deps, info, maker, max_exit_penalty),
+        ExecuteMsg::ProposeNewOwner { owner, expires_in } => {
+            let config = CONFIG.load(deps.storage)?;
+
+            propose_new_owner(
+                deps,
+                info,
+                env,
+                owner,
+                expires_in,
+                config.owner,
+                OWNERSHIP_PROPOSAL,
+            )
+            .map_err(Into::into)
+        }
+        ExecuteMsg::DropOwnershipProposal {} => {
+            let config = CONFIG.load(deps.storage)?;
+
+            drop_ownership_proposal(deps, info, config.owner, OWNERSHIP_PROPOSAL)
+                .map_err(Into::into)
+        }
+        ExecuteMsg::ClaimOwnership {} => {
+            claim_ownership(deps, info, env, OWNERSHIP_PROPOSAL, |deps, new_owner| {
+                CONFIG.update::<_, StdError>(deps.storage, |mut v| {
+                    v.owner = new_owner;
+                    Ok(v)
+                })?;
+
+                Ok(())
+            })
+            .map_err(Into::into)
+        }
+    }
+}
+
+/// Locks the attached xORO until the end of the week **weeks** weeks from now.
+fn create_lock(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    weeks: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let amount = must_pay(&info, &config.deposit_denom)?;
+
+    if LOCKS.may_load(deps.storage, &info.sender)?.is_some() {
+        return Err(ContractError::LockAlreadyExists {});
+    }
+
+    let now = env.block.time.seconds();
+    let end = lock_end(&config, now, weeks)?;
+
+    LOCKS.save(
+        deps.storage,
+        &info.sender,
+        &Lock {
+            amount,
+            start: now,
+            end,
+        },
+        now,
+    )?;
+    add_locked(deps.storage, end, amount, now)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "create_lock"),
+        attr("user", info.sender),
+        attr("amount", amount),
+        attr("end", end.to_string()),
+    ]))
+}
+
+/// Adds the attached xORO to the sender's active lock.
+fn increase_amount(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let amount = must_pay(&info, &config.deposit_denom)?;
+
+    let now = env.block.time.seconds();
+    let mut lock = load_active_lock(deps.storage, &info.sender, now)?;
+
+    lock.amount = lock.amount.checked_add(amount)?;
+    LOCKS.save(deps.storage, &info.sender, &lock, now)?;
+    add_locked(deps.storage, lock.end, amount, now)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "increase_amount"),
+        attr("user", info.sender),
+        attr("amount", amount),
+        attr("total_amount", lock.amount),
+    ]))
+}
+
+/// Moves the end of the sender's active lock to the end of the week **weeks** weeks from now.
+fn extend_lock_time(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    weeks: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let now = env.block.time.seconds();
+    let mut lock = load_active_lock(deps.storage, &info.sender, now)?;
+
+    let end = lock_end(&config, now, weeks)?;
+    if end <= lock.end {
+        return Err(ContractError::LockTimeNotExtended {});
+    }
+
+    sub_locked(deps.storage, lock.end, lock.amount, now)?;
+    add_locked(deps.storage, end, lock.amount, now)?;
+    lock.end = end;
+    LOCKS.save(deps.storage, &info.sender, &lock, now)?;
+
+    Ok(Response::new().add_attributes(vec![
+        attr("action", "extend_lock_time"),
+        attr("user", info.sender),
+        attr("end", end.to_string()),
+    ]))
+}
+
+/// Withdraws all xORO from the sender's lock. Locks which haven't expired yet can only be withdrawn
+/// with **early** set, in which case the exit penalty is sent to the Maker.
+fn withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    early: bool,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let lock = LOCKS
+        .may_load(deps.storage, &info.sender)?
+        .ok_or(ContractError::LockNotFound {})?;
+
+    let now = env.block.time.seconds();
+    if !early && now < lock.end {
+        return Err(ContractError::LockHasNotExpired {});
+    }
+
+    let penalty = exit_penalty(&config, &lock, now)?;
+    let return_amount = lock.amount.checked_sub(penalty)?;
+
+    LOCKS.remove(deps.storage, &info.sender, now)?;
+    sub_locked(deps.storage, lock.end, lock.amount, now)?;
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    if !return_amount.is_zero() {
+        messages.push(
+            BankMsg::Send {
+                to_address: info.sender.to_string(),
+                amount: coins(return_amount.u128(), &config.deposit_denom),
+            }
+            .into(),
+        );
+    }
+    if !penalty.is_zero() {
+        messages.push(
+            BankMsg::Send {
+                to_address: config.maker.to_string(),
+                amount: coins(penalty.u128(), &config.deposit_denom),
+            }
+            .into(),
+        );
+    }
+
+    Ok(Response::new().add_messages(messages).add_attributes(vec![
+        attr("action", if early { "withdraw_early" } else { "withdraw" }),
+        attr("user", info.sender),
+        attr("amount", return_amount),
+        attr("penalty", penalty),
+    ]))
+}
+
+/// Updates contract parameters.
+///
+/// * **maker** new Maker contract address.
+///
+/// * **max_exit_penalty** new maximum early unlock penalty.
+fn update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    maker: Option<String>,
+    max_exit_penalty: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut attrs = vec![attr("action", "update_config")];
+
+    if let Some(maker) = maker {
+        config.maker = deps.api.addr_validate(&maker)?;
+        attrs.push(attr("maker", maker));
+    }
+
+    if let Some(max_exit_penalty) = max_exit_penalty {
+        ensure!(
+            max_exit_penalty <= Decimal::one(),
+            ContractError::InvalidMaxExitPenalty {}
+        );
+        config.max_exit_penalty = max_exit_penalty;
+        attrs.push(attr("max_exit_penalty", max_exit_penalty.to_string()));
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+/// Returns the end of the week **weeks** weeks after **now**.
+fn lock_end(config: &Config, now: u64, weeks: u64) -> Result<u64, ContractError> {
+    if weeks == 0 || weeks > config.max_lock_weeks {
+        return Err(ContractError::InvalidLockWeeks {
+            max: config.max_lock_weeks,
+        });
+    }
+
+    Ok((now / WEEK + weeks) * WEEK)
+}
+
+/// Loads the lock of **user** and checks that it hasn't expired.
+fn load_active_lock(storage: &dyn Storage, user: &Addr, now: u64) -> Result<Lock, ContractError> {
+    let lock = LOCKS
+        .may_load(storage, user)?
+        .ok_or(ContractError::LockNotFound {})?;
+
+    if now >= lock.end {
+        return Err(ContractError::LockExpired {});
+    }
+
+    Ok(lock)
+}
+
+fn add_locked(storage: &mut dyn Storage, end: u64, amount: Uint128, now: u64) -> StdResult<()> {
+    LOCKED_BY_END.update(storage, end, now, |locked| -> StdResult<_> {
+        Ok(locked.unwrap_or_default().checked_add(amount)?)
+    })?;
+
+    Ok(())
+}
+
+fn sub_locked(storage: &mut dyn Storage, end: u64, amount: Uint128, now: u64) -> StdResult<()> {
+    LOCKED_BY_END.update(storage, end, now, |locked| -> StdResult<_> {
+        Ok(locked.unwrap_or_default().checked_sub(amount)?)
+    })?;
+
+    Ok(())
+}
+
+/// Returns the penalty for withdrawing **lock** at **now**. The penalty decays linearly
+/// with the remaining lock time and reaches **max_exit_penalty** for a lock of the maximum time.
+fn exit_penalty(config: &Config, lock: &Lock, now: u64) -> StdResult<Uint128> {
+    if now >= lock.end {
+        return Ok(Uint128::zero());
+    }
+
+    Ok(config
+        .max_exit_penalty
+        .checked_mul_uint128(lock.amount)?
+        .multiply_ratio(lock.end - now, config.max_lock_time()))
+}
+
+/// Exposes all the queries available in the contract.
+///
+/// * **QueryMsg::Config {}** Returns the voting escrow contract configuration
+///
+/// * **QueryMsg::LockInfo { address }** Returns the lock of the given address
+///
+/// * **QueryMsg::BalanceAt { address, timestamp }** Returns the voting power of the given address at the given timestamp
+///
+/// * **QueryMsg::TotalSupplyAt { timestamp }** Returns the total voting power at the given timestamp
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::LockInfo { address } => to_json_binary(&query_lock_info(deps, env, address)?),
+        QueryMsg::BalanceAt { address, timestamp } => {
+            to_json_binary(&balance_at(deps, env, address, timestamp)?)
+        }
+        QueryMsg::TotalSupplyAt { timestamp } => {
+            to_json_binary(&total_supply_at(deps, env, timestamp)?)
+        }
+    }
+}
+
+fn query_lock_info(deps: Deps, env: Env, address: String) -> StdResult<LockInfoResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = deps.api.addr_validate(&address)?;
+    let lock = LOCKS.load(deps.storage, &address)?;
+
+    let now = env.block.time.seconds();
+
+    Ok(LockInfoResponse {
+        amount: lock.amount,
+        start: lock.start,
+        end: lock.end,
+        voting_power: lock.voting_power(config.max_lock_time(), now),
+        exit_penalty: exit_penalty(&config, &lock, now)?,
+    })
+}
+
+fn balance_at(deps: Deps, env: Env, address: String, timestamp: Option<u64>) -> StdResult<Uint128> {
+    let config = CONFIG.load(deps.storage)?;
+    let address = deps.api.addr_validate(&address)?;
+    let now = env.block.time.seconds();
+    let timestamp = timestamp.unwrap_or(now);
+
+    let lock = if timestamp == now {
+        LOCKS.may_load(deps.storage, &address)?
+    } else {
+        LOCKS.may_load_at_height(deps.storage, &address, timestamp)?
+    };
+
+    Ok(lock
+        .map(|lock| lock.voting_power(config.max_lock_time(), timestamp))
+        .unwrap_or_default())
+}
+
+/// Sums the voting power of all locks expiring after **timestamp**.
+/// Lock ends are rounded to weeks and can't be further than the maximum lock time away,
+/// so only a bounded number of weeks needs to be checked.
+fn total_supply_at(deps: Deps, env: Env, timestamp: Option<u64>) -> StdResult<Uint128> {
+    let config = CONFIG.load(deps.storage)?;
+    let now = env.block.time.seconds();
+    let timestamp = timestamp.unwrap_or(now);
+    let max_lock_time = config.max_lock_time();
+
+    let mut total = Uint128::zero();
+    for week in timestamp / WEEK + 1..=(timestamp + max_lock_time) / WEEK {
+        let end = week * WEEK;
+        let locked = if timestamp == now {
+            LOCKED_BY_END.may_load(deps.storage, end)?
+        } else {
+            LOCKED_BY_END.may_load_at_height(deps.storage, end, timestamp)?
+        };
+
+        if let Some(locked) = locked {
+            total = total.checked_add(locked.multiply_ratio(end - timestamp, max_lock_time))?;
+        }
+    }
+
+    Ok(total)
+}
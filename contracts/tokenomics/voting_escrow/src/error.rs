@@ -0,0 +1,48 @@
+use cosmwasm_std::{OverflowError, StdError};
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+use oroswap::voting_escrow::MAX_LOCK_WEEKS_LIMIT;
+
+/// This enum describes voting escrow contract errors
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    PaymentError(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Maximum lock time must be between 1 and {MAX_LOCK_WEEKS_LIMIT} weeks")]
+    InvalidMaxLockWeeks {},
+
+    #[error("Lock time must be between 1 and {max} weeks")]
+    InvalidLockWeeks { max: u64 },
+
+    #[error("Maximum exit penalty must not exceed 1")]
+    InvalidMaxExitPenalty {},
+
+    #[error("Lock already exists")]
+    LockAlreadyExists {},
+
+    #[error("Lock not found")]
+    LockNotFound {},
+
+    #[error("Lock has expired")]
+    LockExpired {},
+
+    #[error("Lock has not expired yet")]
+    LockHasNotExpired {},
+
+    #[error("Lock end must be later than the current one")]
+    LockTimeNotExtended {},
+}
+
+impl From<OverflowError> for ContractError {
+    fn from(o: OverflowError) -> Self {
+        StdError::from(o).into()
+    }
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, SnapshotMap, Strategy};
+
+use oroswap::common::OwnershipProposal;
+use oroswap::voting_escrow::{Config, Lock};
+
+/// Stores the contract config at the given key
+pub const CONFIG: Item<Config> = Item::new("config");
+
+/// Contains a proposal to change contract ownership.
+pub const OWNERSHIP_PROPOSAL: Item<OwnershipProposal> = Item::new("ownership_proposal");
+
+/// Contains the history of locks per address. Snapshots are taken per timestamp.
+pub const LOCKS: SnapshotMap<&Addr, Lock> =
+    SnapshotMap::new("locks", "locks_chpts", "locks_chlg", Strategy::EveryBlock);
+
+/// Contains the history of the total amount of xORO in locks expiring at a given timestamp.
+/// Snapshots are taken per timestamp.
+pub const LOCKED_BY_END: SnapshotMap<u64, Uint128> = SnapshotMap::new(
+    "locked_by_end",
+    "locked_by_end_chpts",
+    "locked_by_end_chlg",
+    Strategy::EveryBlock,
+);
@@ -0,0 +1,346 @@
+#![cfg(not(tarpaulin_include))]
+
+use cosmwasm_std::{coin, coins, Addr, Decimal, Timestamp, Uint128};
+use cw_multi_test::{App, AppResponse, ContractWrapper, Executor};
+use cw_utils::PaymentError;
+
+use oroswap::voting_escrow::{
+    Config, ExecuteMsg, InstantiateMsg, LockInfoResponse, QueryMsg, WEEK,
+};
+use oroswap_voting_escrow::error::ContractError;
+
+const OWNER: &str = "owner";
+const MAKER: &str = "maker";
+const USER1: &str = "user1";
+const USER2: &str = "user2";
+const XORO: &str = "xoro";
+const MAX_LOCK_WEEKS: u64 = 104;
+/// Lock ends are rounded to weeks so tests start at a week boundary
+const START_TIME: u64 = 3000 * WEEK;
+
+#[test]
+fn voting_power_decays_linearly() {
+    let (mut app, escrow) = setup();
+
+    create_lock(&mut app, &escrow, USER1, 1000, MAX_LOCK_WEEKS).unwrap();
+    create_lock(&mut app, &escrow, USER2, 2000, 52).unwrap();
+
+    assert_eq!(balance_at(&app, &escrow, USER1, None), 1000);
+    assert_eq!(balance_at(&app, &escrow, USER2, None), 1000);
+    assert_eq!(total_supply_at(&app, &escrow, None), 2000);
+
+    advance_time(&mut app, 26 * WEEK);
+
+    assert_eq!(balance_at(&app, &escrow, USER1, None), 750);
+    assert_eq!(balance_at(&app, &escrow, USER2, None), 500);
+    assert_eq!(total_supply_at(&app, &escrow, None), 1250);
+
+    // Historical queries
+    let before_locks = Some(START_TIME - 1);
+    assert_eq!(balance_at(&app, &escrow, USER1, before_locks), 0);
+    assert_eq!(total_supply_at(&app, &escrow, before_locks), 0);
+
+    let week_after_locks = Some(START_TIME + WEEK);
+    assert_eq!(balance_at(&app, &escrow, USER1, week_after_locks), 990);
+    assert_eq!(balance_at(&app, &escrow, USER2, week_after_locks), 980);
+    assert_eq!(total_supply_at(&app, &escrow, week_after_locks), 1970);
+
+    // The second lock expires
+    advance_time(&mut app, 26 * WEEK);
+
+    assert_eq!(balance_at(&app, &escrow, USER1, None), 500);
+    assert_eq!(balance_at(&app, &escrow, USER2, None), 0);
+    assert_eq!(total_supply_at(&app, &escrow, None), 500);
+    assert_eq!(
+        total_supply_at(&app, &escrow, Some(START_TIME + 26 * WEEK)),
+        1250
+    );
+}
+
+#[test]
+fn increase_and_extend_lock() {
+    let (mut app, escrow) = setup();
+
+    create_lock(&mut app, &escrow, USER1, 1000, 10).unwrap();
+    assert_eq!(balance_at(&app, &escrow, USER1, None), 96);
+
+    let err = create_lock(&mut app, &escrow, USER1, 1000, 10).unwrap_err();
+    assert_eq!(ContractError::LockAlreadyExists {}, err.downcast().unwrap());
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked(USER1),
+            escrow.clone(),
+            &ExecuteMsg::IncreaseAmount {},
+            &coins(1000, "random-coin"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::PaymentError(PaymentError::MissingDenom(XORO.to_string())),
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(USER1),
+        escrow.clone(),
+        &ExecuteMsg::IncreaseAmount {},
+        &coins(1000, XORO),
+    )
+    .unwrap();
+    assert_eq!(balance_at(&app, &escrow, USER1, None), 192);
+    assert_eq!(total_supply_at(&app, &escrow, None), 192);
+
+    let err = extend_lock_time(&mut app, &escrow, USER1, 5).unwrap_err();
+    assert_eq!(
+        ContractError::LockTimeNotExtended {},
+        err.downcast().unwrap()
+    );
+
+    let err = extend_lock_time(&mut app, &escrow, USER1, MAX_LOCK_WEEKS + 1).unwrap_err();
+    assert_eq!(
+        ContractError::InvalidLockWeeks {
+            max: MAX_LOCK_WEEKS
+        },
+        err.downcast().unwrap()
+    );
+
+    extend_lock_time(&mut app, &escrow, USER1, MAX_LOCK_WEEKS).unwrap();
+    assert_eq!(balance_at(&app, &escrow, USER1, None), 2000);
+    assert_eq!(total_supply_at(&app, &escrow, None), 2000);
+
+    let err = withdraw(&mut app, &escrow, USER1, ExecuteMsg::Withdraw {}).unwrap_err();
+    assert_eq!(ContractError::LockHasNotExpired {}, err.downcast().unwrap());
+
+    advance_time(&mut app, MAX_LOCK_WEEKS * WEEK);
+
+    assert_eq!(total_supply_at(&app, &escrow, None), 0);
+    let err = extend_lock_time(&mut app, &escrow, USER1, 1).unwrap_err();
+    assert_eq!(ContractError::LockExpired {}, err.downcast().unwrap());
+
+    withdraw(&mut app, &escrow, USER1, ExecuteMsg::Withdraw {}).unwrap();
+    assert_eq!(
+        app.wrap().query_balance(USER1, XORO).unwrap(),
+        coin(10_000, XORO)
+    );
+
+    let err = withdraw(&mut app, &escrow, USER1, ExecuteMsg::Withdraw {}).unwrap_err();
+    assert_eq!(ContractError::LockNotFound {}, err.downcast().unwrap());
+}
+
+#[test]
+fn early_withdrawal_penalty_goes_to_maker() {
+    let (mut app, escrow) = setup();
+
+    create_lock(&mut app, &escrow, USER1, 1000, MAX_LOCK_WEEKS).unwrap();
+    advance_time(&mut app, 52 * WEEK);
+
+    let lock_info: LockInfoResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &escrow,
+            &QueryMsg::LockInfo {
+                address: USER1.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        lock_info,
+        LockInfoResponse {
+            amount: Uint128::new(1000),
+            start: START_TIME,
+            end: START_TIME + MAX_LOCK_WEEKS * WEEK,
+            voting_power: Uint128::new(500),
+            exit_penalty: Uint128::new(250),
+        }
+    );
+
+    withdraw(&mut app, &escrow, USER1, ExecuteMsg::WithdrawEarly {}).unwrap();
+
+    assert_eq!(
+        app.wrap().query_balance(USER1, XORO).unwrap(),
+        coin(9_750, XORO)
+    );
+    assert_eq!(
+        app.wrap().query_balance(MAKER, XORO).unwrap(),
+        coin(250, XORO)
+    );
+    assert_eq!(balance_at(&app, &escrow, USER1, None), 0);
+    assert_eq!(total_supply_at(&app, &escrow, None), 0);
+
+    // Voting power before the withdrawal is still available
+    assert_eq!(
+        balance_at(&app, &escrow, USER1, Some(START_TIME + 26 * WEEK)),
+        750
+    );
+
+    // A new lock can be created after the withdrawal
+    create_lock(&mut app, &escrow, USER1, 1000, 1).unwrap();
+}
+
+#[test]
+fn update_config() {
+    let mut app = mock_app();
+    let code_id = store_escrow_code(&mut app);
+
+    let err = instantiate_escrow(&mut app, code_id, 0, Decimal::percent(50)).unwrap_err();
+    assert_eq!(
+        ContractError::InvalidMaxLockWeeks {},
+        err.downcast().unwrap()
+    );
+
+    let err = instantiate_escrow(&mut app, code_id, 104, Decimal::percent(101)).unwrap_err();
+    assert_eq!(
+        ContractError::InvalidMaxExitPenalty {},
+        err.downcast().unwrap()
+    );
+
+    let escrow = instantiate_escrow(&mut app, code_id, 104, Decimal::percent(50)).unwrap();
+
+    let msg = ExecuteMsg::UpdateConfig {
+        maker: Some("new_maker".to_string()),
+        max_exit_penalty: Some(Decimal::percent(10)),
+    };
+
+    let err = app
+        .execute_contract(Addr::unchecked(USER1), escrow.clone(), &msg, &[])
+        .unwrap_err();
+    assert_eq!(ContractError::Unauthorized {}, err.downcast().unwrap());
+
+    app.execute_contract(Addr::unchecked(OWNER), escrow.clone(), &msg, &[])
+        .unwrap();
+
+    let config: Config = app
+        .wrap()
+        .query_wasm_smart(&escrow, &QueryMsg::Config {})
+        .unwrap();
+    assert_eq!(
+        config,
+        Config {
+            owner: Addr::unchecked(OWNER),
+            deposit_denom: XORO.to_string(),
+            maker: Addr::unchecked("new_maker"),
+            max_lock_weeks: 104,
+            max_exit_penalty: Decimal::percent(10),
+        }
+    );
+}
+
+fn mock_app() -> App {
+    let mut app = App::new(|app, _, storage| {
+        for user in [USER1, USER2] {
+            app.bank
+                .init_balance(
+                    storage,
+                    &Addr::unchecked(user),
+                    vec![coin(10_000, XORO), coin(10_000, "random-coin")],
+                )
+                .unwrap();
+        }
+    });
+    app.update_block(|block| block.time = Timestamp::from_seconds(START_TIME));
+
+    app
+}
+
+fn store_escrow_code(app: &mut App) -> u64 {
+    let escrow_contract = Box::new(ContractWrapper::new_with_empty(
+        oroswap_voting_escrow::contract::execute,
+        oroswap_voting_escrow::contract::instantiate,
+        oroswap_voting_escrow::contract::query,
+    ));
+
+    app.store_code(escrow_contract)
+}
+
+fn instantiate_escrow(
+    app: &mut App,
+    code_id: u64,
+    max_lock_weeks: u64,
+    max_exit_penalty: Decimal,
+) -> anyhow::Result<Addr> {
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(OWNER),
+        &InstantiateMsg {
+            owner: OWNER.to_string(),
+            deposit_denom: XORO.to_string(),
+            maker: MAKER.to_string(),
+            max_lock_weeks,
+            max_exit_penalty,
+        },
+        &[],
+        "vxORO",
+        None,
+    )
+}
+
+fn setup() -> (App, Addr) {
+    let mut app = mock_app();
+    let code_id = store_escrow_code(&mut app);
+    let escrow =
+        instantiate_escrow(&mut app, code_id, MAX_LOCK_WEEKS, Decimal::percent(50)).unwrap();
+
+    (app, escrow)
+}
+
+fn advance_time(app: &mut App, seconds: u64) {
+    app.update_block(|block| block.time = block.time.plus_seconds(seconds));
+}
+
+fn create_lock(
+    app: &mut App,
+    escrow: &Addr,
+    user: &str,
+    amount: u128,
+    weeks: u64,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(user),
+        escrow.clone(),
+        &ExecuteMsg::CreateLock { weeks },
+        &coins(amount, XORO),
+    )
+}
+
+fn extend_lock_time(
+    app: &mut App,
+    escrow: &Addr,
+    user: &str,
+    weeks: u64,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(user),
+        escrow.clone(),
+        &ExecuteMsg::ExtendLockTime { weeks },
+        &[],
+    )
+}
+
+fn withdraw(
+    app: &mut App,
+    escrow: &Addr,
+    user: &str,
+    msg: ExecuteMsg,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(Addr::unchecked(user), escrow.clone(), &msg, &[])
+}
+
+fn balance_at(app: &App, escrow: &Addr, user: &str, timestamp: Option<u64>) -> u128 {
+    app.wrap()
+        .query_wasm_smart::<Uint128>(
+            escrow,
+            &QueryMsg::BalanceAt {
+                address: user.to_string(),
+                timestamp,
+            },
+        )
+        .unwrap()
+        .u128()
+}
+
+fn total_supply_at(app: &App, escrow: &Addr, timestamp: Option<u64>) -> u128 {
+    app.wrap()
+        .query_wasm_smart::<Uint128>(escrow, &QueryMsg::TotalSupplyAt { timestamp })
+        .unwrap()
+        .u128()
+}
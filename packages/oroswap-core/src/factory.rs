@@ -1,7 +1,7 @@
 use crate::asset::{AssetInfo, PairInfo};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary,Uint128};
+use cosmwasm_std::{Addr, Binary, Coin, Uint128};
 use std::fmt::{Display, Formatter, Result};
 
 const MAX_TOTAL_FEE_BPS: u16 = 10_000;
@@ -60,6 +60,10 @@ fn default_pool_creation_fee() -> Uint128 {
     Uint128::new(1000)
 }
 
+fn default_pool_creation_fee_denom() -> String {
+    "uzig".to_string()
+}
+
 /// This structure stores a pair type's configuration.
 #[cw_serde]
 pub struct PairConfig {
@@ -85,10 +89,14 @@ pub struct PairConfig {
     /// Default is false.
     #[serde(default)]
     pub permissioned: bool,
-
-    //TODO: add pool creation fee
+    /// The fee charged for creating a pair of this type. It is sent to the fee address.
+    /// Zero disables the fee.
     #[serde(default = "default_pool_creation_fee")]
     pub pool_creation_fee: Uint128,
+    /// The denom in which the pool creation fee must be paid.
+    /// Default is "uzig".
+    #[serde(default = "default_pool_creation_fee_denom")]
+    pub pool_creation_fee_denom: String,
 }
 
 impl PairConfig {
@@ -99,6 +107,28 @@ impl PairConfig {
     
 }
 
+/// This structure overrides the maker fee recipient and split for a single pair.
+/// The pair pays the maker fee in its own tokens to `fee_address` instead of the Maker.
+#[cw_serde]
+pub struct PairMakerFee {
+    /// Address that receives the pair's maker fee
+    pub fee_address: Addr,
+    /// Amount of fees (in bps) sent to `fee_address`
+    pub maker_fee_bps: u16,
+}
+
+/// Checks that a fee tier (the total fee in bps charged by a pair) is within bounds.
+pub fn valid_fee_tier(total_fee_bps: u16) -> bool {
+    total_fee_bps <= MAX_TOTAL_FEE_BPS
+}
+
+impl PairMakerFee {
+    /// This method is used to check fee bps.
+    pub fn valid_fee_bps(&self) -> bool {
+        self.maker_fee_bps <= MAX_MAKER_FEE_BPS
+    }
+}
+
 /// This structure stores the basic settings for creating a new factory contract.
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -155,6 +185,8 @@ pub enum ExecuteMsg {
         asset_infos: Vec<AssetInfo>,
         /// Optional binary serialised parameters for custom pool types
         init_params: Option<Binary>,
+        /// Optional total fee (in bps) for this pair, must be one of the allowed fee tiers
+        fee_tier: Option<u16>,
     },
     /// Deregister removes a previously created pair.
     Deregister {
@@ -209,6 +241,43 @@ pub enum ExecuteMsg {
         /// Addresses to remove from pause authorities
         authorities: Vec<String>,
     },
+    /// Override the maker fee recipient and split for a specific pair
+    SetPairMakerFee {
+        /// The assets of the pair
+        asset_infos: Vec<AssetInfo>,
+        /// The pair type
+        pair_type: PairType,
+        /// Address that receives the pair's maker fee
+        fee_address: String,
+        /// Amount of fees (in bps) sent to `fee_address`
+        maker_fee_bps: u16,
+    },
+    /// Remove a pair's maker fee override so it falls back to its pair type config
+    RemovePairMakerFee {
+        /// The assets of the pair
+        asset_infos: Vec<AssetInfo>,
+        /// The pair type
+        pair_type: PairType,
+    },
+    /// Add fee tiers (total fees in bps) that pairs can be created with
+    AddFeeTiers {
+        /// Fee tiers to allow
+        fee_tiers: Vec<u16>,
+    },
+    /// Remove allowed fee tiers. Pairs already using a removed tier keep it
+    RemoveFeeTiers {
+        /// Fee tiers to disallow
+        fee_tiers: Vec<u16>,
+    },
+    /// Set or remove the fee tier of a specific pair
+    UpdatePairFeeTier {
+        /// The assets of the pair
+        asset_infos: Vec<AssetInfo>,
+        /// The pair type
+        pair_type: PairType,
+        /// New fee tier, or `None` to fall back to the pair type's total fee
+        fee_tier: Option<u16>,
+    },
 }
 
 /// This structure describes the available query messages for the factory contract.
@@ -240,6 +309,13 @@ pub enum QueryMsg {
         /// The pair type for which we return fee information. Pair type is a [`PairType`] struct
         pair_type: PairType,
     },
+    /// PoolCreationFee returns the coins that must be attached to a CreatePair message for a specific pair type.
+    /// The vector is empty if pairs of this type can be created for free.
+    #[returns(Vec<Coin>)]
+    PoolCreationFee {
+        /// The pair type for which we return the pool creation fee
+        pair_type: PairType,
+    },
     /// Returns a vector that contains blacklisted pair types
     #[returns(Vec<PairType>)]
     BlacklistedPairTypes {},
@@ -263,6 +339,29 @@ pub enum QueryMsg {
     /// Returns the total number of paused pairs
     #[returns(u32)]
     PausedPairsCount {},
+    /// Returns fee parameters for a specific pair with its fee tier and maker fee override applied
+    #[returns(FeeInfoResponse)]
+    PairFeeInfo {
+        /// The assets of the pair
+        asset_infos: Vec<AssetInfo>,
+        /// The pair type
+        pair_type: PairType,
+    },
+    /// Returns fee parameters for a specific pair by its address
+    #[returns(FeeInfoResponse)]
+    FeeInfoByPair {
+        /// The address of the pair
+        pair_addr: String,
+    },
+    /// Returns the allowed fee tiers
+    #[returns(Vec<u16>)]
+    FeeTiers {},
+    /// Returns all pairs with a maker fee override
+    #[returns(Vec<PairMakerFeeResponse>)]
+    PairMakerFees {
+        start_after: Option<StartAfter>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -309,6 +408,17 @@ pub struct FeeInfoResponse {
     pub pool_creation_fee: Uint128,
 }
 
+/// A custom struct for each query response that returns a pair's maker fee override.
+#[cw_serde]
+pub struct PairMakerFeeResponse {
+    /// The pair the override applies to
+    pub pair_info: PairInfo,
+    /// Address that receives the pair's maker fee
+    pub fee_address: Addr,
+    /// Amount of fees (in bps) sent to `fee_address`
+    pub maker_fee_bps: u16,
+}
+
 /// This is an enum used for setting and removing a contract address.
 #[cw_serde]
 pub enum UpdateAddr {
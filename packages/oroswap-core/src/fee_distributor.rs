@@ -0,0 +1,77 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Uint128};
+
+/// The default number of epochs processed by one claim
+pub const DEFAULT_CLAIM_LIMIT: u32 = 10;
+/// The maximum number of epochs processed by one claim
+pub const MAX_CLAIM_LIMIT: u32 = 52;
+
+/// This structure describes the parameters used for creating a contract.
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The vxORO voting escrow contract address
+    pub voting_escrow: String,
+    /// The ORO denom distributed by the contract
+    pub token_denom: String,
+}
+
+/// This structure describes the execute messages available in the contract.
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Assigns ORO received since the last checkpoint to the current epoch
+    CheckpointToken {},
+    /// Claims the sender's share of ORO from finished epochs
+    Claim {
+        /// The address that receives the ORO. Defaults to the sender.
+        recipient: Option<String>,
+        /// The maximum number of epochs to claim from
+        limit: Option<u32>,
+    },
+}
+
+/// This structure describes the query messages available in the contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Config returns the contract configuration specified in a custom [`Config`] structure
+    #[returns(Config)]
+    Config {},
+    /// Claimable returns the amount of ORO that a claim with the same **limit** would send to **address**
+    #[returns(ClaimableResponse)]
+    Claimable { address: String, limit: Option<u32> },
+    /// Epochs returns the amount of ORO distributed in each epoch
+    #[returns(Vec<EpochInfo>)]
+    Epochs {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+/// This structure stores the main parameters for the fee distributor contract.
+#[cw_serde]
+pub struct Config {
+    /// The vxORO voting escrow contract address
+    pub voting_escrow: Addr,
+    /// The ORO denom
+    pub token_denom: String,
+}
+
+/// This structure describes the ORO distributed in one epoch.
+#[cw_serde]
+pub struct EpochInfo {
+    /// The epoch start timestamp
+    pub start: u64,
+    /// The amount of ORO distributed in the epoch
+    pub amount: Uint128,
+}
+
+/// This structure describes the response of the claimable query.
+#[cw_serde]
+pub struct ClaimableResponse {
+    /// The amount of ORO that can be claimed
+    pub amount: Uint128,
+    /// The start of the last epoch included in **amount**
+    pub last_epoch: Option<u64>,
+    /// Whether there are more finished epochs to claim from after **last_epoch**
+    pub has_more: bool,
+}
@@ -0,0 +1,121 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Uint128};
+
+/// The sum of vote weights of one user in basis points
+pub const VOTE_WEIGHTS_SUM: u16 = 10000;
+/// The upper limit for the number of pools receiving ORO emissions
+pub const MAX_POOLS_LIMIT: u32 = 100;
+
+/// This structure describes the parameters used for creating a contract.
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Address allowed to change contract parameters
+    pub owner: String,
+    /// The vxORO voting escrow contract address
+    pub voting_escrow: String,
+    /// The factory contract address
+    pub factory: String,
+    /// The incentives contract address
+    pub incentives: String,
+    /// The maximum number of pools receiving ORO emissions
+    pub pools_limit: u32,
+    /// The maximum share of alloc points a single pool can receive
+    pub pool_cap: Decimal,
+}
+
+/// This structure describes the execute messages available in the contract.
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Splits the sender's voting power between LP tokens for the current epoch.
+    /// Weights are set in basis points and must not exceed 10000 in total.
+    /// Voting again in the same epoch replaces the previous votes.
+    Vote { votes: Vec<(String, u16)> },
+    /// Pushes alloc points based on the votes of the previous epoch to the incentives contract.
+    /// Can be executed by anyone once per epoch.
+    TunePools {},
+    /// Updates contract parameters
+    /// ## Executor
+    /// Only the current owner can execute this
+    UpdateConfig {
+        /// The new maximum number of pools receiving ORO emissions
+        pools_limit: Option<u32>,
+        /// The new maximum share of alloc points per pool
+        pool_cap: Option<Decimal>,
+    },
+    /// Creates a request to change contract ownership
+    /// ## Executor
+    /// Only the current owner can execute this
+    ProposeNewOwner {
+        /// The newly proposed owner
+        owner: String,
+        /// The validity period of the offer to change the owner
+        expires_in: u64,
+    },
+    /// Removes a request to change contract ownership
+    /// ## Executor
+    /// Only the current owner can execute this
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    /// ## Executor
+    /// Only the newly proposed owner can execute this
+    ClaimOwnership {},
+}
+
+/// This structure describes the query messages available in the contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Config returns the contract configuration specified in a custom [`Config`] structure
+    #[returns(Config)]
+    Config {},
+    /// UserVote returns the last votes of the given address
+    #[returns(Option<UserVote>)]
+    UserVote { address: String },
+    /// PoolVotes returns the voting power given to an LP token in the given epoch.
+    /// Returns votes of the current epoch if epoch unset.
+    #[returns(Uint128)]
+    PoolVotes {
+        lp_token: String,
+        epoch: Option<u64>,
+    },
+    /// TuneInfo returns the result of the last pool tuning
+    #[returns(Option<TuneInfo>)]
+    TuneInfo {},
+}
+
+/// This structure stores the main parameters for the generator controller contract.
+#[cw_serde]
+pub struct Config {
+    /// Address that's allowed to change contract parameters
+    pub owner: Addr,
+    /// The vxORO voting escrow contract address
+    pub voting_escrow: Addr,
+    /// The factory contract address
+    pub factory: Addr,
+    /// The incentives contract address
+    pub incentives: Addr,
+    /// The maximum number of pools receiving ORO emissions
+    pub pools_limit: u32,
+    /// The maximum share of alloc points a single pool can receive
+    pub pool_cap: Decimal,
+}
+
+/// This structure describes the votes of a user.
+#[cw_serde]
+pub struct UserVote {
+    /// The start of the epoch the votes were cast in
+    pub epoch: u64,
+    /// The voting power of the user at the time of voting
+    pub voting_power: Uint128,
+    /// LP tokens with their weights in basis points
+    pub votes: Vec<(String, u16)>,
+}
+
+/// This structure describes the result of a pool tuning.
+#[cw_serde]
+pub struct TuneInfo {
+    /// The start of the epoch the tuning happened in
+    pub epoch: u64,
+    /// LP tokens with the alloc points sent to the incentives contract
+    pub pools: Vec<(String, Uint128)>,
+}
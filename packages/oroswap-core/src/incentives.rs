@@ -30,6 +30,11 @@ pub const MAX_PAGE_LIMIT: u8 = 50;
 /// Max number of orphaned rewards to claim at a time
 pub const MAX_ORPHANED_REWARD_LIMIT: u8 = 10;
 
+/// Returns the start of the epoch which contains **timestamp**.
+pub fn epoch_start(timestamp: u64) -> u64 {
+    EPOCHS_START + timestamp.saturating_sub(EPOCHS_START) / EPOCH_LENGTH * EPOCH_LENGTH
+}
+
 #[cw_serde]
 pub struct InstantiateMsg {
     pub owner: String,
@@ -7,13 +7,16 @@ pub mod asset;
 pub mod common;
 pub mod cosmwasm_ext;
 pub mod factory;
+pub mod fee_distributor;
 pub mod fee_granter;
+pub mod generator_controller;
 #[cfg(feature = "injective")]
 pub mod injective_ext;
 pub mod maker;
 pub mod native_coin_registry;
 pub mod observation;
 pub mod oracle;
+pub mod oro_converter;
 pub mod pair;
 pub mod pair_concentrated;
 pub mod pair_concentrated_inj;
@@ -26,6 +29,7 @@ pub mod token;
 pub mod token_factory;
 pub mod tokenfactory_tracker;
 pub mod vesting;
+pub mod voting_escrow;
 pub mod xoro_token;
 
 #[cfg(test)]
@@ -17,6 +17,17 @@ pub struct DevFundConfig {
     pub asset_info: AssetInfo,
 }
 
+/// Protects fee swaps against sandwiching by comparing the swap return with the return at the
+/// pair's SMA price (see the pair `Observe` query).
+#[cw_serde]
+pub struct PriceGuard {
+    /// The max allowed shortfall of a swap return against the return at the SMA price
+    pub max_deviation: Decimal,
+    /// Whether a smaller part of the fee token is swapped when swapping the whole amount
+    /// deviates too much. Otherwise the fee token is skipped until the next collect
+    pub allow_partial: bool,
+}
+
 /// This structure stores the main parameters for the Maker contract.
 #[cw_serde]
 pub struct Config {
@@ -56,6 +67,13 @@ pub struct Config {
     pub authorized_keepers: Vec<Addr>,
     /// List of critical tokens that only the owner can manage bridges for
     pub critical_tokens: Vec<AssetInfo>,
+    /// The router contract used to find swap routes to ORO.
+    /// Bridges are used only for fee tokens without a route
+    #[serde(default)]
+    pub router_contract: Option<Addr>,
+    /// Swaps deviating from the pairs' SMA prices more than allowed are skipped or reduced
+    #[serde(default)]
+    pub price_guard: Option<PriceGuard>,
 }
 
 /// This structure stores general parameters for the contract.
@@ -83,6 +101,10 @@ pub struct InstantiateMsg {
     pub collect_cooldown: Option<u64>,
     /// List of critical tokens that only the owner can manage bridges for
     pub critical_tokens: Option<Vec<AssetInfo>>,
+    /// The router contract used to find swap routes to ORO
+    pub router_contract: Option<String>,
+    /// Swaps deviating from the pairs' SMA prices more than allowed are skipped or reduced
+    pub price_guard: Option<PriceGuard>,
 }
 
 #[cw_serde]
@@ -92,6 +114,13 @@ pub struct UpdateDevFundConfig {
     pub set: Option<DevFundConfig>,
 }
 
+#[cw_serde]
+pub struct UpdatePriceGuard {
+    /// If 'set' is None then the price guard will be removed,
+    /// otherwise it will be updated with the new parameters
+    pub set: Option<PriceGuard>,
+}
+
 /// This structure describes the functions that can be executed in this contract.
 #[cw_serde]
 pub enum ExecuteMsg {
@@ -124,6 +153,10 @@ pub enum ExecuteMsg {
         dev_fund_config: Option<Box<UpdateDevFundConfig>>,
         /// List of critical tokens that only the owner can manage bridges for
         critical_tokens: Option<Vec<AssetInfo>>,
+        /// The router contract used to find swap routes to ORO
+        router_contract: Option<UpdateAddr>,
+        /// Price guard configuration for fee swaps
+        price_guard: Option<UpdatePriceGuard>,
     },
     /// Add bridge tokens used to swap specific fee tokens to ORO (effectively declaring a swap route)
     UpdateBridges {
@@ -183,6 +216,25 @@ pub enum QueryMsg {
     /// Returns the seize config
     #[returns(SeizeConfig)]
     QuerySeizeConfig {},
+    /// Returns fee swaps made by the Maker in ascending order. Swaps can be filtered by time range
+    /// (inclusive) and by an asset that was swapped from or to
+    #[returns(Vec<SwapRecord>)]
+    SwapHistory {
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        asset: Option<AssetInfo>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns ORO distributions made by the Maker in ascending order.
+    /// Distributions can be filtered by time range (inclusive)
+    #[returns(Vec<DistributionRecord>)]
+    DistributionHistory {
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 /// A custom struct that holds contract parameters and is used to retrieve them.
@@ -216,6 +268,44 @@ pub struct ConfigResponse {
     pub authorized_keepers: Vec<Addr>,
     /// List of critical tokens that only the owner can manage bridges for
     pub critical_tokens: Vec<AssetInfo>,
+    /// The router contract used to find swap routes to ORO
+    pub router_contract: Option<Addr>,
+    /// Price guard configuration for fee swaps
+    pub price_guard: Option<PriceGuard>,
+}
+
+/// This structure describes a fee swap recorded in the Maker ledger.
+#[cw_serde]
+pub struct SwapRecord {
+    /// The record id
+    pub id: u64,
+    /// The block timestamp of the swap
+    pub timestamp: u64,
+    /// The fee token and amount swapped
+    pub asset_in: Asset,
+    /// The received asset and amount. This is ORO or a bridge asset that is swapped later
+    pub asset_out: Asset,
+    /// The pair or router contract used for the swap
+    pub pool: Addr,
+}
+
+/// This structure describes an ORO distribution recorded in the Maker ledger.
+#[cw_serde]
+pub struct DistributionRecord {
+    /// The record id
+    pub id: u64,
+    /// The block timestamp of the distribution
+    pub timestamp: u64,
+    /// The total amount of ORO distributed
+    pub oro_amount: Uint128,
+    /// The amount of ORO sent to the xORO staking contract
+    pub staking_amount: Uint128,
+    /// The amount of ORO sent to the governance contract
+    pub governance_amount: Uint128,
+    /// The amount of ORO sent to the second fee receiver
+    pub second_receiver_amount: Uint128,
+    /// The amount of ORO swapped for the dev fund
+    pub dev_fund_amount: Uint128,
 }
 
 /// A custom struct used to return multiple asset balances.
@@ -12,6 +12,8 @@ pub struct InstantiateMsg {
     pub asset_infos: Vec<AssetInfo>,
     /// The type of pair to use for the oracle
     pub pair_type: PairType,
+    /// Minimum time in seconds between two consecutive TWAP updates. Defaults to one day
+    pub period: Option<u64>,
 }
 
 /// This structure describes the execute functions available in the contract.
@@ -19,13 +21,19 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     /// Update/accumulate prices
     Update {},
+    /// Updates the contract configuration. Only the owner can execute this
+    UpdateConfig {
+        /// Minimum time in seconds between two consecutive TWAP updates
+        period: Option<u64>,
+    },
 }
 
 /// This structure describes the query messages available in the contract.
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    /// Calculates a new TWAP with updated precision
+    /// Calculates a new TWAP with updated precision. Fails if no TWAP was computed yet
+    /// or if the latest TWAP is older than two periods
     #[returns(Vec<(AssetInfo, Uint256)>)]
     Consult {
         /// The asset for which to compute a new TWAP value
@@ -149,6 +149,13 @@ pub enum QueryMsg {
     /// Returns an estimation of assets received for the given amount of LP tokens
     #[returns(Vec<Asset>)]
     SimulateWithdraw { lp_amount: Uint128 },
+    /// Simulates an imbalanced withdraw of exactly the given assets using at most `lp_amount`
+    /// LP tokens and returns the amount of LP tokens that would be burned
+    #[returns(SimulateImbalancedWithdrawResponse)]
+    SimulateImbalancedWithdraw {
+        lp_amount: Uint128,
+        assets: Vec<Asset>,
+    },
     /// Returns an estimation of shares received for the given amount of assets
     #[returns(Uint128)]
     SimulateProvide {
@@ -166,6 +173,15 @@ pub struct PoolResponse {
     pub total_share: Uint128,
 }
 
+/// This struct is used to return the result of an imbalanced withdraw simulation.
+#[cw_serde]
+pub struct SimulateImbalancedWithdrawResponse {
+    /// The assets that would be withdrawn
+    pub assets: Vec<Asset>,
+    /// The amount of LP tokens that would be burned
+    pub lp_amount: Uint128,
+}
+
 /// This struct is used to return a query result with the general contract configuration.
 #[cw_serde]
 pub struct ConfigResponse {
@@ -199,6 +215,9 @@ pub struct SimulationResponse {
     pub spread_amount: Uint128,
     /// The amount of fees charged by the transaction
     pub commission_amount: Uint128,
+    /// The amount of sale tax charged on the offer asset (only set by pairs with sale taxes)
+    #[serde(default)]
+    pub tax_amount: Uint128,
 }
 
 /// This structure holds the parameters that are returned from a reverse swap simulation response.
@@ -210,6 +229,9 @@ pub struct ReverseSimulationResponse {
     pub spread_amount: Uint128,
     /// The amount of fees charged by the transaction
     pub commission_amount: Uint128,
+    /// The amount of sale tax charged on the offer asset (only set by pairs with sale taxes)
+    #[serde(default)]
+    pub tax_amount: Uint128,
 }
 
 /// This structure is used to return a cumulative prices query response.
@@ -1,6 +1,23 @@
 use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, Binary, StdError, StdResult};
 
+/// The custom pair type name of the ORO/xORO pair.
+pub const XORO_PAIR_TYPE: &str = "xoro";
+
+/// This structure holds the parameters used for creating an ORO/xORO pair.
+/// The pair swaps ORO and xORO at the staking exchange rate.
 #[cw_serde]
 pub struct XoroPairInitParams {
+    /// The ORO staking contract address
     pub staking: String,
 }
+
+impl XoroPairInitParams {
+    /// Deserializes the params from an `Option<Binary>`.
+    pub fn from_json(data: Option<Binary>) -> StdResult<Self> {
+        data.as_ref()
+            .map(from_json::<XoroPairInitParams>)
+            .transpose()?
+            .ok_or_else(|| StdError::generic_err("Missing Init params"))
+    }
+}
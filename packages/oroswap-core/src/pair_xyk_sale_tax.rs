@@ -1,9 +1,13 @@
 use std::collections::HashMap;
 
 use crate::asset::{validate_native_denom, AssetInfo};
+use crate::pair::FeeShareConfig;
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{from_json, Addr, Api, Binary, Decimal, StdError, StdResult};
 
+/// The custom pair type name under which the XYK pair enables sale taxes.
+pub const SALE_TAX_PAIR_TYPE: &str = "xyk_sale_tax";
+
 #[cw_serde]
 pub struct TaxConfig<T> {
     /// The tax rate to apply to token sales of `tax_denom`.
@@ -177,6 +181,19 @@ impl SaleTaxInitParams {
     }
 }
 
+/// This structure stores the configuration of a XYK pair with sale taxes.
+#[cw_serde]
+pub struct SaleTaxPoolConfig {
+    /// Whether asset balances are tracked over blocks or not.
+    pub track_asset_balances: bool,
+    /// The config for swap fee sharing
+    pub fee_share: Option<FeeShareConfig>,
+    /// The configs of the trade taxes for the pair.
+    pub tax_configs: TaxConfigsChecked,
+    /// The address that is allowed to updated the tax configs.
+    pub tax_config_admin: Addr,
+}
+
 #[cw_serde]
 /// Message used when migrating the contract from the standard XYK pair.
 pub struct MigrateMsg {
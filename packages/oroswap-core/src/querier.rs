@@ -215,6 +215,36 @@ where
     })
 }
 
+/// Returns the fee information for a specific pair, with the pair's maker fee override applied.
+///
+/// * **asset_infos** assets of the pair we query information for.
+///
+/// * **pair_type** type of the pair we query information for.
+pub fn query_pair_fee_info<C>(
+    querier: &QuerierWrapper<C>,
+    factory_contract: impl Into<String>,
+    asset_infos: Vec<AssetInfo>,
+    pair_type: PairType,
+) -> StdResult<FeeInfo>
+where
+    C: CustomQuery,
+{
+    let res: FeeInfoResponse = querier.query_wasm_smart(
+        factory_contract,
+        &FactoryQueryMsg::PairFeeInfo {
+            asset_infos,
+            pair_type,
+        },
+    )?;
+
+    Ok(FeeInfo {
+        fee_address: res.fee_address,
+        total_fee_rate: Decimal::from_ratio(res.total_fee_bps, 10000u16),
+        maker_fee_rate: Decimal::from_ratio(res.maker_fee_bps, 10000u16),
+        pool_creation_fee: res.pool_creation_fee,
+    })
+}
+
 /// Accepts two tokens as input and returns a pair's information.
 ///
 /// * **factory_contract** address of the factory contract.
@@ -308,3 +338,116 @@ pub fn reverse_simulate(
         },
     )
 }
+
+/// Returns all unpaused factory pairs between the given assets.
+///
+/// * **factory_contract** address of the factory contract.
+///
+/// * **asset_infos** assets for which we return the pairs.
+pub fn query_unpaused_pairs(
+    querier: &QuerierWrapper,
+    factory_contract: &Addr,
+    asset_infos: &[AssetInfo],
+) -> StdResult<Vec<PairInfo>> {
+    let mut pairs = vec![];
+    for pair in query_pairs_by_assets(querier, factory_contract, asset_infos)?.pairs {
+        let paused: bool = querier.query_wasm_smart(
+            factory_contract,
+            &FactoryQueryMsg::IsPairPaused {
+                asset_infos: asset_infos.to_vec(),
+                pair_type: pair.pair_type.clone(),
+            },
+        )?;
+        if !paused {
+            pairs.push(pair);
+        }
+    }
+
+    Ok(pairs)
+}
+
+/// Simulates the swap in all unpaused pairs between the given assets and returns the pair
+/// with the highest return amount together with this amount. Pairs which can't simulate
+/// the swap (e.g. without liquidity) are skipped. Returns `None` if no pair can perform the swap.
+///
+/// * **factory_contract** address of the factory contract.
+///
+/// * **offer_asset** asset that is swapped together with the amount to swap.
+///
+/// * **ask_asset_info** asset that is swapped to.
+pub fn query_best_pair(
+    querier: &QuerierWrapper,
+    factory_contract: &Addr,
+    offer_asset: &Asset,
+    ask_asset_info: &AssetInfo,
+) -> StdResult<Option<(PairInfo, Uint128)>> {
+    let mut best_pair: Option<(PairInfo, Uint128)> = None;
+
+    for pair in query_unpaused_pairs(
+        querier,
+        factory_contract,
+        &[offer_asset.info.clone(), ask_asset_info.clone()],
+    )? {
+        let simulation: StdResult<SimulationResponse> = querier.query_wasm_smart(
+            &pair.contract_addr,
+            &PairQueryMsg::Simulation {
+                offer_asset: offer_asset.clone(),
+                ask_asset_info: Some(ask_asset_info.clone()),
+            },
+        );
+
+        if let Ok(simulation) = simulation {
+            if best_pair
+                .as_ref()
+                .map_or(true, |(_, amount)| simulation.return_amount > *amount)
+            {
+                best_pair = Some((pair, simulation.return_amount));
+            }
+        }
+    }
+
+    Ok(best_pair)
+}
+
+/// Simulates the reverse swap in all unpaused pairs between the given assets and returns the pair
+/// which requires the lowest offer amount together with this amount. Pairs which can't simulate
+/// the swap are skipped. Returns `None` if no pair can perform the swap.
+///
+/// * **factory_contract** address of the factory contract.
+///
+/// * **offer_asset_info** asset that is swapped.
+///
+/// * **ask_asset** asset that is swapped to together with the amount to receive.
+pub fn query_best_pair_reverse(
+    querier: &QuerierWrapper,
+    factory_contract: &Addr,
+    offer_asset_info: &AssetInfo,
+    ask_asset: &Asset,
+) -> StdResult<Option<(PairInfo, Uint128)>> {
+    let mut best_pair: Option<(PairInfo, Uint128)> = None;
+
+    for pair in query_unpaused_pairs(
+        querier,
+        factory_contract,
+        &[offer_asset_info.clone(), ask_asset.info.clone()],
+    )? {
+        let simulation: StdResult<ReverseSimulationResponse> = querier.query_wasm_smart(
+            &pair.contract_addr,
+            &PairQueryMsg::ReverseSimulation {
+                offer_asset_info: Some(offer_asset_info.clone()),
+                ask_asset: ask_asset.clone(),
+            },
+        );
+
+        if let Ok(simulation) = simulation {
+            if best_pair
+                .as_ref()
+                .map_or(true, |(_, amount)| simulation.offer_amount < *amount)
+            {
+                best_pair = Some((pair, simulation.offer_amount));
+            }
+        }
+    }
+
+    Ok(best_pair)
+}
@@ -2,10 +2,18 @@ use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Decimal, Uint128};
 use cw20::Cw20ReceiveMsg;
 
-use crate::asset::AssetInfo;
+use crate::asset::{Asset, AssetInfo};
 use crate::factory::PairType;
 
 pub const MAX_SWAP_OPERATIONS: usize = 50;
+/// The default number of hops used when searching for the best route
+pub const DEFAULT_ROUTE_HOPS: u8 = 2;
+/// The maximum number of hops allowed when searching for the best route
+pub const MAX_ROUTE_HOPS: u8 = 3;
+/// The maximum number of intermediate assets the best route search can go through
+pub const MAX_HUB_ASSETS: usize = 10;
+/// The maximum number of candidate routes simulated when searching for the best route
+pub const MAX_ROUTE_CANDIDATES: usize = 20;
 
 /// This structure holds the parameters used for creating a contract.
 #[cw_serde]
@@ -44,6 +52,26 @@ impl SwapOperation {
             SwapOperation::OroSwap { ask_asset_info, .. } => ask_asset_info.clone(),
         }
     }
+
+    pub fn get_offer_asset_info(&self) -> AssetInfo {
+        match self {
+            SwapOperation::NativeSwap { offer_denom, .. } => AssetInfo::NativeToken {
+                denom: offer_denom.clone(),
+            },
+            SwapOperation::OroSwap {
+                offer_asset_info, ..
+            } => offer_asset_info.clone(),
+        }
+    }
+}
+
+/// This structure describes one leg of a split swap.
+#[cw_serde]
+pub struct SplitRoute {
+    /// The swap operations performed by this leg
+    pub operations: Vec<SwapOperation>,
+    /// The relative weight of this leg. The offer amount is split between legs proportionally to their weights
+    pub weight: u64,
 }
 
 /// This structure describes the execute messages available in the contract.
@@ -58,6 +86,34 @@ pub enum ExecuteMsg {
         to: Option<String>,
         max_spread: Option<Decimal>,
     },
+    /// ExecuteSplitSwapOperations splits the offer amount between several routes by weight and checks
+    /// the summed output of all routes against one minimum amount to receive
+    ExecuteSplitSwapOperations {
+        routes: Vec<SplitRoute>,
+        minimum_receive: Option<Uint128>,
+        to: Option<String>,
+        max_spread: Option<Decimal>,
+    },
+    /// ExecuteSwapOperationsExactOut swaps only as much of the offer asset as needed to receive
+    /// `ask_amount` and refunds the rest to the sender
+    ExecuteSwapOperationsExactOut {
+        operations: Vec<SwapOperation>,
+        ask_amount: Uint128,
+        max_offer: Uint128,
+        to: Option<String>,
+        max_spread: Option<Decimal>,
+    },
+    /// ExecuteBestRoute finds the best route for the sent native coin and swaps it
+    ExecuteBestRoute {
+        ask_asset_info: AssetInfo,
+        max_hops: Option<u8>,
+        minimum_receive: Option<Uint128>,
+        to: Option<String>,
+        max_spread: Option<Decimal>,
+    },
+    /// UpdateHubAssets sets the intermediate assets used when searching for the best route.
+    /// Only the factory owner can execute this
+    UpdateHubAssets { hub_assets: Vec<AssetInfo> },
 
     /// Internal use
     /// ExecuteSwapOperation executes a single swap operation
@@ -86,6 +142,40 @@ pub enum Cw20HookMsg {
         /// Max spread
         max_spread: Option<Decimal>,
     },
+    ExecuteSplitSwapOperations {
+        /// Weighted routes between which the received amount is split
+        routes: Vec<SplitRoute>,
+        /// The minimum amount of tokens to get from all routes combined
+        minimum_receive: Option<Uint128>,
+        /// The recipient
+        to: Option<String>,
+        /// Max spread
+        max_spread: Option<Decimal>,
+    },
+    ExecuteSwapOperationsExactOut {
+        /// A vector of swap operations
+        operations: Vec<SwapOperation>,
+        /// The amount of tokens to receive
+        ask_amount: Uint128,
+        /// The maximum amount of tokens to swap
+        max_offer: Uint128,
+        /// The recipient
+        to: Option<String>,
+        /// Max spread
+        max_spread: Option<Decimal>,
+    },
+    ExecuteBestRoute {
+        /// The asset to swap to
+        ask_asset_info: AssetInfo,
+        /// The maximum number of hops in the route
+        max_hops: Option<u8>,
+        /// The minimum amount of tokens to get from a swap
+        minimum_receive: Option<Uint128>,
+        /// The recipient
+        to: Option<String>,
+        /// Max spread
+        max_spread: Option<Decimal>,
+    },
 }
 
 /// This structure describes the query messages available in the contract.
@@ -110,6 +200,25 @@ pub enum QueryMsg {
         /// The swap operations to perform, each swap involving a specific pool
         operations: Vec<SwapOperation>,
     },
+    /// SimulateSplitSwapOperations simulates swaps split between several weighted routes
+    #[returns(SimulateSplitSwapOperationsResponse)]
+    SimulateSplitSwapOperations {
+        /// The total amount of tokens to swap
+        offer_amount: Uint128,
+        /// Weighted routes between which the offer amount is split
+        routes: Vec<SplitRoute>,
+    },
+    /// FindBestRoute searches the direct pairs and the routes through the configured hub assets
+    /// for the route with the highest return amount
+    #[returns(FindBestRouteResponse)]
+    FindBestRoute {
+        /// The asset to swap
+        offer_asset: Asset,
+        /// The asset to swap to
+        ask_asset_info: AssetInfo,
+        /// The maximum number of hops in the route. Defaults to 2 and cannot exceed 3
+        max_hops: Option<u8>,
+    },
 }
 
 /// This structure describes a custom struct to return a query response containing the base contract configuration.
@@ -117,6 +226,8 @@ pub enum QueryMsg {
 pub struct ConfigResponse {
     /// The Oroswap factory contract address
     pub oroswap_factory: String,
+    /// The intermediate assets used when searching for the best route
+    pub hub_assets: Vec<AssetInfo>,
 }
 
 /// This structure describes a custom struct to return a query response containing the end amount of a swap simulation
@@ -128,6 +239,35 @@ pub struct SimulateSwapOperationsResponse {
     pub router_stages: Vec<RouterStage>,
 }
 
+/// This structure describes a custom struct to return a query response containing the end amount of a split swap simulation
+#[cw_serde]
+pub struct SimulateSplitSwapOperationsResponse {
+    /// The total amount of tokens received from all routes
+    pub amount: Uint128,
+    /// Breakdown for each route in the order they were specified
+    pub routes: Vec<SplitRouteResponse>,
+}
+
+/// This structure describes a custom struct to return a query response containing the best route found
+#[cw_serde]
+pub struct FindBestRouteResponse {
+    /// The swap operations of the best route
+    pub operations: Vec<SwapOperation>,
+    /// The simulation of the best route
+    pub simulation: SimulateSwapOperationsResponse,
+}
+
+/// Simulation result for a single route of a split swap
+#[cw_serde]
+pub struct SplitRouteResponse {
+    /// The part of the offer amount swapped through this route
+    pub offer_amount: Uint128,
+    /// The amount of tokens received from this route
+    pub return_amount: Uint128,
+    /// Detailed breakdown for each stage of this route
+    pub router_stages: Vec<RouterStage>,
+}
+
 /// Detailed information for a single stage in a multi-hop swap
 #[cw_serde]
 pub struct RouterStage {
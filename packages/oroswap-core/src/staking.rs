@@ -1,9 +1,16 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Uint128};
+use cosmwasm_std::{Addr, Binary, Decimal, Uint128};
+
+/// The maximum unbonding period (30 days)
+pub const MAX_UNBONDING_PERIOD: u64 = 86400 * 30;
+/// The maximum instant exit fee (50%)
+pub const MAX_INSTANT_EXIT_FEE: Decimal = Decimal::raw(500000000000000000);
 
 /// This structure describes the parameters used for creating a contract.
 #[cw_serde]
 pub struct InstantiateMsg {
+    /// The contract owner
+    pub owner: String,
     /// The ORO token contract address
     pub deposit_token_denom: String,
     /// Tracking contract admin
@@ -14,6 +21,10 @@ pub struct InstantiateMsg {
     pub token_factory_addr: String,
     /// Bootstrap amount to initialize the pool (optional, defaults to MINIMUM_STAKE_AMOUNT)
     pub bootstrap_amount: Option<Uint128>,
+    /// Unbonding period in seconds (optional, defaults to 0 meaning `Leave` returns ORO immediately)
+    pub unbonding_period: Option<u64>,
+    /// Fee charged on `InstantLeave` (optional, defaults to 0)
+    pub instant_exit_fee: Option<Decimal>,
 }
 
 /// This structure describes the execute messages available in the contract.
@@ -30,7 +41,55 @@ pub enum ExecuteMsg {
     },
     /// Burns xORO in exchange for ORO.
     /// The receiver is optional. If not set, the sender will receive the ORO.
+    /// If the unbonding period is set, ORO is locked in an unbonding entry
+    /// which the receiver can claim once the period has passed.
     Leave { receiver: Option<String> },
+    /// Burns xORO in exchange for ORO bypassing the unbonding period.
+    /// The instant exit fee is deducted from the returned ORO and stays with remaining stakers.
+    /// No fee is charged if the unbonding period is not set.
+    /// The receiver is optional. If not set, the sender will receive the ORO.
+    InstantLeave { receiver: Option<String> },
+    /// Claims the sender's unbonded ORO
+    Claim {},
+    /// Updates contract parameters
+    /// ## Executor
+    /// Only the current owner can execute this
+    UpdateConfig {
+        /// The new unbonding period in seconds
+        unbonding_period: Option<u64>,
+        /// The new instant exit fee
+        instant_exit_fee: Option<Decimal>,
+    },
+    /// Points xORO balance tracking to another tracker contract.
+    /// The tracker must track xORO over seconds.
+    /// ## Executor
+    /// Only the current owner can execute this
+    UpdateTracker { tracker_addr: String },
+    /// Pauses `Enter`, `EnterWithHook`, `Leave` and `InstantLeave`.
+    /// ## Executor
+    /// Only the current owner can execute this
+    Pause {},
+    /// Unpauses the contract
+    /// ## Executor
+    /// Only the current owner can execute this
+    Unpause {},
+    /// Creates a request to change contract ownership
+    /// ## Executor
+    /// Only the current owner can execute this
+    ProposeNewOwner {
+        /// The newly proposed owner
+        owner: String,
+        /// The validity period of the offer to change the owner
+        expires_in: u64,
+    },
+    /// Removes a request to change contract ownership
+    /// ## Executor
+    /// Only the current owner can execute this
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    /// ## Executor
+    /// Only the newly proposed owner can execute this
+    ClaimOwnership {},
 }
 
 /// This structure describes the query messages available in the contract.
@@ -59,17 +118,45 @@ pub enum QueryMsg {
     /// Returns current total supply if timestamp unset.
     #[returns(Uint128)]
     TotalSupplyAt { timestamp: Option<u64> },
+    /// Returns the pending unbonding entries of the given address
+    #[returns(Vec<UnbondingEntry>)]
+    Unbondings {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Returns the total amount of ORO in unbonding
+    #[returns(Uint128)]
+    TotalUnbonding {},
+}
+
+/// This structure describes a migration message.
+#[cw_serde]
+pub struct MigrateMsg {
+    /// The contract owner. Required when migrating from a version that did not store an owner
+    pub owner: Option<String>,
 }
 
 /// This structure stores the main parameters for the staking contract.
 #[cw_serde]
 pub struct Config {
+    /// The contract owner
+    pub owner: Addr,
     /// The ORO token denom
     pub oro_denom: String,
     /// The xORO token denom
     pub xoro_denom: String,
     /// The bootstrap amount for pool initialization
     pub bootstrap_amount: Uint128,
+    /// Unbonding period in seconds. Zero means `Leave` returns ORO immediately
+    #[serde(default)]
+    pub unbonding_period: u64,
+    /// Fee charged on `InstantLeave`
+    #[serde(default)]
+    pub instant_exit_fee: Decimal,
+    /// Whether staking and unstaking are paused
+    #[serde(default)]
+    pub paused: bool,
 }
 
 /// This structure stores the tracking contract data.
@@ -93,3 +180,12 @@ pub struct StakingResponse {
     /// The xORO denom
     pub xoro_amount: Uint128,
 }
+
+/// This structure describes ORO locked after `Leave` until the unbonding period has passed.
+#[cw_serde]
+pub struct UnbondingEntry {
+    /// The amount of ORO in unbonding
+    pub amount: Uint128,
+    /// The timestamp when ORO can be claimed
+    pub release_time: u64,
+}
@@ -23,6 +23,24 @@ pub enum ExecuteMsg {
         /// The amount of tokens to claim
         amount: Option<Uint128>,
     },
+    /// Claims vested tokens on behalf of a beneficiary and sends them to the beneficiary
+    /// ## Executor
+    /// Only the claim operator of the beneficiary can execute this
+    ClaimFor {
+        /// The beneficiary whose vested tokens are claimed
+        beneficiary: String,
+        /// The amount of tokens to claim
+        amount: Option<Uint128>,
+    },
+    /// Sets or removes the address allowed to claim vested tokens on behalf of the sender
+    SetClaimOperator { operator: Option<String> },
+    /// Proposes to move the sender's vesting account to a new beneficiary.
+    /// A new proposal replaces the previous one
+    ProposeBeneficiaryChange { new_beneficiary: String },
+    /// Moves the vesting account of `beneficiary` to the sender
+    /// ## Executor
+    /// Only the proposed beneficiary can execute this
+    AcceptBeneficiaryChange { beneficiary: String },
     /// Receives a message of type [`Cw20ReceiveMsg`] and processes it depending on the received template
     Receive(Cw20ReceiveMsg),
     /// RegisterVestingAccounts registers vesting targets/accounts
@@ -38,6 +56,16 @@ pub enum ExecuteMsg {
         /// The amount of tokens to withdraw
         withdraw_amount: Uint128,
     },
+    /// Terminates the revocable schedules of a vesting account.
+    /// Already vested tokens are sent to the account, unvested tokens are returned to the recipient.
+    /// ## Executor
+    /// Only the current owner can execute this
+    RevokeVestingAccount {
+        /// The account whose revocable schedules are terminated
+        address: String,
+        /// The address that receives the unvested tokens. Defaults to the owner
+        recipient: Option<String>,
+    },
     /// Creates a request to change contract ownership
     /// ## Executor
     /// Only the current owner can execute this
@@ -82,6 +110,27 @@ pub struct VestingSchedule {
     pub start_point: VestingSchedulePoint,
     /// The end point for the vesting schedule
     pub end_point: Option<VestingSchedulePoint>,
+    /// The unlock curve between the start and end points. Linear if not set
+    #[serde(default)]
+    pub shape: Option<VestingShape>,
+    /// Whether the owner can revoke the unvested part of the schedule
+    #[serde(default)]
+    pub revocable: bool,
+}
+
+/// This enum describes how tokens are unlocked between the start and end points of a [`VestingSchedule`].
+/// All shapes require an end point.
+#[cw_serde]
+pub enum VestingShape {
+    /// Nothing is unlocked before `time`. At `time` the amount that would have been unlocked linearly
+    /// since the start point is released at once, after which vesting continues linearly
+    Cliff { time: u64 },
+    /// The linearly unlocked amount is released in discrete steps every `period` seconds since the start point.
+    /// The remainder is released at the end point
+    Periodic { period: u64 },
+    /// Tokens are unlocked linearly between consecutive points of the curve.
+    /// Points must lie strictly between the start and end points and hold the cumulative unlocked amount
+    Piecewise { points: Vec<VestingSchedulePoint> },
 }
 
 /// This structure stores the parameters used to create a vesting schedule.
@@ -117,6 +166,18 @@ pub enum QueryMsg {
     /// Timestamp returns the current timestamp
     #[returns(u64)]
     Timestamp {},
+    /// Returns the future unlock timeline for a specific address.
+    /// Each point holds the total amount unlocked across all schedules at that time.
+    /// The unlocked amount grows linearly between consecutive points;
+    /// two points with the same time describe an instant unlock.
+    #[returns(Vec<VestingSchedulePoint>)]
+    UnlockTimeline { address: String },
+    /// Returns the address allowed to claim on behalf of a specific beneficiary
+    #[returns(Option<Addr>)]
+    ClaimOperator { address: String },
+    /// Returns the proposed new beneficiary for a specific vesting account
+    #[returns(Option<Addr>)]
+    BeneficiaryChangeProposal { address: String },
 }
 
 /// This structure describes a custom struct used to return the contract configuration.
@@ -0,0 +1,149 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Decimal, Uint128};
+
+/// Seconds in one week. Lock end times are rounded down to whole weeks.
+pub const WEEK: u64 = 7 * 86400;
+/// The upper limit for the configured maximum lock time (in weeks)
+pub const MAX_LOCK_WEEKS_LIMIT: u64 = 208;
+
+/// This structure describes the parameters used for creating a contract.
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// Address allowed to change contract parameters
+    pub owner: String,
+    /// The xORO denom that is locked in exchange for voting power
+    pub deposit_denom: String,
+    /// The Maker contract address which receives early unlock penalties
+    pub maker: String,
+    /// The maximum lock time (in weeks). Locking for this long gives 1 vxORO per 1 xORO.
+    pub max_lock_weeks: u64,
+    /// The share of a lock paid as a penalty when it is withdrawn right after being locked
+    /// for the maximum time. The penalty decays linearly with the remaining lock time.
+    pub max_exit_penalty: Decimal,
+}
+
+/// This structure describes the execute messages available in the contract.
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Locks the attached xORO for the given number of weeks
+    CreateLock { weeks: u64 },
+    /// Adds the attached xORO to an existing lock
+    IncreaseAmount {},
+    /// Extends an existing lock so it ends the given number of weeks from now
+    ExtendLockTime { weeks: u64 },
+    /// Withdraws xORO from an expired lock
+    Withdraw {},
+    /// Withdraws xORO from a lock before it expires. Part of the xORO is sent to the Maker
+    /// as a penalty.
+    WithdrawEarly {},
+    /// Updates contract parameters
+    /// ## Executor
+    /// Only the current owner can execute this
+    UpdateConfig {
+        /// The new Maker contract address
+        maker: Option<String>,
+        /// The new maximum early unlock penalty
+        max_exit_penalty: Option<Decimal>,
+    },
+    /// Creates a request to change contract ownership
+    /// ## Executor
+    /// Only the current owner can execute this
+    ProposeNewOwner {
+        /// The newly proposed owner
+        owner: String,
+        /// The validity period of the offer to change the owner
+        expires_in: u64,
+    },
+    /// Removes a request to change contract ownership
+    /// ## Executor
+    /// Only the current owner can execute this
+    DropOwnershipProposal {},
+    /// Claims contract ownership
+    /// ## Executor
+    /// Only the newly proposed owner can execute this
+    ClaimOwnership {},
+}
+
+/// This structure describes the query messages available in the contract.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Config returns the contract configuration specified in a custom [`Config`] structure
+    #[returns(Config)]
+    Config {},
+    /// LockInfo returns the lock of the given address
+    #[returns(LockInfoResponse)]
+    LockInfo { address: String },
+    /// BalanceAt returns vxORO voting power of the given address at the given timestamp.
+    /// Returns current voting power if timestamp unset.
+    #[returns(Uint128)]
+    BalanceAt {
+        address: String,
+        timestamp: Option<u64>,
+    },
+    /// TotalSupplyAt returns total vxORO voting power at the given timestamp.
+    /// Returns current total voting power if timestamp unset.
+    #[returns(Uint128)]
+    TotalSupplyAt { timestamp: Option<u64> },
+}
+
+/// This structure stores the main parameters for the voting escrow contract.
+#[cw_serde]
+pub struct Config {
+    /// Address that's allowed to change contract parameters
+    pub owner: Addr,
+    /// The xORO denom
+    pub deposit_denom: String,
+    /// The Maker contract address which receives early unlock penalties
+    pub maker: Addr,
+    /// The maximum lock time (in weeks)
+    pub max_lock_weeks: u64,
+    /// The maximum early unlock penalty
+    pub max_exit_penalty: Decimal,
+}
+
+impl Config {
+    /// Returns the maximum lock time in seconds.
+    pub fn max_lock_time(&self) -> u64 {
+        self.max_lock_weeks * WEEK
+    }
+}
+
+/// This structure describes a lock of xORO.
+#[cw_serde]
+pub struct Lock {
+    /// The amount of locked xORO
+    pub amount: Uint128,
+    /// The timestamp when the lock was created
+    pub start: u64,
+    /// The timestamp when the lock expires
+    pub end: u64,
+}
+
+impl Lock {
+    /// Returns the voting power of the lock at the given timestamp.
+    /// The voting power decays linearly from **amount** (for a lock of **max_lock_time**) to zero at **end**.
+    pub fn voting_power(&self, max_lock_time: u64, timestamp: u64) -> Uint128 {
+        if timestamp >= self.end {
+            Uint128::zero()
+        } else {
+            self.amount
+                .multiply_ratio(self.end - timestamp, max_lock_time)
+        }
+    }
+}
+
+/// This structure describes the response of the lock info query.
+#[cw_serde]
+pub struct LockInfoResponse {
+    /// The amount of locked xORO
+    pub amount: Uint128,
+    /// The timestamp when the lock was created
+    pub start: u64,
+    /// The timestamp when the lock expires
+    pub end: u64,
+    /// The current voting power of the lock
+    pub voting_power: Uint128,
+    /// The penalty charged if the lock is withdrawn now
+    pub exit_penalty: Uint128,
+}